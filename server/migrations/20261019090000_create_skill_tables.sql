@@ -0,0 +1,24 @@
+CREATE TABLE IF NOT EXISTS skills
+(
+    id              INTEGER PRIMARY KEY,
+    name            VARCHAR(255) NOT NULL,
+    normalized_name VARCHAR(255) UNIQUE NOT NULL,
+    created_at      TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS user_skills
+(
+    id          INTEGER PRIMARY KEY,
+    user_id     INTEGER NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    skill_id    INTEGER NOT NULL REFERENCES skills (id) ON DELETE CASCADE,
+    proficiency TEXT,
+    created_at  TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (user_id, skill_id)
+);
+
+CREATE TABLE IF NOT EXISTS user_skill_experiences
+(
+    user_skill_id INTEGER NOT NULL REFERENCES user_skills (id) ON DELETE CASCADE,
+    experience_id INTEGER NOT NULL REFERENCES experiences (id) ON DELETE CASCADE,
+    PRIMARY KEY (user_skill_id, experience_id)
+);
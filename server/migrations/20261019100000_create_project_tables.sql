@@ -0,0 +1,29 @@
+CREATE TABLE IF NOT EXISTS projects
+(
+    id             INTEGER PRIMARY KEY,
+    user_id        INTEGER NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    name           TEXT    NOT NULL,
+    role           TEXT,
+    description    TEXT,
+    url            TEXT,
+    repository_url TEXT,
+    start_date     TIMESTAMP,
+    end_date       TIMESTAMP,
+    created_at     TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS project_skills
+(
+    project_id INTEGER NOT NULL REFERENCES projects (id) ON DELETE CASCADE,
+    skill_id   INTEGER NOT NULL REFERENCES skills (id) ON DELETE CASCADE,
+    PRIMARY KEY (project_id, skill_id)
+);
+
+CREATE TABLE IF NOT EXISTS project_images
+(
+    id         INTEGER PRIMARY KEY,
+    project_id INTEGER NOT NULL REFERENCES projects (id) ON DELETE CASCADE,
+    url        TEXT    NOT NULL,
+    caption    TEXT,
+    position   INTEGER NOT NULL DEFAULT 0
+);
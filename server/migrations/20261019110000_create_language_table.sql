@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS languages
+(
+    id            INTEGER PRIMARY KEY,
+    user_id       INTEGER     NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    language_code VARCHAR(3)  NOT NULL,
+    level         VARCHAR(10) NOT NULL,
+    created_at    TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (user_id, language_code)
+);
@@ -0,0 +1,35 @@
+CREATE TABLE IF NOT EXISTS publications
+(
+    id               INTEGER PRIMARY KEY,
+    user_id          INTEGER NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    title            TEXT    NOT NULL,
+    venue            TEXT,
+    publication_date TIMESTAMP,
+    co_authors       TEXT,
+    doi              VARCHAR(255),
+    url              VARCHAR(255),
+    created_at       TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS patents
+(
+    id            INTEGER PRIMARY KEY,
+    user_id       INTEGER      NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    title         TEXT         NOT NULL,
+    patent_number VARCHAR(255) NOT NULL,
+    office        VARCHAR(255) NOT NULL,
+    status        VARCHAR(50),
+    url           VARCHAR(255),
+    created_at    TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS awards
+(
+    id          INTEGER PRIMARY KEY,
+    user_id     INTEGER NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    title       TEXT    NOT NULL,
+    issuer      TEXT,
+    award_date  TIMESTAMP,
+    description TEXT,
+    created_at  TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
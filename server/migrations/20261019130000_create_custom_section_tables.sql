@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS custom_sections
+(
+    id         INTEGER PRIMARY KEY,
+    user_id    INTEGER      NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    title      VARCHAR(255) NOT NULL,
+    position   INTEGER      NOT NULL DEFAULT 0,
+    visibility VARCHAR(20)  NOT NULL DEFAULT 'public',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS custom_section_entries
+(
+    id         INTEGER PRIMARY KEY,
+    section_id INTEGER NOT NULL REFERENCES custom_sections (id) ON DELETE CASCADE,
+    title      TEXT    NOT NULL,
+    subtitle   TEXT,
+    start_date TIMESTAMP,
+    end_date   TIMESTAMP,
+    body       TEXT,
+    link       TEXT,
+    position   INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,26 @@
+CREATE TABLE IF NOT EXISTS recommendations
+(
+    id            INTEGER PRIMARY KEY,
+    author_id     INTEGER     NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    recipient_id  INTEGER     NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    experience_id INTEGER REFERENCES experiences (id) ON DELETE SET NULL,
+    relationship  VARCHAR(50) NOT NULL,
+    body          TEXT        NOT NULL,
+    status        VARCHAR(20) NOT NULL DEFAULT 'pending',
+    created_at    TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS recommendations_recipient_status ON recommendations (recipient_id, status);
+
+CREATE TABLE IF NOT EXISTS notifications
+(
+    id           INTEGER PRIMARY KEY,
+    user_id      INTEGER     NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    kind         VARCHAR(50) NOT NULL,
+    message      TEXT        NOT NULL,
+    reference_id INTEGER,
+    read_at      TIMESTAMP,
+    created_at   TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS notifications_user ON notifications (user_id, read_at);
@@ -0,0 +1,187 @@
+-- Profile dates are stored as text in one of the formats YYYY, YYYY-MM or YYYY-MM-DD.
+--
+-- The date columns were declared as TIMESTAMP, which gives them numeric affinity in SQLite and turns year-only
+-- dates into integers. Every date column is therefore recreated as TEXT. Existing values are kept when they
+-- already are a valid date and full timestamps are cut down to their date. Any other value is moved unchanged
+-- to a `<column>_legacy` column next to the date. Experiences, educations and certifications show it to their
+-- owner, so the date can be entered again.
+
+-- Every stored date, normalized once below with the same rules for all columns.
+CREATE TEMP TABLE profile_dates
+(
+    table_name  TEXT    NOT NULL,
+    column_name TEXT    NOT NULL,
+    row_id      INTEGER NOT NULL,
+    value       TEXT    NOT NULL,
+    normalized  TEXT,
+    PRIMARY KEY (table_name, column_name, row_id)
+);
+
+INSERT INTO profile_dates (table_name, column_name, row_id, value)
+SELECT 'experiences', 'start_date', id, CAST(start_date AS TEXT) FROM experiences WHERE trim(CAST(start_date AS TEXT)) != ''
+UNION ALL
+SELECT 'experiences', 'end_date', id, CAST(end_date AS TEXT) FROM experiences WHERE trim(CAST(end_date AS TEXT)) != ''
+UNION ALL
+SELECT 'educations', 'start_date', id, CAST(start_date AS TEXT) FROM educations WHERE trim(CAST(start_date AS TEXT)) != ''
+UNION ALL
+SELECT 'educations', 'end_date', id, CAST(end_date AS TEXT) FROM educations WHERE trim(CAST(end_date AS TEXT)) != ''
+UNION ALL
+SELECT 'certification', 'issue_date', id, CAST(issue_date AS TEXT) FROM certification WHERE trim(CAST(issue_date AS TEXT)) != ''
+UNION ALL
+SELECT 'certification', 'expiration_date', id, CAST(expiration_date AS TEXT) FROM certification WHERE trim(CAST(expiration_date AS TEXT)) != ''
+UNION ALL
+SELECT 'projects', 'start_date', id, CAST(start_date AS TEXT) FROM projects WHERE trim(CAST(start_date AS TEXT)) != ''
+UNION ALL
+SELECT 'projects', 'end_date', id, CAST(end_date AS TEXT) FROM projects WHERE trim(CAST(end_date AS TEXT)) != ''
+UNION ALL
+SELECT 'publications', 'publication_date', id, CAST(publication_date AS TEXT) FROM publications WHERE trim(CAST(publication_date AS TEXT)) != ''
+UNION ALL
+SELECT 'awards', 'award_date', id, CAST(award_date AS TEXT) FROM awards WHERE trim(CAST(award_date AS TEXT)) != ''
+UNION ALL
+SELECT 'custom_section_entries', 'start_date', id, CAST(start_date AS TEXT) FROM custom_section_entries WHERE trim(CAST(start_date AS TEXT)) != ''
+UNION ALL
+SELECT 'custom_section_entries', 'end_date', id, CAST(end_date AS TEXT) FROM custom_section_entries WHERE trim(CAST(end_date AS TEXT)) != '';
+
+UPDATE profile_dates
+SET normalized = CASE
+    WHEN substr(value, 1, 4) = '0000' THEN NULL
+    WHEN value GLOB '[0-9][0-9][0-9][0-9]' THEN value
+    WHEN value GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]'
+        AND date(value || '-01', '+0 days') = value || '-01' THEN value
+    WHEN value GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]*'
+        AND date(substr(value, 1, 10), '+0 days') = substr(value, 1, 10) THEN substr(value, 1, 10)
+    END;
+
+ALTER TABLE experiences ADD COLUMN start_date_text TEXT;
+ALTER TABLE experiences ADD COLUMN start_date_legacy TEXT;
+UPDATE experiences
+SET (start_date_text, start_date_legacy) = (
+    SELECT normalized, CASE WHEN normalized IS NULL THEN value END
+    FROM profile_dates
+    WHERE table_name = 'experiences' AND column_name = 'start_date' AND row_id = experiences.id
+);
+ALTER TABLE experiences DROP COLUMN start_date;
+ALTER TABLE experiences RENAME COLUMN start_date_text TO start_date;
+
+ALTER TABLE experiences ADD COLUMN end_date_text TEXT;
+ALTER TABLE experiences ADD COLUMN end_date_legacy TEXT;
+UPDATE experiences
+SET (end_date_text, end_date_legacy) = (
+    SELECT normalized, CASE WHEN normalized IS NULL THEN value END
+    FROM profile_dates
+    WHERE table_name = 'experiences' AND column_name = 'end_date' AND row_id = experiences.id
+);
+ALTER TABLE experiences DROP COLUMN end_date;
+ALTER TABLE experiences RENAME COLUMN end_date_text TO end_date;
+
+ALTER TABLE educations ADD COLUMN start_date_text TEXT;
+ALTER TABLE educations ADD COLUMN start_date_legacy TEXT;
+UPDATE educations
+SET (start_date_text, start_date_legacy) = (
+    SELECT normalized, CASE WHEN normalized IS NULL THEN value END
+    FROM profile_dates
+    WHERE table_name = 'educations' AND column_name = 'start_date' AND row_id = educations.id
+);
+ALTER TABLE educations DROP COLUMN start_date;
+ALTER TABLE educations RENAME COLUMN start_date_text TO start_date;
+
+ALTER TABLE educations ADD COLUMN end_date_text TEXT;
+ALTER TABLE educations ADD COLUMN end_date_legacy TEXT;
+UPDATE educations
+SET (end_date_text, end_date_legacy) = (
+    SELECT normalized, CASE WHEN normalized IS NULL THEN value END
+    FROM profile_dates
+    WHERE table_name = 'educations' AND column_name = 'end_date' AND row_id = educations.id
+);
+ALTER TABLE educations DROP COLUMN end_date;
+ALTER TABLE educations RENAME COLUMN end_date_text TO end_date;
+
+ALTER TABLE certification ADD COLUMN issue_date_text TEXT;
+ALTER TABLE certification ADD COLUMN issue_date_legacy TEXT;
+UPDATE certification
+SET (issue_date_text, issue_date_legacy) = (
+    SELECT normalized, CASE WHEN normalized IS NULL THEN value END
+    FROM profile_dates
+    WHERE table_name = 'certification' AND column_name = 'issue_date' AND row_id = certification.id
+);
+ALTER TABLE certification DROP COLUMN issue_date;
+ALTER TABLE certification RENAME COLUMN issue_date_text TO issue_date;
+
+ALTER TABLE certification ADD COLUMN expiration_date_text TEXT;
+ALTER TABLE certification ADD COLUMN expiration_date_legacy TEXT;
+UPDATE certification
+SET (expiration_date_text, expiration_date_legacy) = (
+    SELECT normalized, CASE WHEN normalized IS NULL THEN value END
+    FROM profile_dates
+    WHERE table_name = 'certification' AND column_name = 'expiration_date' AND row_id = certification.id
+);
+ALTER TABLE certification DROP COLUMN expiration_date;
+ALTER TABLE certification RENAME COLUMN expiration_date_text TO expiration_date;
+
+ALTER TABLE projects ADD COLUMN start_date_text TEXT;
+ALTER TABLE projects ADD COLUMN start_date_legacy TEXT;
+UPDATE projects
+SET (start_date_text, start_date_legacy) = (
+    SELECT normalized, CASE WHEN normalized IS NULL THEN value END
+    FROM profile_dates
+    WHERE table_name = 'projects' AND column_name = 'start_date' AND row_id = projects.id
+);
+ALTER TABLE projects DROP COLUMN start_date;
+ALTER TABLE projects RENAME COLUMN start_date_text TO start_date;
+
+ALTER TABLE projects ADD COLUMN end_date_text TEXT;
+ALTER TABLE projects ADD COLUMN end_date_legacy TEXT;
+UPDATE projects
+SET (end_date_text, end_date_legacy) = (
+    SELECT normalized, CASE WHEN normalized IS NULL THEN value END
+    FROM profile_dates
+    WHERE table_name = 'projects' AND column_name = 'end_date' AND row_id = projects.id
+);
+ALTER TABLE projects DROP COLUMN end_date;
+ALTER TABLE projects RENAME COLUMN end_date_text TO end_date;
+
+ALTER TABLE publications ADD COLUMN publication_date_text TEXT;
+ALTER TABLE publications ADD COLUMN publication_date_legacy TEXT;
+UPDATE publications
+SET (publication_date_text, publication_date_legacy) = (
+    SELECT normalized, CASE WHEN normalized IS NULL THEN value END
+    FROM profile_dates
+    WHERE table_name = 'publications' AND column_name = 'publication_date' AND row_id = publications.id
+);
+ALTER TABLE publications DROP COLUMN publication_date;
+ALTER TABLE publications RENAME COLUMN publication_date_text TO publication_date;
+
+ALTER TABLE awards ADD COLUMN award_date_text TEXT;
+ALTER TABLE awards ADD COLUMN award_date_legacy TEXT;
+UPDATE awards
+SET (award_date_text, award_date_legacy) = (
+    SELECT normalized, CASE WHEN normalized IS NULL THEN value END
+    FROM profile_dates
+    WHERE table_name = 'awards' AND column_name = 'award_date' AND row_id = awards.id
+);
+ALTER TABLE awards DROP COLUMN award_date;
+ALTER TABLE awards RENAME COLUMN award_date_text TO award_date;
+
+ALTER TABLE custom_section_entries ADD COLUMN start_date_text TEXT;
+ALTER TABLE custom_section_entries ADD COLUMN start_date_legacy TEXT;
+UPDATE custom_section_entries
+SET (start_date_text, start_date_legacy) = (
+    SELECT normalized, CASE WHEN normalized IS NULL THEN value END
+    FROM profile_dates
+    WHERE table_name = 'custom_section_entries' AND column_name = 'start_date' AND row_id = custom_section_entries.id
+);
+ALTER TABLE custom_section_entries DROP COLUMN start_date;
+ALTER TABLE custom_section_entries RENAME COLUMN start_date_text TO start_date;
+
+ALTER TABLE custom_section_entries ADD COLUMN end_date_text TEXT;
+ALTER TABLE custom_section_entries ADD COLUMN end_date_legacy TEXT;
+UPDATE custom_section_entries
+SET (end_date_text, end_date_legacy) = (
+    SELECT normalized, CASE WHEN normalized IS NULL THEN value END
+    FROM profile_dates
+    WHERE table_name = 'custom_section_entries' AND column_name = 'end_date' AND row_id = custom_section_entries.id
+);
+ALTER TABLE custom_section_entries DROP COLUMN end_date;
+ALTER TABLE custom_section_entries RENAME COLUMN end_date_text TO end_date;
+
+DROP TABLE profile_dates;
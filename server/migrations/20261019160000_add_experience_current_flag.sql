@@ -0,0 +1,2 @@
+-- A null end date means the end is unknown. Experiences the user still holds are flagged explicitly.
+ALTER TABLE experiences ADD COLUMN is_current BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS organizations
+(
+    id         INTEGER PRIMARY KEY,
+    name       VARCHAR(255) NOT NULL,
+    slug       VARCHAR(255) NOT NULL UNIQUE,
+    website    VARCHAR(255),
+    logo_url   VARCHAR(255),
+    org_type   VARCHAR(20)  NOT NULL,
+    created_by INTEGER REFERENCES users (id) ON DELETE SET NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS organizations_type_name ON organizations (org_type, name);
+
+-- Entries keep their free-text company or school, the organization link is optional.
+ALTER TABLE experiences ADD COLUMN organization_id INTEGER REFERENCES organizations (id) ON DELETE SET NULL;
+ALTER TABLE educations ADD COLUMN organization_id INTEGER REFERENCES organizations (id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS experiences_organization ON experiences (organization_id);
+CREATE INDEX IF NOT EXISTS educations_organization ON educations (organization_id);
+
+-- Who may see a profile: 'public' for everyone, 'members' for logged-in users, 'private' for the owner only.
+ALTER TABLE profiles ADD COLUMN visibility VARCHAR(20) NOT NULL DEFAULT 'public';
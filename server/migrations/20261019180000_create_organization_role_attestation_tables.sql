@@ -0,0 +1,20 @@
+CREATE TABLE IF NOT EXISTS organization_roles
+(
+    id              INTEGER PRIMARY KEY,
+    organization_id INTEGER     NOT NULL REFERENCES organizations (id) ON DELETE CASCADE,
+    user_id         INTEGER     NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    role            VARCHAR(20) NOT NULL,
+    created_at      TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (organization_id, user_id)
+);
+
+-- The signature covers the verified fields of the experience, an attestation only counts while they are unchanged.
+CREATE TABLE IF NOT EXISTS experience_attestations
+(
+    id              INTEGER PRIMARY KEY,
+    experience_id   INTEGER NOT NULL UNIQUE REFERENCES experiences (id) ON DELETE CASCADE,
+    organization_id INTEGER NOT NULL REFERENCES organizations (id) ON DELETE CASCADE,
+    attester_id     INTEGER REFERENCES users (id) ON DELETE SET NULL,
+    signature       TEXT    NOT NULL,
+    created_at      TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
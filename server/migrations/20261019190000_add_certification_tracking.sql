@@ -0,0 +1,6 @@
+-- Result of the last credential link check: 'reachable', 'broken', or null while unchecked.
+ALTER TABLE certification ADD COLUMN credential_url_status VARCHAR(20);
+ALTER TABLE certification ADD COLUMN credential_url_checked_at TIMESTAMP;
+
+-- The expiration date the owner was last notified about, so every expiration is announced once.
+ALTER TABLE certification ADD COLUMN expiry_notified_for TEXT;
@@ -0,0 +1,4 @@
+-- The raw Open Badges assertion of an imported certification: JSON, or a compact JWS / JWT.
+ALTER TABLE certification ADD COLUMN badge_assertion TEXT;
+ALTER TABLE certification ADD COLUMN badge_format VARCHAR(20);
+ALTER TABLE certification ADD COLUMN badge_verification VARCHAR(20);
@@ -0,0 +1,37 @@
+-- The registry of contact information types. See `ContactTypeModel` for the meaning of the columns.
+CREATE TABLE IF NOT EXISTS contact_types
+(
+    name           VARCHAR(30) PRIMARY KEY,
+    label          VARCHAR(50) NOT NULL,
+    value_format   VARCHAR(20) NOT NULL,
+    pattern        VARCHAR(255),
+    hosts          VARCHAR(255),
+    path_prefix    VARCHAR(50),
+    url_template   VARCHAR(255),
+    case_sensitive BOOLEAN     NOT NULL DEFAULT FALSE,
+    has_label      BOOLEAN     NOT NULL DEFAULT FALSE,
+    is_enabled     BOOLEAN     NOT NULL DEFAULT TRUE,
+    created_at     TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- The types that used to be built in keep their names, so existing entries keep working.
+INSERT INTO contact_types (name, label, value_format, pattern, hosts, path_prefix, url_template, has_label)
+VALUES ('email', 'Email', 'email', NULL, NULL, NULL, NULL, FALSE),
+       ('phone', 'Phone', 'phone', NULL, NULL, NULL, NULL, FALSE),
+       ('website', 'Website', 'url', NULL, NULL, NULL, NULL, FALSE),
+       ('linkedin', 'LinkedIn', 'handle', '[a-z0-9-]{3,100}', 'linkedin.com', 'in', 'https://www.linkedin.com/in/{value}', FALSE),
+       ('github', 'GitHub', 'handle', '[a-z0-9](?:-?[a-z0-9]){0,38}', 'github.com', NULL, 'https://github.com/{value}', FALSE),
+       ('twitter', 'Twitter', 'handle', '[a-z0-9_]{1,15}', 'twitter.com,x.com', NULL, 'https://x.com/{value}', FALSE),
+       ('facebook', 'Facebook', 'handle', '[a-z0-9.]{5,50}', 'facebook.com,fb.com', NULL, 'https://www.facebook.com/{value}', FALSE),
+       ('instagram', 'Instagram', 'handle', '[a-z0-9._]{1,30}', 'instagram.com', NULL, 'https://www.instagram.com/{value}', FALSE),
+       ('mastodon', 'Mastodon', 'fediverse', NULL, NULL, NULL, 'https://{host}/@{user}', FALSE),
+       ('matrix', 'Matrix', 'matrix', NULL, NULL, NULL, 'https://matrix.to/#/{value}', FALSE),
+       ('signal', 'Signal', 'handle', '[a-z_][a-z0-9_]{2,31}\.[0-9]{2,9}', NULL, NULL, NULL, FALSE),
+       ('bluesky', 'Bluesky', 'handle', '(?:[a-z0-9](?:[a-z0-9-]{0,61}[a-z0-9])?\.)+[a-z]{2,63}', 'bsky.app', 'profile', 'https://bsky.app/profile/{value}', FALSE),
+       ('gitlab', 'GitLab', 'handle', '[a-z0-9_][a-z0-9_.-]{1,254}', 'gitlab.com', NULL, 'https://gitlab.com/{value}', FALSE),
+       ('stackoverflow', 'Stack Overflow', 'handle', '[0-9]{1,12}', 'stackoverflow.com', 'users', 'https://stackoverflow.com/users/{value}', FALSE),
+       ('discord', 'Discord', 'handle', '[a-z0-9_.]{2,32}', NULL, NULL, NULL, FALSE),
+       ('custom', 'Custom link', 'url', NULL, NULL, NULL, NULL, TRUE);
+
+-- The label users give entries of types like `custom`.
+ALTER TABLE contact_information ADD COLUMN label VARCHAR(50);
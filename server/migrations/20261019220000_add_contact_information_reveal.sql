@@ -0,0 +1,18 @@
+-- Who sees the value of a contact entry: 'public' for everyone, 'masked' masked until revealed,
+-- 'members' masked until revealed by a logged-in user.
+ALTER TABLE contact_information ADD COLUMN visibility VARCHAR(20) NOT NULL DEFAULT 'public';
+
+-- Reveals of masked contact entries, shown to their owners and used to rate limit viewers.
+-- The viewer key is a SHA-256 hash of the viewer's user ID or IP address.
+CREATE TABLE IF NOT EXISTS contact_reveals
+(
+    id                     INTEGER PRIMARY KEY,
+    contact_information_id INTEGER     NOT NULL REFERENCES contact_information (id) ON DELETE CASCADE,
+    owner_id               INTEGER     NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    viewer_id              INTEGER REFERENCES users (id) ON DELETE SET NULL,
+    viewer_key             VARCHAR(64) NOT NULL,
+    created_at             TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS contact_reveals_viewer ON contact_reveals (viewer_key, created_at);
+CREATE INDEX IF NOT EXISTS contact_reveals_owner ON contact_reveals (owner_id, created_at);
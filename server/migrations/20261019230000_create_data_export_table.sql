@@ -0,0 +1,15 @@
+-- Personal data exports, built in the background. The archive is kept until the download link expires.
+-- Status: 'pending' while the archive is built, then 'ready' or 'failed'.
+CREATE TABLE IF NOT EXISTS data_exports
+(
+    id           INTEGER PRIMARY KEY,
+    user_id      INTEGER     NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    token        VARCHAR(64) UNIQUE NOT NULL,
+    status       VARCHAR(20) NOT NULL DEFAULT 'pending',
+    archive      BLOB,
+    created_at   TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    completed_at TIMESTAMP,
+    expires_at   TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS data_exports_user ON data_exports (user_id, created_at);
@@ -0,0 +1,10 @@
+-- Requests for a recommendation, kept so a user has at most one open request per author and can be rate limited.
+CREATE TABLE IF NOT EXISTS recommendation_requests
+(
+    id           INTEGER PRIMARY KEY,
+    requester_id INTEGER NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    author_id    INTEGER NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    created_at   TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS recommendation_requests_requester ON recommendation_requests (requester_id, created_at);
@@ -0,0 +1,3 @@
+-- When the user confirmed they receive mail at their account email, NULL while it is unconfirmed.
+-- Imported badges are only verified against a confirmed account email.
+ALTER TABLE users ADD COLUMN email_verified_at TIMESTAMP;
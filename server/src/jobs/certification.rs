@@ -0,0 +1,201 @@
+use std::io;
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
+use std::time::Duration;
+
+use crate::models::certification::CertificationStatus;
+use crate::models::date::ProfileDate;
+use crate::models::notification::NotificationKind;
+use crate::response::error_handling::AppError;
+use crate::services::certification_service::{certification_status, CertificationService};
+use crate::services::notification_service::NotificationService;
+
+const EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(12 * 60 * 60);
+const LINK_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const LINK_CHECK_BATCH_SIZE: i32 = 50;
+const LINK_RECHECK_AFTER_DAYS: i64 = 7;
+const LINK_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Spawns the job that notifies owners of certifications that are about to expire.
+///
+/// Every expiration date is announced once. Certifications that were already expired when the job first saw them
+/// are recorded without a notification.
+///
+pub fn spawn_certification_expiry_job(
+    certification_service: CertificationService,
+    notification_service: NotificationService,
+) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(EXPIRY_CHECK_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            if notify_expiring_certifications(&certification_service, &notification_service)
+                .await
+                .is_err()
+            {
+                tracing::error!("Error checking certification expiry");
+            }
+        }
+    });
+}
+
+async fn notify_expiring_certifications(
+    certification_service: &CertificationService,
+    notification_service: &NotificationService,
+) -> Result<(), AppError> {
+    let today = ProfileDate::today();
+
+    for certification in certification_service
+        .get_unnotified_expiring_certifications()
+        .await?
+    {
+        match certification_status(&Some(certification.expiration_date), today) {
+            CertificationStatus::Valid => continue,
+            CertificationStatus::ExpiringSoon => {
+                notification_service
+                    .notify(
+                        certification.user_id,
+                        NotificationKind::CertificationExpiring,
+                        format!(
+                            "Your certification {} expires on {}",
+                            certification.name,
+                            certification.expiration_date.last_day()
+                        ),
+                        Some(certification.id),
+                    )
+                    .await?;
+            }
+            CertificationStatus::Expired => {}
+        }
+
+        certification_service
+            .mark_expiry_notified(certification.id, certification.expiration_date)
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Spawns the job that checks whether the credential links of certifications can be reached.
+///
+/// Links are rechecked once their last result is a week old. Only public addresses are contacted.
+///
+pub fn spawn_credential_link_check_job(certification_service: CertificationService) {
+    tokio::spawn(async move {
+        let agent = ureq::AgentBuilder::new()
+            .timeout(LINK_CHECK_TIMEOUT)
+            .resolver(resolve_public_addresses)
+            .build();
+        let mut interval = tokio::time::interval(LINK_CHECK_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let links = match certification_service
+                .get_credential_links_due(LINK_RECHECK_AFTER_DAYS, LINK_CHECK_BATCH_SIZE)
+                .await
+            {
+                Ok(links) => links,
+                Err(_) => {
+                    tracing::error!("Error loading credential links to check");
+                    continue;
+                }
+            };
+
+            for link in links {
+                let agent = agent.clone();
+                let url = link.credential_url.clone();
+                let reachable = tokio::task::spawn_blocking(move || is_reachable(&agent, &url))
+                    .await
+                    .unwrap_or(false);
+
+                if certification_service
+                    .set_credential_url_status(&link, reachable)
+                    .await
+                    .is_err()
+                {
+                    tracing::error!("Error storing credential link status");
+                }
+            }
+        }
+    });
+}
+
+/// Requests a link with `HEAD`, falling back to `GET` for servers that reject `HEAD`.
+fn is_reachable(agent: &ureq::Agent, url: &str) -> bool {
+    match agent.head(url).call() {
+        Ok(_) => true,
+        Err(ureq::Error::Status(_, _)) => agent.get(url).call().is_ok(),
+        Err(_) => false,
+    }
+}
+
+/// Resolves a host, dropping loopback, private and other non-public addresses.
+fn resolve_public_addresses(netloc: &str) -> io::Result<Vec<SocketAddr>> {
+    let addresses = netloc
+        .to_socket_addrs()?
+        .filter(|address| is_public_address(address.ip()))
+        .collect::<Vec<SocketAddr>>();
+
+    if addresses.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "host does not resolve to a public address",
+        ));
+    }
+
+    Ok(addresses)
+}
+
+fn is_public_address(address: IpAddr) -> bool {
+    match address {
+        IpAddr::V4(address) => {
+            let [first, second, ..] = address.octets();
+            !(address.is_loopback()
+                || address.is_private()
+                || address.is_link_local()
+                || address.is_unspecified()
+                || address.is_broadcast()
+                || address.is_documentation()
+                || first == 0
+                || (first == 100 && (64..128).contains(&second)))
+        }
+        IpAddr::V6(address) => match address.to_ipv4_mapped() {
+            Some(mapped) => is_public_address(IpAddr::V4(mapped)),
+            None => {
+                let first = address.segments()[0];
+                !(address.is_loopback()
+                    || address.is_unspecified()
+                    || (first & 0xfe00) == 0xfc00
+                    || (first & 0xffc0) == 0xfe80)
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_public_address;
+
+    #[test]
+    fn test_is_public_address() {
+        let public = |address: &str| is_public_address(address.parse().unwrap());
+
+        assert!(public("93.184.216.34"));
+        assert!(public("2606:2800:220:1:248:1893:25c8:1946"));
+
+        // Test with addresses that must not be contacted
+        assert!(!public("127.0.0.1"));
+        assert!(!public("10.1.2.3"));
+        assert!(!public("172.16.0.1"));
+        assert!(!public("192.168.1.1"));
+        assert!(!public("169.254.169.254"));
+        assert!(!public("100.64.0.1"));
+        assert!(!public("0.0.0.0"));
+        assert!(!public("::1"));
+        assert!(!public("fd00::1"));
+        assert!(!public("fe80::1"));
+        assert!(!public("::ffff:127.0.0.1"));
+    }
+}
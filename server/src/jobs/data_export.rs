@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use crate::models::notification::NotificationKind;
+use crate::services::data_export_service::DataExportService;
+use crate::services::notification_service::NotificationService;
+
+const EXPIRED_EXPORT_CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Spawns the task that builds the archive of a data export and notifies its owner when the download is ready.
+///
+/// Exports that cannot be built are marked as failed, so the owner can request a new one.
+///
+pub fn spawn_data_export_build(
+    data_export_service: DataExportService,
+    notification_service: NotificationService,
+    export_id: i32,
+    user_id: i32,
+) {
+    tokio::spawn(async move {
+        let completed = match data_export_service.build_archive(user_id).await {
+            Ok(archive) => data_export_service
+                .complete_data_export(export_id, archive)
+                .await
+                .is_ok(),
+            Err(_) => false,
+        };
+
+        if !completed {
+            tracing::error!("Error building data export {}", export_id);
+            if data_export_service
+                .fail_data_export(export_id)
+                .await
+                .is_err()
+            {
+                tracing::error!("Error marking data export {} as failed", export_id);
+            }
+            return;
+        }
+
+        if notification_service
+            .notify(
+                user_id,
+                NotificationKind::DataExportReady,
+                "Your data export is ready to download".to_string(),
+                Some(export_id),
+            )
+            .await
+            .is_err()
+        {
+            tracing::error!("Error notifying about data export {}", export_id);
+        }
+    });
+}
+
+/// Spawns the job that deletes data exports whose download link has expired, together with their archives.
+///
+/// The job also fails exports whose build was lost, e.g. to a restart, and first runs right at startup.
+///
+pub fn spawn_expired_data_export_cleanup_job(data_export_service: DataExportService) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(EXPIRED_EXPORT_CLEANUP_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            if data_export_service.fail_stale_data_exports().await.is_err() {
+                tracing::error!("Error failing stale data exports");
+            }
+
+            if data_export_service
+                .delete_expired_data_exports()
+                .await
+                .is_err()
+            {
+                tracing::error!("Error deleting expired data exports");
+            }
+        }
+    });
+}
@@ -0,0 +1,2 @@
+pub mod certification;
+pub mod data_export;
@@ -12,18 +12,36 @@ use tower_sessions::cookie::time::Duration;
 use tower_sessions::{Expiry, MemoryStore, SessionManagerLayer};
 
 use crate::services::account_service::AccountService;
+use crate::services::attestation_service::{AttestationKey, AttestationService};
+use crate::services::award_service::AwardService;
 use crate::services::certification_service::CertificationService;
-use crate::services::contact_information_service::ContactInformationService;
+use crate::services::contact_information_service::{ContactInformationService, TrustedProxies};
+use crate::services::contact_type_service::ContactTypeService;
+use crate::services::custom_section_service::CustomSectionService;
+use crate::services::data_export_service::DataExportService;
 use crate::services::education_service::EducationService;
 use crate::services::experience_service::ExperienceService;
+use crate::services::language_service::LanguageService;
+use crate::services::notification_service::NotificationService;
+use crate::services::og_image_service::OgImageService;
+use crate::services::organization_service::OrganizationService;
+use crate::services::patent_service::PatentService;
 use crate::services::profile_service::ProfileService;
+use crate::services::project_service::ProjectService;
+use crate::services::publication_service::PublicationService;
+use crate::services::recommendation_service::RecommendationService;
+use crate::services::resume_service::ResumeService;
+use crate::services::skill_service::SkillService;
 use crate::services::user_service::UserService;
 
+mod jobs;
 mod models;
 mod response;
 mod router;
 mod routes;
 mod services;
+#[cfg(test)]
+mod test_utils;
 
 pub type IdenoPool = Pool<Sqlite>;
 pub type IdenoDBResult = SqliteQueryResult;
@@ -35,8 +53,23 @@ pub struct AppState {
     account_service: AccountService,
     certification_service: CertificationService,
     contact_information_service: ContactInformationService,
+    contact_type_service: ContactTypeService,
     education_service: EducationService,
     experience_service: ExperienceService,
+    skill_service: SkillService,
+    project_service: ProjectService,
+    language_service: LanguageService,
+    award_service: AwardService,
+    patent_service: PatentService,
+    publication_service: PublicationService,
+    custom_section_service: CustomSectionService,
+    recommendation_service: RecommendationService,
+    notification_service: NotificationService,
+    organization_service: OrganizationService,
+    attestation_service: AttestationService,
+    resume_service: ResumeService,
+    data_export_service: DataExportService,
+    og_image_service: OgImageService,
 }
 
 /// This is the main entry point for the server application.
@@ -44,7 +77,10 @@ pub struct AppState {
 /// It panics and stops execution when critical environmental variables are missing or a database connection cannot be established.
 ///
 /// # Note
-/// Environment variables used: `CORS_ORIGIN`, `DATABASE_URL` and optional `PORT` (default is 3000).
+/// Environment variables used: `CORS_ORIGIN`, which is also the origin of profile links, `DATABASE_URL`,
+/// `ATTESTATION_SECRET`, optional `PORT` (default is 3000), optional `CREDENTIAL_LINK_CHECK`, which enables checking
+/// certification credential links when set to `true`, and optional `PUBLIC_ORIGIN`, the origin the server is reachable
+/// at, which profile pages need to link their preview images.
 ///
 #[tokio::main]
 async fn main() {
@@ -60,10 +96,8 @@ async fn main() {
         .with_max_level(tracing::Level::INFO)
         .init();
 
-    let cors_origin = std::env::var("CORS_ORIGIN")
-        .expect("CORS_ORIGIN must be set")
-        .parse::<HeaderValue>()
-        .unwrap();
+    let client_origin = std::env::var("CORS_ORIGIN").expect("CORS_ORIGIN must be set");
+    let cors_origin = client_origin.parse::<HeaderValue>().unwrap();
 
     tracing::info!(name: "bootstrap", "CORS_ORIGIN: {}", cors_origin.to_str().unwrap());
 
@@ -81,7 +115,7 @@ async fn main() {
 
     let db_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
 
-    let db = SqlitePool::connect(&*db_url).await.unwrap();
+    let db = SqlitePool::connect(&db_url).await.unwrap();
 
     tracing::info!(name: "bootstrap", "Connected to database at {}", db_url);
 
@@ -91,13 +125,76 @@ async fn main() {
 
     tracing::info!(name: "bootstrap", "Starting server");
 
+    let trusted_proxies =
+        TrustedProxies::parse(&std::env::var("TRUSTED_PROXIES").unwrap_or_default())
+            .unwrap_or_else(|entry| {
+                tracing::error!(
+                    name: "bootstrap",
+                    "TRUSTED_PROXIES contains the invalid entry {}, no proxy is trusted",
+                    entry
+                );
+                TrustedProxies::default()
+            });
+
+    let attestation_key =
+        AttestationKey::from_secret(std::env::var("ATTESTATION_SECRET").ok().as_deref());
+
+    if !attestation_key.is_enabled() {
+        tracing::error!(
+            name: "bootstrap",
+            "ATTESTATION_SECRET is not set or still the example value, experience attestations are disabled"
+        );
+    }
+
     let user_service = UserService::new(db.clone());
-    let profile_service = ProfileService::new(db.clone());
+    let profile_service = ProfileService::new(db.clone(), &client_origin);
     let account_service = AccountService::new(db.clone());
     let certification_service = CertificationService::new(db.clone());
-    let contact_information_service = ContactInformationService::new(db.clone());
+    let contact_type_service = ContactTypeService::new(db.clone());
+    let contact_information_service = ContactInformationService::new(
+        db.clone(),
+        contact_type_service.clone(),
+        trusted_proxies,
+    );
     let education_service = EducationService::new(db.clone());
-    let experience_service = ExperienceService::new(db.clone());
+    let experience_service = ExperienceService::new(db.clone(), attestation_key.clone());
+    let skill_service = SkillService::new(db.clone());
+    let project_service = ProjectService::new(db.clone());
+    let language_service = LanguageService::new(db.clone());
+    let award_service = AwardService::new(db.clone());
+    let patent_service = PatentService::new(db.clone());
+    let publication_service = PublicationService::new(db.clone());
+    let custom_section_service = CustomSectionService::new(db.clone());
+    let recommendation_service = RecommendationService::new(db.clone());
+    let notification_service = NotificationService::new(db.clone());
+    let organization_service = OrganizationService::new(db.clone());
+    let attestation_service = AttestationService::new(db.clone(), attestation_key);
+    let resume_service = ResumeService::new(db.clone());
+    let data_export_service = DataExportService::new(db.clone());
+    let public_origin = std::env::var("PUBLIC_ORIGIN").ok();
+
+    match &public_origin {
+        Some(public_origin) => {
+            tracing::info!(name: "bootstrap", "PUBLIC_ORIGIN: {}", public_origin)
+        }
+        None => tracing::warn!(
+            name: "bootstrap",
+            "PUBLIC_ORIGIN is not set, profile pages will not advertise preview images"
+        ),
+    }
+
+    let og_image_service = OgImageService::new(public_origin.as_deref());
+
+    jobs::certification::spawn_certification_expiry_job(
+        certification_service.clone(),
+        notification_service.clone(),
+    );
+
+    jobs::data_export::spawn_expired_data_export_cleanup_job(data_export_service.clone());
+
+    if std::env::var("CREDENTIAL_LINK_CHECK").is_ok_and(|value| value == "true") {
+        jobs::certification::spawn_credential_link_check_job(certification_service.clone());
+    }
 
     let state = AppState {
         user_service,
@@ -105,8 +202,23 @@ async fn main() {
         account_service,
         certification_service,
         contact_information_service,
+        contact_type_service,
         education_service,
         experience_service,
+        skill_service,
+        project_service,
+        language_service,
+        award_service,
+        patent_service,
+        publication_service,
+        custom_section_service,
+        recommendation_service,
+        notification_service,
+        organization_service,
+        attestation_service,
+        resume_service,
+        data_export_service,
+        og_image_service,
     };
 
     let router = router::router(cors, session_layer, state);
@@ -118,7 +230,10 @@ async fn main() {
 
     tracing::info!(name: "bootstrap", "Listening on {}", socket_addr);
 
-    axum::serve(listener, router.into_make_service())
-        .await
-        .unwrap()
+    axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .unwrap()
 }
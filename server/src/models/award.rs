@@ -0,0 +1,31 @@
+use crate::models::date::ProfileDate;
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct AwardModel {
+    pub id: i32,
+    pub user_id: i32,
+    pub title: String,
+    pub issuer: Option<String>,
+    pub award_date: Option<ProfileDate>,
+    pub description: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PublicAwardModel {
+    pub title: String,
+    pub issuer: Option<String>,
+    pub award_date: Option<ProfileDate>,
+    pub description: Option<String>,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct AuthAwardModel {
+    pub id: i32,
+    pub title: String,
+    pub issuer: Option<String>,
+    pub award_date: Option<ProfileDate>,
+    pub description: Option<String>,
+}
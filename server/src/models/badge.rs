@@ -0,0 +1,61 @@
+use crate::models::date::ProfileDate;
+use serde::Serialize;
+use sqlx::FromRow;
+
+/// The Open Badges version an imported badge was issued with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BadgeFormat {
+    OpenBadges2,
+    OpenBadges3,
+}
+
+impl BadgeFormat {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            BadgeFormat::OpenBadges2 => "open_badges_2",
+            BadgeFormat::OpenBadges3 => "open_badges_3",
+        }
+    }
+}
+
+/// The outcome of checking the signature of a badge.
+///
+/// Badges without a signature, signed with a key that cannot be resolved without contacting the issuer or
+/// that does not identify the issuer, or issued to someone else, are kept as unverified.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BadgeVerification {
+    Verified,
+    Unverified,
+}
+
+impl BadgeVerification {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            BadgeVerification::Verified => "verified",
+            BadgeVerification::Unverified => "unverified",
+        }
+    }
+}
+
+/// The certification fields read from an Open Badges assertion, together with the raw assertion.
+#[derive(Clone, Debug)]
+pub struct ImportedBadgeModel {
+    pub format: BadgeFormat,
+    pub verification: BadgeVerification,
+    pub name: String,
+    pub organization: String,
+    pub issue_date: Option<ProfileDate>,
+    pub expiration_date: Option<ProfileDate>,
+    pub credential_id: Option<String>,
+    pub credential_url: Option<String>,
+    pub assertion: String,
+}
+
+/// The badge stored with an imported certification.
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct CertificationBadgeModel {
+    pub badge_format: String,
+    pub badge_verification: String,
+    pub badge_assertion: String,
+}
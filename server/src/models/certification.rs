@@ -1,3 +1,4 @@
+use crate::models::date::ProfileDate;
 use serde::Serialize;
 use sqlx::FromRow;
 
@@ -7,10 +8,18 @@ pub struct CertificationModel {
     pub user_id: i32,
     pub name: String,
     pub organization: String,
-    pub issue_date: Option<String>,
-    pub expiration_date: Option<String>,
+    pub issue_date: Option<ProfileDate>,
+    pub expiration_date: Option<ProfileDate>,
+    /// A stored date that could not be read as a profile date, kept for the owner to enter it again.
+    pub issue_date_legacy: Option<String>,
+    /// A stored date that could not be read as a profile date, kept for the owner to enter it again.
+    pub expiration_date_legacy: Option<String>,
+    #[sqlx(skip)]
+    pub status: CertificationStatus,
     pub credential_id: Option<String>,
     pub credential_url: Option<String>,
+    pub credential_url_status: Option<String>,
+    pub badge_verification: Option<String>,
     pub created_at: String,
 }
 
@@ -18,10 +27,14 @@ pub struct CertificationModel {
 pub struct PublicCertificationModel {
     pub name: String,
     pub organization: String,
-    pub issue_date: Option<String>,
-    pub expiration_date: Option<String>,
+    pub issue_date: Option<ProfileDate>,
+    pub expiration_date: Option<ProfileDate>,
+    #[sqlx(skip)]
+    pub status: CertificationStatus,
     pub credential_id: Option<String>,
     pub credential_url: Option<String>,
+    pub credential_url_status: Option<String>,
+    pub badge_verification: Option<String>,
 }
 
 #[derive(Clone, FromRow, Debug, Serialize)]
@@ -29,8 +42,42 @@ pub struct AuthCertificationModel {
     pub id: i32,
     pub name: String,
     pub organization: String,
-    pub issue_date: Option<String>,
-    pub expiration_date: Option<String>,
+    pub issue_date: Option<ProfileDate>,
+    pub expiration_date: Option<ProfileDate>,
+    /// A stored date that could not be read as a profile date, kept for the owner to enter it again.
+    pub issue_date_legacy: Option<String>,
+    /// A stored date that could not be read as a profile date, kept for the owner to enter it again.
+    pub expiration_date_legacy: Option<String>,
+    #[sqlx(skip)]
+    pub status: CertificationStatus,
     pub credential_id: Option<String>,
     pub credential_url: Option<String>,
-}
\ No newline at end of file
+    pub credential_url_status: Option<String>,
+    pub badge_verification: Option<String>,
+}
+
+/// Whether a certification is still valid, derived from its expiration date.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CertificationStatus {
+    #[default]
+    Valid,
+    ExpiringSoon,
+    Expired,
+}
+
+/// A certification whose expiration the owner has not been notified about yet.
+#[derive(Clone, FromRow, Debug)]
+pub struct ExpiringCertificationModel {
+    pub id: i32,
+    pub user_id: i32,
+    pub name: String,
+    pub expiration_date: ProfileDate,
+}
+
+/// A certification whose credential link is due for a check.
+#[derive(Clone, FromRow, Debug)]
+pub struct CredentialLinkModel {
+    pub id: i32,
+    pub credential_url: String,
+}
@@ -6,19 +6,62 @@ pub struct ContactInformationModel {
     pub id: i32,
     pub user_id: i32,
     pub type_field: String,
+    pub label: Option<String>,
     pub value: String,
+    #[sqlx(skip)]
+    pub url: Option<String>,
+    pub visibility: String,
     pub created_at: String,
 }
 
+/// A contact information entry as shown to other users.
+///
+/// Entries that are not public carry a masked value and no link, until revealed through the reveal endpoint.
+///
 #[derive(Clone, FromRow, Debug, Serialize)]
 pub struct PublicContactInformationModel {
+    pub id: i32,
     pub type_field: String,
+    pub label: Option<String>,
     pub value: String,
+    #[sqlx(skip)]
+    pub url: Option<String>,
+    pub visibility: String,
+    #[sqlx(skip)]
+    pub masked: bool,
 }
 
 #[derive(Clone, FromRow, Debug, Serialize)]
 pub struct AuthContactInformationModel {
     pub id: i32,
     pub type_field: String,
+    pub label: Option<String>,
     pub value: String,
+    #[sqlx(skip)]
+    pub url: Option<String>,
+    pub visibility: String,
+}
+
+/// A reveal of one of the masked contact entries of a user. Anonymous viewers have no username.
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct ContactRevealModel {
+    pub contact_information_id: i32,
+    pub type_field: String,
+    pub label: Option<String>,
+    pub viewer_username: Option<String>,
+    pub created_at: String,
+}
+
+impl From<AuthContactInformationModel> for PublicContactInformationModel {
+    fn from(entry: AuthContactInformationModel) -> Self {
+        PublicContactInformationModel {
+            id: entry.id,
+            type_field: entry.type_field,
+            label: entry.label,
+            value: entry.value,
+            url: entry.url,
+            visibility: entry.visibility,
+            masked: false,
+        }
+    }
 }
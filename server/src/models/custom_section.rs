@@ -0,0 +1,72 @@
+use crate::models::date::ProfileDate;
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PublicCustomSectionModel {
+    #[serde(skip)]
+    pub id: i32,
+    pub title: String,
+    #[sqlx(skip)]
+    pub entries: Vec<PublicCustomSectionEntryModel>,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct AuthCustomSectionModel {
+    pub id: i32,
+    pub title: String,
+    pub position: i32,
+    pub visibility: String,
+    #[sqlx(skip)]
+    pub entries: Vec<AuthCustomSectionEntryModel>,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PublicCustomSectionEntryModel {
+    #[serde(skip)]
+    pub section_id: i32,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    pub body: Option<String>,
+    pub link: Option<String>,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct AuthCustomSectionEntryModel {
+    pub id: i32,
+    #[serde(skip)]
+    pub section_id: i32,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    pub body: Option<String>,
+    pub link: Option<String>,
+    pub position: i32,
+}
+
+impl From<AuthCustomSectionModel> for PublicCustomSectionModel {
+    fn from(section: AuthCustomSectionModel) -> Self {
+        PublicCustomSectionModel {
+            id: section.id,
+            title: section.title,
+            entries: section.entries.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<AuthCustomSectionEntryModel> for PublicCustomSectionEntryModel {
+    fn from(entry: AuthCustomSectionEntryModel) -> Self {
+        PublicCustomSectionEntryModel {
+            section_id: entry.section_id,
+            title: entry.title,
+            subtitle: entry.subtitle,
+            start_date: entry.start_date,
+            end_date: entry.end_date,
+            body: entry.body,
+            link: entry.link,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Clone, FromRow, Debug)]
+pub struct DataExportModel {
+    pub id: i32,
+    pub token: String,
+    pub status: String,
+    pub created_at: String,
+    pub completed_at: Option<String>,
+    pub expires_at: Option<String>,
+}
+
+/// A data export as shown to its owner, with the download link while the archive can be downloaded.
+#[derive(Clone, Debug, Serialize)]
+pub struct AuthDataExportModel {
+    pub id: i32,
+    pub status: String,
+    pub created_at: String,
+    pub completed_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub download_url: Option<String>,
+}
+
+impl From<DataExportModel> for AuthDataExportModel {
+    fn from(export: DataExportModel) -> Self {
+        let download_url = match DataExportStatus::from_str(&export.status) {
+            Some(DataExportStatus::Ready) => Some(format!("/api/v1/data-export/{}", export.token)),
+            _ => None,
+        };
+
+        AuthDataExportModel {
+            id: export.id,
+            status: export.status,
+            created_at: export.created_at,
+            completed_at: export.completed_at,
+            expires_at: export.expires_at,
+            download_url,
+        }
+    }
+}
+
+/// The states of a data export. Expired exports are deleted, together with their archive.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum DataExportStatus {
+    Pending,
+    Ready,
+    Failed,
+}
+
+impl DataExportStatus {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            DataExportStatus::Pending => "pending",
+            DataExportStatus::Ready => "ready",
+            DataExportStatus::Failed => "failed",
+        }
+    }
+
+    pub(crate) fn from_str(s: &str) -> Option<DataExportStatus> {
+        match s {
+            "pending" => Some(DataExportStatus::Pending),
+            "ready" => Some(DataExportStatus::Ready),
+            "failed" => Some(DataExportStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        for status in [
+            DataExportStatus::Pending,
+            DataExportStatus::Ready,
+            DataExportStatus::Failed,
+        ] {
+            assert_eq!(DataExportStatus::from_str(status.as_str()), Some(status));
+        }
+        assert_eq!(DataExportStatus::from_str("expired"), None);
+    }
+}
@@ -0,0 +1,438 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::sqlite::{SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+use sqlx::{Decode, Encode, Sqlite, Type};
+
+/// A date on a profile entry, known to the year, the month or the exact day.
+///
+/// Profile dates are serialized, deserialized and stored in exactly one format, chosen by their precision:
+///
+/// * `YYYY` - year precision, e.g. `2021`
+/// * `YYYY-MM` - month precision, e.g. `2021-09`
+/// * `YYYY-MM-DD` - day precision, e.g. `2021-09-14`
+///
+/// Any other input, including impossible calendar dates like `2021-02-30`, is rejected.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProfileDate {
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+impl ProfileDate {
+    /// Parses a profile date in one of the formats `YYYY`, `YYYY-MM` or `YYYY-MM-DD`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The string to parse.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(ProfileDate)` if the string is a valid date in one of the supported formats, otherwise `None`.
+    ///
+    pub fn parse(value: &str) -> Option<ProfileDate> {
+        let mut parts = value.split('-');
+
+        let year = parse_number(parts.next()?, 4)?;
+        let month = match parts.next() {
+            Some(part) => Some(parse_number(part, 2)?),
+            None => None,
+        };
+        let day = match parts.next() {
+            Some(part) => Some(parse_number(part, 2)?),
+            None => None,
+        };
+
+        if parts.next().is_some() || year == 0 {
+            return None;
+        }
+
+        if let Some(month) = month {
+            if !(1..=12).contains(&month) {
+                return None;
+            }
+
+            if let Some(day) = day {
+                if day == 0 || day > days_in_month(year, month) {
+                    return None;
+                }
+            }
+        }
+
+        Some(ProfileDate {
+            year,
+            month: month.map(|month| month as u8),
+            day: day.map(|day| day as u8),
+        })
+    }
+
+    /// Returns the current date in UTC with day precision.
+    pub fn today() -> ProfileDate {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() / 86_400)
+            .unwrap_or(0);
+
+        ProfileDate::from_days_since_epoch(days as i64)
+    }
+
+    /// Returns the year of the date.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month of the date, from 1 to 12, if the date is known to the month.
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+
+    /// Returns the day of the month of the date, if the date is known to the day.
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    /// Converts a number of days since 1970-01-01 into a date with day precision.
+    fn from_days_since_epoch(days: i64) -> ProfileDate {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        ProfileDate {
+            year: year as u16,
+            month: Some(month as u8),
+            day: Some(day as u8),
+        }
+    }
+
+    /// Converts the first day this date may refer to into a number of days since 1970-01-01.
+    pub fn days_since_epoch(&self) -> i64 {
+        let month = i64::from(self.month.unwrap_or(1));
+        let day = i64::from(self.day.unwrap_or(1));
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+        let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Returns the last day this date may refer to, with day precision.
+    ///
+    /// A year-only date ends on December 31st, a month-only date on the last day of its month.
+    ///
+    pub fn last_day(&self) -> ProfileDate {
+        let month = self.month.unwrap_or(12);
+        let day = self
+            .day
+            .unwrap_or_else(|| days_in_month(self.year, u16::from(month)) as u8);
+
+        ProfileDate {
+            year: self.year,
+            month: Some(month),
+            day: Some(day),
+        }
+    }
+
+    /// Creates a date with month precision from a month index as returned by `first_month`.
+    pub fn from_month_index(index: i32) -> ProfileDate {
+        ProfileDate {
+            year: index.div_euclid(12) as u16,
+            month: Some(index.rem_euclid(12) as u8 + 1),
+            day: None,
+        }
+    }
+
+    /// Returns the index of the first month this date may refer to, counted in months since year zero.
+    ///
+    /// A year-only date starts in January.
+    ///
+    pub fn first_month(&self) -> i32 {
+        i32::from(self.year) * 12 + i32::from(self.month.unwrap_or(1)) - 1
+    }
+
+    /// Returns the index of the last month this date may refer to, counted in months since year zero.
+    ///
+    /// A year-only date ends in December.
+    ///
+    pub fn last_month(&self) -> i32 {
+        i32::from(self.year) * 12 + i32::from(self.month.unwrap_or(12)) - 1
+    }
+
+    /// Compares two dates at the coarser of their precisions.
+    ///
+    /// `2021` and `2021-09` are considered equal, as the first one does not say which month is meant.
+    ///
+    pub fn compare_at_shared_precision(&self, other: &ProfileDate) -> Ordering {
+        self.year
+            .cmp(&other.year)
+            .then_with(|| match (self.month, other.month) {
+                (Some(month), Some(other_month)) => month.cmp(&other_month),
+                _ => Ordering::Equal,
+            })
+            .then_with(|| match (self.day, other.day) {
+                (Some(day), Some(other_day)) => day.cmp(&other_day),
+                _ => Ordering::Equal,
+            })
+    }
+
+    /// Checks that an optional end date does not come before an optional start date.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if either date is missing or the end date is not before the start date.
+    ///
+    pub fn is_valid_range(start: &Option<ProfileDate>, end: &Option<ProfileDate>) -> bool {
+        match (start, end) {
+            (Some(start), Some(end)) => end.compare_at_shared_precision(start) != Ordering::Less,
+            _ => true,
+        }
+    }
+}
+
+fn parse_number(part: &str, digits: usize) -> Option<u16> {
+    if part.len() != digits || !part.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    part.parse().ok()
+}
+
+fn days_in_month(year: u16, month: u16) -> u16 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for ProfileDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Serialize for ProfileDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ProfileDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+
+        ProfileDate::parse(&value).ok_or_else(|| invalid_date::<D>(&value))
+    }
+}
+
+/// Deserializes an optional profile date, treating an empty or blank string like a missing date.
+///
+/// Forms send an empty string for a date field that was left blank, so payloads deserialize their dates with
+/// `#[serde(default, deserialize_with = "deserialize_optional_date")]`.
+///
+/// # Errors
+///
+/// Returns an error if the value is neither missing, blank nor a valid profile date.
+///
+pub fn deserialize_optional_date<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<ProfileDate>, D::Error> {
+    match Option::<String>::deserialize(deserializer)? {
+        Some(value) if !value.trim().is_empty() => ProfileDate::parse(&value)
+            .map(Some)
+            .ok_or_else(|| invalid_date::<D>(&value)),
+        _ => Ok(None),
+    }
+}
+
+fn invalid_date<'de, D: Deserializer<'de>>(value: &str) -> D::Error {
+    serde::de::Error::custom(format!(
+        "invalid date `{}`, expected YYYY, YYYY-MM or YYYY-MM-DD",
+        value
+    ))
+}
+
+impl Type<Sqlite> for ProfileDate {
+    fn type_info() -> SqliteTypeInfo {
+        <String as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <String as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for ProfileDate {
+    fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+        <String as Encode<'q, Sqlite>>::encode(self.to_string(), args)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for ProfileDate {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        let value = <&str as Decode<Sqlite>>::decode(value)?;
+
+        ProfileDate::parse(value).ok_or_else(|| format!("invalid profile date `{}`", value).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{deserialize_optional_date, ProfileDate};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(ProfileDate::parse("2021").unwrap().to_string(), "2021");
+        assert_eq!(ProfileDate::parse("2021-09").unwrap().to_string(), "2021-09");
+        assert_eq!(
+            ProfileDate::parse("2021-09-14").unwrap().to_string(),
+            "2021-09-14"
+        );
+        assert_eq!(
+            ProfileDate::parse("2024-02-29").unwrap().to_string(),
+            "2024-02-29"
+        );
+
+        // Test with strings that are not valid profile dates
+        assert_eq!(ProfileDate::parse(""), None);
+        assert_eq!(ProfileDate::parse("21"), None);
+        assert_eq!(ProfileDate::parse("0000"), None);
+        assert_eq!(ProfileDate::parse("2021-9"), None);
+        assert_eq!(ProfileDate::parse("2021-13"), None);
+        assert_eq!(ProfileDate::parse("2023-02-29"), None);
+        assert_eq!(ProfileDate::parse("2021-09-14T10:00:00"), None);
+        assert_eq!(ProfileDate::parse("2021-09-14-01"), None);
+        assert_eq!(ProfileDate::parse("September 2021"), None);
+    }
+
+    #[test]
+    fn test_is_valid_range() {
+        let date = |value: &str| ProfileDate::parse(value);
+
+        assert!(ProfileDate::is_valid_range(&date("2020"), &date("2021")));
+        assert!(ProfileDate::is_valid_range(&date("2021-03"), &date("2021")));
+        assert!(ProfileDate::is_valid_range(&date("2021-03-01"), &date("2021-03")));
+        assert!(ProfileDate::is_valid_range(&date("2021-03-01"), &None));
+        assert!(ProfileDate::is_valid_range(&None, &date("2021")));
+
+        assert!(!ProfileDate::is_valid_range(&date("2021"), &date("2020-12")));
+        assert!(!ProfileDate::is_valid_range(&date("2021-03"), &date("2021-02-28")));
+        assert!(!ProfileDate::is_valid_range(&date("2021-03-02"), &date("2021-03-01")));
+    }
+
+    #[test]
+    fn test_months() {
+        let year = ProfileDate::parse("2021").unwrap();
+        assert_eq!(
+            ProfileDate::from_month_index(year.first_month()).to_string(),
+            "2021-01"
+        );
+        assert_eq!(
+            ProfileDate::from_month_index(year.last_month()).to_string(),
+            "2021-12"
+        );
+
+        let day = ProfileDate::parse("2021-09-14").unwrap();
+        assert_eq!(day.first_month(), day.last_month());
+        assert_eq!(
+            ProfileDate::from_month_index(day.first_month()).to_string(),
+            "2021-09"
+        );
+    }
+
+    #[test]
+    fn test_from_days_since_epoch() {
+        assert_eq!(
+            ProfileDate::from_days_since_epoch(0).to_string(),
+            "1970-01-01"
+        );
+        assert_eq!(
+            ProfileDate::from_days_since_epoch(11_016).to_string(),
+            "2000-02-29"
+        );
+        assert_eq!(
+            ProfileDate::from_days_since_epoch(19_000).to_string(),
+            "2022-01-08"
+        );
+    }
+
+    #[test]
+    fn test_days_since_epoch() {
+        for days in [0, 59, 11_016, 19_000, 20_000] {
+            assert_eq!(
+                ProfileDate::from_days_since_epoch(days).days_since_epoch(),
+                days
+            );
+        }
+        assert_eq!(ProfileDate::parse("2021").unwrap().days_since_epoch(), 18_628);
+    }
+
+    #[test]
+    fn test_last_day() {
+        let last_day = |value: &str| ProfileDate::parse(value).unwrap().last_day().to_string();
+
+        assert_eq!(last_day("2021"), "2021-12-31");
+        assert_eq!(last_day("2024-02"), "2024-02-29");
+        assert_eq!(last_day("2023-02"), "2023-02-28");
+        assert_eq!(last_day("2021-09-14"), "2021-09-14");
+    }
+
+    #[test]
+    fn test_serde() {
+        let date: ProfileDate = serde_json::from_str("\"2021-09\"").unwrap();
+        assert_eq!(serde_json::to_string(&date).unwrap(), "\"2021-09\"");
+
+        assert!(serde_json::from_str::<ProfileDate>("\"2021-09-31\"").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_optional_date() {
+        #[derive(serde::Deserialize)]
+        struct Payload {
+            #[serde(default, deserialize_with = "deserialize_optional_date")]
+            date: Option<ProfileDate>,
+        }
+        let date = |json: &str| serde_json::from_str::<Payload>(json).map(|payload| payload.date);
+
+        assert_eq!(
+            date(r#"{ "date": "2021-09" }"#).unwrap(),
+            ProfileDate::parse("2021-09")
+        );
+        assert_eq!(date(r#"{ "date": "" }"#).unwrap(), None);
+        assert_eq!(date(r#"{ "date": "  " }"#).unwrap(), None);
+        assert_eq!(date(r#"{ "date": null }"#).unwrap(), None);
+        assert_eq!(date("{}").unwrap(), None);
+
+        assert!(date(r#"{ "date": "2021-09-31" }"#).is_err());
+    }
+}
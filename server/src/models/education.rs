@@ -1,3 +1,4 @@
+use crate::models::date::ProfileDate;
 use serde::Serialize;
 use sqlx::FromRow;
 
@@ -6,28 +7,40 @@ pub struct EducationModel {
     pub id: i32,
     pub user_id: i32,
     pub school: String,
+    pub organization_id: Option<i32>,
     pub degree: Option<String>,
     pub field: Option<String>,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    /// A stored date that could not be read as a profile date, kept for the owner to enter it again.
+    pub start_date_legacy: Option<String>,
+    /// A stored date that could not be read as a profile date, kept for the owner to enter it again.
+    pub end_date_legacy: Option<String>,
     pub created_at: String,
 }
 
 #[derive(Clone, FromRow, Debug, Serialize)]
 pub struct PublicEducationModel {
     pub school: String,
+    pub organization_slug: Option<String>,
     pub degree: Option<String>,
     pub field: Option<String>,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
 }
 
 #[derive(Clone, FromRow, Debug, Serialize)]
 pub struct AuthEducationModel {
     pub id: i32,
     pub school: String,
+    pub organization_id: Option<i32>,
+    pub organization_slug: Option<String>,
     pub degree: Option<String>,
     pub field: Option<String>,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    /// A stored date that could not be read as a profile date, kept for the owner to enter it again.
+    pub start_date_legacy: Option<String>,
+    /// A stored date that could not be read as a profile date, kept for the owner to enter it again.
+    pub end_date_legacy: Option<String>,
 }
\ No newline at end of file
@@ -1,3 +1,4 @@
+use crate::models::date::ProfileDate;
 use serde::Serialize;
 use sqlx::FromRow;
 
@@ -6,31 +7,169 @@ pub struct ExperienceModel {
     pub id: i32,
     pub user_id: i32,
     pub company: String,
+    pub organization_id: Option<i32>,
     pub title: String,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    /// A stored date that could not be read as a profile date, kept for the owner to enter it again.
+    pub start_date_legacy: Option<String>,
+    /// A stored date that could not be read as a profile date, kept for the owner to enter it again.
+    pub end_date_legacy: Option<String>,
+    pub is_current: bool,
     pub exp_type: Option<String>,
     pub description: Option<String>,
     pub created_at: String,
+    #[sqlx(skip)]
+    pub verified: bool,
 }
 
 #[derive(Clone, FromRow, Debug, Serialize)]
 pub struct PublicExperienceModel {
+    #[serde(skip)]
+    pub id: i32,
     pub company: String,
+    pub organization_slug: Option<String>,
     pub title: String,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    pub is_current: bool,
+    #[sqlx(skip)]
+    pub duration_months: Option<i32>,
     pub exp_type: Option<String>,
     pub description: Option<String>,
+    #[sqlx(skip)]
+    pub verified: bool,
 }
 
 #[derive(Clone, FromRow, Debug, Serialize)]
 pub struct AuthExperienceModel {
     pub id: i32,
     pub company: String,
+    pub organization_id: Option<i32>,
+    pub organization_slug: Option<String>,
     pub title: String,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    /// A stored date that could not be read as a profile date, kept for the owner to enter it again.
+    pub start_date_legacy: Option<String>,
+    /// A stored date that could not be read as a profile date, kept for the owner to enter it again.
+    pub end_date_legacy: Option<String>,
+    pub is_current: bool,
+    #[sqlx(skip)]
+    pub duration_months: Option<i32>,
     pub exp_type: Option<String>,
     pub description: Option<String>,
-}
\ No newline at end of file
+    #[sqlx(skip)]
+    pub verified: bool,
+}
+
+/// The fields of an experience covered by an attestation, together with the attestation signature.
+#[derive(Clone, FromRow, Debug)]
+pub struct AttestedExperienceModel {
+    pub id: i32,
+    pub user_id: i32,
+    pub organization_id: Option<i32>,
+    pub company: String,
+    pub title: String,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    pub is_current: bool,
+    pub signature: Option<String>,
+}
+
+/// An experience linked to an organization, as reviewed by the people verifying for the organization.
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct OrganizationExperienceModel {
+    pub id: i32,
+    pub username: String,
+    pub company: String,
+    pub title: String,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    pub is_current: bool,
+    #[serde(skip)]
+    pub user_id: i32,
+    #[serde(skip)]
+    pub organization_id: Option<i32>,
+    #[serde(skip)]
+    pub signature: Option<String>,
+    #[sqlx(skip)]
+    pub verified: bool,
+}
+
+/// Consecutive positions a user held at the same company, most recent first.
+#[derive(Clone, Debug, Serialize)]
+pub struct ExperienceGroupModel<T> {
+    pub company: String,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    pub is_current: bool,
+    pub tenure_months: Option<i32>,
+    pub positions: Vec<T>,
+}
+
+/// The parts of an experience needed to derive the experience metrics of a user.
+#[derive(Clone, FromRow, Debug)]
+pub struct ExperienceSpanModel {
+    pub company: String,
+    pub title: String,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    pub is_current: bool,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
+pub struct ExperienceMetricsModel {
+    pub total_months: i32,
+    pub total_years: f64,
+    pub gaps: Vec<ExperienceGapModel>,
+    pub current_title: Option<String>,
+    pub current_company: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq)]
+pub struct ExperienceGapModel {
+    pub start: ProfileDate,
+    pub end: ProfileDate,
+    pub months: i32,
+}
+
+impl From<&PublicExperienceModel> for ExperienceSpanModel {
+    fn from(experience: &PublicExperienceModel) -> Self {
+        ExperienceSpanModel {
+            company: experience.company.clone(),
+            title: experience.title.clone(),
+            start_date: experience.start_date,
+            end_date: experience.end_date,
+            is_current: experience.is_current,
+        }
+    }
+}
+
+impl From<&AuthExperienceModel> for ExperienceSpanModel {
+    fn from(experience: &AuthExperienceModel) -> Self {
+        ExperienceSpanModel {
+            company: experience.company.clone(),
+            title: experience.title.clone(),
+            start_date: experience.start_date,
+            end_date: experience.end_date,
+            is_current: experience.is_current,
+        }
+    }
+}
+
+impl From<&OrganizationExperienceModel> for AttestedExperienceModel {
+    fn from(experience: &OrganizationExperienceModel) -> Self {
+        AttestedExperienceModel {
+            id: experience.id,
+            user_id: experience.user_id,
+            organization_id: experience.organization_id,
+            company: experience.company.clone(),
+            title: experience.title.clone(),
+            start_date: experience.start_date,
+            end_date: experience.end_date,
+            is_current: experience.is_current,
+            signature: experience.signature.clone(),
+        }
+    }
+}
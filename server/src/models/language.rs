@@ -0,0 +1,24 @@
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct LanguageModel {
+    pub id: i32,
+    pub user_id: i32,
+    pub language_code: String,
+    pub level: String,
+    pub created_at: String,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PublicLanguageModel {
+    pub language_code: String,
+    pub level: String,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct AuthLanguageModel {
+    pub id: i32,
+    pub language_code: String,
+    pub level: String,
+}
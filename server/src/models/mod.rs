@@ -1,6 +1,21 @@
+pub mod award;
+pub mod badge;
 pub mod certification;
 pub mod contact_information;
+pub mod contact_type;
+pub mod custom_section;
+pub mod data_export;
+pub mod date;
 pub mod education;
 pub mod experience;
+pub mod language;
+pub mod notification;
+pub mod organization;
+pub mod patent;
 pub mod profile;
+pub mod project;
+pub mod publication;
+pub mod recommendation;
+pub mod resume;
+pub mod skill;
 pub mod user;
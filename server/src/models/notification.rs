@@ -0,0 +1,38 @@
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct NotificationModel {
+    pub id: i32,
+    pub kind: String,
+    pub message: String,
+    pub reference_id: Option<i32>,
+    pub read_at: Option<String>,
+    pub created_at: String,
+}
+
+/// The events a user can be notified about.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum NotificationKind {
+    RecommendationRequested,
+    RecommendationReceived,
+    RecommendationApproved,
+    RecommendationDeclined,
+    ExperienceVerified,
+    CertificationExpiring,
+    DataExportReady,
+}
+
+impl NotificationKind {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            NotificationKind::RecommendationRequested => "recommendation_requested",
+            NotificationKind::RecommendationReceived => "recommendation_received",
+            NotificationKind::RecommendationApproved => "recommendation_approved",
+            NotificationKind::RecommendationDeclined => "recommendation_declined",
+            NotificationKind::ExperienceVerified => "experience_verified",
+            NotificationKind::CertificationExpiring => "certification_expiring",
+            NotificationKind::DataExportReady => "data_export_ready",
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct OrganizationModel {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+    pub website: Option<String>,
+    pub logo_url: Option<String>,
+    pub org_type: String,
+    pub created_at: String,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PublicOrganizationModel {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+    pub website: Option<String>,
+    pub logo_url: Option<String>,
+    pub org_type: String,
+}
+
+/// A user linked to an organization through one of their experiences or educations.
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct OrganizationMemberModel {
+    pub username: String,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub headline: Option<String>,
+    pub role: Option<String>,
+    pub is_current: bool,
+}
+
+/// A role a user holds on an organization.
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct OrganizationRoleModel {
+    pub user_id: i32,
+    pub username: String,
+    pub role: String,
+    pub created_at: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct OrganizationPageResponse {
+    pub organization: PublicOrganizationModel,
+    pub members: Vec<OrganizationMemberModel>,
+}
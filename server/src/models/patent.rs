@@ -0,0 +1,33 @@
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PatentModel {
+    pub id: i32,
+    pub user_id: i32,
+    pub title: String,
+    pub patent_number: String,
+    pub office: String,
+    pub status: Option<String>,
+    pub url: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PublicPatentModel {
+    pub title: String,
+    pub patent_number: String,
+    pub office: String,
+    pub status: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct AuthPatentModel {
+    pub id: i32,
+    pub title: String,
+    pub patent_number: String,
+    pub office: String,
+    pub status: Option<String>,
+    pub url: Option<String>,
+}
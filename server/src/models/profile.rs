@@ -1,7 +1,11 @@
 use crate::models::certification::PublicCertificationModel;
 use crate::models::contact_information::PublicContactInformationModel;
+use crate::models::custom_section::PublicCustomSectionModel;
 use crate::models::education::PublicEducationModel;
-use crate::models::experience::PublicExperienceModel;
+use crate::models::experience::{ExperienceMetricsModel, PublicExperienceModel};
+use crate::models::language::PublicLanguageModel;
+use crate::models::project::PublicProjectModel;
+use crate::models::recommendation::PublicRecommendationModel;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
@@ -15,6 +19,7 @@ pub struct ProfileModel {
     pub country: Option<String>,
     pub city: Option<String>,
     pub bio: Option<String>,
+    pub visibility: String,
     pub created_at: String,
 }
 
@@ -35,5 +40,10 @@ pub struct PublicProfileResponse {
     pub certification: Vec<PublicCertificationModel>,
     pub education: Vec<PublicEducationModel>,
     pub experience: Vec<PublicExperienceModel>,
+    pub experience_metrics: ExperienceMetricsModel,
     pub contact_information: Vec<PublicContactInformationModel>,
+    pub project: Vec<PublicProjectModel>,
+    pub language: Vec<PublicLanguageModel>,
+    pub custom_sections: Vec<PublicCustomSectionModel>,
+    pub recommendation: Vec<PublicRecommendationModel>,
 }
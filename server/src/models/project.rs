@@ -0,0 +1,65 @@
+use crate::models::date::ProfileDate;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct ProjectModel {
+    pub id: i32,
+    pub user_id: i32,
+    pub name: String,
+    pub role: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub repository_url: Option<String>,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    pub created_at: String,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PublicProjectModel {
+    #[serde(skip)]
+    pub id: i32,
+    pub name: String,
+    pub role: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub repository_url: Option<String>,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    #[sqlx(skip)]
+    pub skills: Vec<String>,
+    #[sqlx(skip)]
+    pub images: Vec<ProjectImageModel>,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct AuthProjectModel {
+    pub id: i32,
+    pub name: String,
+    pub role: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub repository_url: Option<String>,
+    pub start_date: Option<ProfileDate>,
+    pub end_date: Option<ProfileDate>,
+    #[sqlx(skip)]
+    pub skills: Vec<String>,
+    #[sqlx(skip)]
+    pub images: Vec<ProjectImageModel>,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize, Deserialize)]
+pub struct ProjectImageModel {
+    #[serde(skip)]
+    #[sqlx(default)]
+    pub project_id: i32,
+    pub url: String,
+    pub caption: Option<String>,
+}
+
+#[derive(Clone, FromRow, Debug)]
+pub struct ProjectSkillModel {
+    pub project_id: i32,
+    pub name: String,
+}
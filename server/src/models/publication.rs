@@ -0,0 +1,37 @@
+use crate::models::date::ProfileDate;
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PublicationModel {
+    pub id: i32,
+    pub user_id: i32,
+    pub title: String,
+    pub venue: Option<String>,
+    pub publication_date: Option<ProfileDate>,
+    pub co_authors: Option<String>,
+    pub doi: Option<String>,
+    pub url: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PublicPublicationModel {
+    pub title: String,
+    pub venue: Option<String>,
+    pub publication_date: Option<ProfileDate>,
+    pub co_authors: Option<String>,
+    pub doi: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct AuthPublicationModel {
+    pub id: i32,
+    pub title: String,
+    pub venue: Option<String>,
+    pub publication_date: Option<ProfileDate>,
+    pub co_authors: Option<String>,
+    pub doi: Option<String>,
+    pub url: Option<String>,
+}
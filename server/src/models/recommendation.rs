@@ -0,0 +1,49 @@
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct RecommendationModel {
+    pub id: i32,
+    pub author_id: i32,
+    pub recipient_id: i32,
+    pub experience_id: Option<i32>,
+    pub relationship: String,
+    pub body: String,
+    pub status: String,
+    pub created_at: String,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PublicRecommendationModel {
+    pub author: String,
+    pub relationship: String,
+    pub body: String,
+    pub experience_title: Option<String>,
+    pub experience_company: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct AuthRecommendationModel {
+    pub id: i32,
+    pub author: String,
+    pub relationship: String,
+    pub body: String,
+    pub status: String,
+    pub experience_id: Option<i32>,
+    pub experience_title: Option<String>,
+    pub experience_company: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct WrittenRecommendationModel {
+    pub id: i32,
+    pub recipient: String,
+    pub relationship: String,
+    pub body: String,
+    pub status: String,
+    pub experience_title: Option<String>,
+    pub experience_company: Option<String>,
+    pub created_at: String,
+}
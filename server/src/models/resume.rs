@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::certification::PublicCertificationModel;
+use crate::models::contact_information::PublicContactInformationModel;
+use crate::models::custom_section::PublicCustomSectionModel;
+use crate::models::education::PublicEducationModel;
+use crate::models::experience::PublicExperienceModel;
+use crate::models::language::PublicLanguageModel;
+use crate::models::profile::PublicProfileModel;
+use crate::models::project::PublicProjectModel;
+
+/// The parts of a profile that are exported into resume documents, as visible to the viewer.
+#[derive(Clone, Debug)]
+pub struct ProfileExportModel {
+    pub profile: PublicProfileModel,
+    pub experiences: Vec<PublicExperienceModel>,
+    pub educations: Vec<PublicEducationModel>,
+    pub certifications: Vec<PublicCertificationModel>,
+    pub contact_information: Vec<PublicContactInformationModel>,
+    pub projects: Vec<PublicProjectModel>,
+    pub languages: Vec<PublicLanguageModel>,
+    pub custom_sections: Vec<PublicCustomSectionModel>,
+}
+
+/// A resume in the JSON Resume schema, see <https://jsonresume.org/schema>.
+///
+/// Only the sections that can be filled from a profile are modelled, other sections are ignored.
+///
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JsonResumeModel {
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    #[serde(default)]
+    pub basics: JsonResumeBasicsModel,
+    #[serde(default)]
+    pub work: Vec<JsonResumeWorkModel>,
+    #[serde(default)]
+    pub education: Vec<JsonResumeEducationModel>,
+    #[serde(default)]
+    pub certificates: Vec<JsonResumeCertificateModel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<JsonResumeMetaModel>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JsonResumeBasicsModel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<JsonResumeLocationModel>,
+    #[serde(default)]
+    pub profiles: Vec<JsonResumeProfileModel>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonResumeLocationModel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JsonResumeProfileModel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonResumeWorkModel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<String>,
+    /// The experience type, which is not part of the schema but allowed as an additional property.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub exp_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonResumeEducationModel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub institution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub study_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JsonResumeCertificateModel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonResumeMetaModel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
@@ -0,0 +1,48 @@
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct SkillModel {
+    pub id: i32,
+    pub user_id: i32,
+    pub skill_id: i32,
+    pub name: String,
+    pub proficiency: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PublicSkillModel {
+    #[serde(skip)]
+    pub id: i32,
+    pub name: String,
+    pub proficiency: Option<String>,
+    #[sqlx(skip)]
+    pub experiences: Vec<PublicSkillExperienceModel>,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct AuthSkillModel {
+    pub id: i32,
+    pub name: String,
+    pub proficiency: Option<String>,
+    #[sqlx(skip)]
+    pub experiences: Vec<AuthSkillExperienceModel>,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct PublicSkillExperienceModel {
+    #[serde(skip)]
+    pub user_skill_id: i32,
+    pub company: String,
+    pub title: String,
+}
+
+#[derive(Clone, FromRow, Debug, Serialize)]
+pub struct AuthSkillExperienceModel {
+    #[serde(skip)]
+    pub user_skill_id: i32,
+    pub id: i32,
+    pub company: String,
+    pub title: String,
+}
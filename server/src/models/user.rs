@@ -20,6 +20,7 @@ pub struct AuthUserModel {
     pub created_at: String,
 }
 
+#[allow(dead_code)]
 #[derive(Clone, FromRow, Debug, Serialize)]
 pub struct PublicAuthUserModel {
     pub id: i32,
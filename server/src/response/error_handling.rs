@@ -12,6 +12,7 @@ pub enum AppError {
     DataConflict { error: String },
     NotFound { error: String },
     Forbidden { error: Option<String> },
+    TooManyRequests { error: String },
 }
 
 #[derive(Serialize)]
@@ -57,6 +58,10 @@ impl IntoResponse for AppError {
                 status_code = StatusCode::FORBIDDEN;
                 body = error.unwrap_or("".to_string());
             }
+            Self::TooManyRequests { error } => {
+                status_code = StatusCode::TOO_MANY_REQUESTS;
+                body = error;
+            }
         }
 
         let response_body = AppResponseBody { message: Some(body) };
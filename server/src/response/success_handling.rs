@@ -3,6 +3,7 @@ use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use serde::Serialize;
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Serialize)]
 pub enum AppSuccess {
     OK { data: Option<String> },
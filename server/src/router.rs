@@ -1,3 +1,4 @@
+use axum::extract::DefaultBodyLimit;
 use axum::routing::{delete, get, patch, post};
 use axum::Router;
 use tower_http::cors::CorsLayer;
@@ -6,13 +7,18 @@ use tower_http::trace::TraceLayer;
 use tower_sessions::{MemoryStore, SessionManagerLayer};
 use tracing::Level;
 
-use crate::routes::api::{auth, profile};
+use crate::routes::api::{auth, contact_type, data_export, organization, profile};
+use crate::routes::page;
 use crate::AppState;
 
+/// The maximum size of an uploaded data export archive.
+const MAX_ARCHIVE_BYTES: usize = 20 * 1024 * 1024;
+
 /// Creates the authentication routes.
 ///
 /// The function sets up the routes for authentication related operations like
-/// login, registration, logout, updating account information, and updating passwords.
+/// login, registration, logout, updating account information, updating passwords, exporting personal data, reading notifications,
+/// creating organizations, managing organization roles and verifying the experiences linked to an organization.
 ///
 /// # Returns
 ///
@@ -26,6 +32,18 @@ fn create_auth_routes() -> Router<AppState> {
     let update_account = auth::account::update_account;
     let update_password = auth::account::update_password;
     let delete_account = auth::account::delete_account;
+    let request_data_export = auth::account::request_data_export;
+    let get_data_exports = auth::account::get_data_exports;
+    let get_notifications = auth::notification::get_notifications;
+    let mark_notification_read = auth::notification::mark_notification_read;
+    let mark_all_notifications_read = auth::notification::mark_all_notifications_read;
+    let add_organization = auth::organization::add_organization;
+    let get_organization_roles = auth::organization::get_organization_roles;
+    let grant_organization_role = auth::organization::grant_organization_role;
+    let revoke_organization_role = auth::organization::revoke_organization_role;
+    let get_organization_experiences = auth::organization::get_organization_experiences;
+    let attest_experience = auth::organization::attest_experience;
+    let revoke_attestation = auth::organization::revoke_attestation;
 
     // /auth
     Router::new()
@@ -34,7 +52,31 @@ fn create_auth_routes() -> Router<AppState> {
         .route("/register", post(register))
         .route("/logout", get(logout))
         .route("/account", patch(update_account).delete(delete_account))
+        .route(
+            "/account/data-export",
+            get(get_data_exports).post(request_data_export),
+        )
         .route("/password", patch(update_password))
+        .route("/notification", get(get_notifications))
+        .route("/notification/read", patch(mark_all_notifications_read))
+        .route("/notification/:id/read", patch(mark_notification_read))
+        .route("/organization", post(add_organization))
+        .route(
+            "/organization/:id/role",
+            get(get_organization_roles).post(grant_organization_role),
+        )
+        .route(
+            "/organization/:id/role/:user_id",
+            delete(revoke_organization_role),
+        )
+        .route(
+            "/organization/:id/experience",
+            get(get_organization_experiences),
+        )
+        .route(
+            "/organization/:id/experience/:experience_id/attestation",
+            post(attest_experience).delete(revoke_attestation),
+        )
         .nest("/profile", create_auth_profile_routes())
         .nest("/admin", create_auth_admin_routes())
 }
@@ -43,7 +85,8 @@ fn create_auth_routes() -> Router<AppState> {
 ///
 /// This function creates routes for managing the user's profile in the authentication system.
 /// It includes routes for getting, updating, adding, and deleting various profile information like contact information,
-/// certifications, educations, and experiences.
+/// certifications (which can also be imported from Open Badges), educations, experiences, skills, projects, languages,
+/// publications, patents, awards, user-defined custom sections with their entries, and recommendations written for or by the user.
 ///
 /// # Returns
 ///
@@ -52,16 +95,28 @@ fn create_auth_routes() -> Router<AppState> {
 fn create_auth_profile_routes() -> Router<AppState> {
     let get_profile = auth::profile::index::get_profile;
     let update_profile = auth::profile::index::update_profile;
+    let update_profile_visibility = auth::profile::index::update_profile_visibility;
 
     let get_contact_info = auth::profile::contact_information::get_contact_information;
     let add_contact_info = auth::profile::contact_information::add_contact_information;
     let delete_contact_info = auth::profile::contact_information::delete_contact_information;
     let update_contact_info = auth::profile::contact_information::update_contact_information;
+    let get_contact_reveals = auth::profile::contact_information::get_contact_reveals;
+
+    let get_json_resume = auth::profile::export::get_json_resume;
+    let get_pdf_resume = auth::profile::export::get_pdf_resume;
+    let get_europass = auth::profile::export::get_europass;
+    let import_json_resume = auth::profile::import::import_json_resume;
+    let preview_linkedin_import = auth::profile::import::preview_linkedin_import;
+    let confirm_import = auth::profile::import::confirm_import;
 
     let get_certifications = auth::profile::certification::get_certifications;
     let add_certification = auth::profile::certification::add_certification;
     let delete_certification = auth::profile::certification::delete_certification;
     let update_certification = auth::profile::certification::update_certification;
+    let import_certification = auth::profile::certification::import_certification;
+    let get_certification_badge = auth::profile::certification::get_certification_badge;
+    let verify_certification_badge = auth::profile::certification::verify_certification_badge;
 
     let get_educations = auth::profile::education::get_educations;
     let add_education = auth::profile::education::add_education;
@@ -73,12 +128,73 @@ fn create_auth_profile_routes() -> Router<AppState> {
     let update_experience = auth::profile::experience::update_experience;
     let delete_experience = auth::profile::experience::delete_experience;
 
+    let get_skills = auth::profile::skill::get_skills;
+    let add_skill = auth::profile::skill::add_skill;
+    let update_skill = auth::profile::skill::update_skill;
+    let delete_skill = auth::profile::skill::delete_skill;
+
+    let get_projects = auth::profile::project::get_projects;
+    let add_project = auth::profile::project::add_project;
+    let update_project = auth::profile::project::update_project;
+    let delete_project = auth::profile::project::delete_project;
+
+    let get_languages = auth::profile::language::get_languages;
+    let add_language = auth::profile::language::add_language;
+    let update_language = auth::profile::language::update_language;
+    let delete_language = auth::profile::language::delete_language;
+
+    let get_publications = auth::profile::publication::get_publications;
+    let add_publication = auth::profile::publication::add_publication;
+    let update_publication = auth::profile::publication::update_publication;
+    let delete_publication = auth::profile::publication::delete_publication;
+
+    let get_patents = auth::profile::patent::get_patents;
+    let add_patent = auth::profile::patent::add_patent;
+    let update_patent = auth::profile::patent::update_patent;
+    let delete_patent = auth::profile::patent::delete_patent;
+
+    let get_awards = auth::profile::award::get_awards;
+    let add_award = auth::profile::award::add_award;
+    let update_award = auth::profile::award::update_award;
+    let delete_award = auth::profile::award::delete_award;
+
+    let get_custom_sections = auth::profile::custom_section::get_custom_sections;
+    let add_custom_section = auth::profile::custom_section::add_custom_section;
+    let update_custom_section = auth::profile::custom_section::update_custom_section;
+    let delete_custom_section = auth::profile::custom_section::delete_custom_section;
+    let reorder_custom_sections = auth::profile::custom_section::reorder_custom_sections;
+    let add_custom_section_entry = auth::profile::custom_section::add_custom_section_entry;
+    let update_custom_section_entry = auth::profile::custom_section::update_custom_section_entry;
+    let delete_custom_section_entry = auth::profile::custom_section::delete_custom_section_entry;
+    let reorder_custom_section_entries =
+        auth::profile::custom_section::reorder_custom_section_entries;
+
+    let get_recommendations = auth::profile::recommendation::get_recommendations;
+    let get_written_recommendations = auth::profile::recommendation::get_written_recommendations;
+    let add_recommendation = auth::profile::recommendation::add_recommendation;
+    let request_recommendation = auth::profile::recommendation::request_recommendation;
+    let approve_recommendation = auth::profile::recommendation::approve_recommendation;
+    let decline_recommendation = auth::profile::recommendation::decline_recommendation;
+    let hide_recommendation = auth::profile::recommendation::hide_recommendation;
+    let delete_recommendation = auth::profile::recommendation::delete_recommendation;
+
     Router::new()
         .route("/", get(get_profile).patch(update_profile))
+        .route("/visibility", patch(update_profile_visibility))
+        .route("/export/json-resume", get(get_json_resume))
+        .route("/export/pdf", get(get_pdf_resume))
+        .route("/export/europass", get(get_europass))
+        .route("/import/json-resume", post(import_json_resume))
+        .route(
+            "/import/linkedin",
+            post(preview_linkedin_import).layer(DefaultBodyLimit::max(MAX_ARCHIVE_BYTES)),
+        )
+        .route("/import/confirm", post(confirm_import))
         .route(
             "/contact-information",
             get(get_contact_info).post(add_contact_info),
         )
+        .route("/contact-information/reveals", get(get_contact_reveals))
         .route(
             "/contact-information/:id",
             delete(delete_contact_info).patch(update_contact_info),
@@ -91,6 +207,12 @@ fn create_auth_profile_routes() -> Router<AppState> {
             "/certification/:id",
             delete(delete_certification).patch(update_certification),
         )
+        .route("/certification/import", post(import_certification))
+        .route("/certification/:id/badge", get(get_certification_badge))
+        .route(
+            "/certification/:id/badge/verify",
+            post(verify_certification_badge),
+        )
         .route("/education", get(get_educations).post(add_education))
         .route(
             "/education/:id",
@@ -101,14 +223,59 @@ fn create_auth_profile_routes() -> Router<AppState> {
             "/experience/:id",
             delete(delete_experience).patch(update_experience),
         )
+        .route("/skill", get(get_skills).post(add_skill))
+        .route("/skill/:id", delete(delete_skill).patch(update_skill))
+        .route("/project", get(get_projects).post(add_project))
+        .route("/project/:id", delete(delete_project).patch(update_project))
+        .route("/language", get(get_languages).post(add_language))
+        .route(
+            "/language/:id",
+            delete(delete_language).patch(update_language),
+        )
+        .route("/publication", get(get_publications).post(add_publication))
+        .route(
+            "/publication/:id",
+            delete(delete_publication).patch(update_publication),
+        )
+        .route("/patent", get(get_patents).post(add_patent))
+        .route("/patent/:id", delete(delete_patent).patch(update_patent))
+        .route("/award", get(get_awards).post(add_award))
+        .route("/award/:id", delete(delete_award).patch(update_award))
+        .route(
+            "/custom-section",
+            get(get_custom_sections).post(add_custom_section),
+        )
+        .route("/custom-section/order", patch(reorder_custom_sections))
+        .route(
+            "/custom-section/:id",
+            delete(delete_custom_section).patch(update_custom_section),
+        )
+        .route("/custom-section/:id/entry", post(add_custom_section_entry))
+        .route(
+            "/custom-section/:id/entry/order",
+            patch(reorder_custom_section_entries),
+        )
+        .route(
+            "/custom-section/:id/entry/:entry_id",
+            delete(delete_custom_section_entry).patch(update_custom_section_entry),
+        )
+        .route(
+            "/recommendation",
+            get(get_recommendations).post(add_recommendation),
+        )
+        .route("/recommendation/written", get(get_written_recommendations))
+        .route("/recommendation/request", post(request_recommendation))
+        .route("/recommendation/:id", delete(delete_recommendation))
+        .route("/recommendation/:id/approve", patch(approve_recommendation))
+        .route("/recommendation/:id/decline", patch(decline_recommendation))
+        .route("/recommendation/:id/hide", patch(hide_recommendation))
 }
 
-
-
 /// Creates the authentication admin routes.
 ///
 /// The function initializes various route handlers for managing users, certifications, education,
-/// experience, and contact information within the admin panel.
+/// experience, skills, projects, languages, publications, patents, awards, custom sections, recommendations,
+/// organizations, contact information and the contact type registry within the admin panel.
 ///
 /// # Returns
 ///
@@ -123,8 +290,22 @@ fn create_auth_admin_routes() -> Router<AppState> {
     let delete_certification = auth::admin::certification::admin_delete_certification;
     let delete_education = auth::admin::education::admin_delete_education;
     let delete_experience = auth::admin::experience::admin_delete_experience;
+    let delete_skill = auth::admin::skill::admin_delete_skill;
+    let delete_project = auth::admin::project::admin_delete_project;
+    let delete_language = auth::admin::language::admin_delete_language;
+    let delete_publication = auth::admin::publication::admin_delete_publication;
+    let delete_patent = auth::admin::patent::admin_delete_patent;
+    let delete_award = auth::admin::award::admin_delete_award;
+    let delete_custom_section = auth::admin::custom_section::admin_delete_custom_section;
+    let get_recommendations = auth::admin::recommendation::admin_get_recommendations;
+    let delete_recommendation = auth::admin::recommendation::admin_delete_recommendation;
+    let update_organization = auth::admin::organization::admin_update_organization;
+    let delete_organization = auth::admin::organization::admin_delete_organization;
     let delete_contact_information =
         auth::admin::contact_information::admin_delete_contact_information;
+    let get_contact_types = auth::admin::contact_type::admin_get_contact_types;
+    let add_contact_type = auth::admin::contact_type::admin_add_contact_type;
+    let update_contact_type = auth::admin::contact_type::admin_update_contact_type;
 
     Router::new()
         .route("/users", get(get_all_users))
@@ -135,10 +316,28 @@ fn create_auth_admin_routes() -> Router<AppState> {
         .route("/certification/:id", delete(delete_certification))
         .route("/education/:id", delete(delete_education))
         .route("/experience/:id", delete(delete_experience))
+        .route("/skill/:id", delete(delete_skill))
+        .route("/project/:id", delete(delete_project))
+        .route("/language/:id", delete(delete_language))
+        .route("/publication/:id", delete(delete_publication))
+        .route("/patent/:id", delete(delete_patent))
+        .route("/award/:id", delete(delete_award))
+        .route("/custom-section/:id", delete(delete_custom_section))
+        .route("/recommendations", get(get_recommendations))
+        .route("/recommendation/:id", delete(delete_recommendation))
+        .route(
+            "/organization/:id",
+            delete(delete_organization).patch(update_organization),
+        )
         .route(
             "/contact-information/:id",
             delete(delete_contact_information),
         )
+        .route(
+            "/contact-type",
+            get(get_contact_types).post(add_contact_type),
+        )
+        .route("/contact-type/:name", patch(update_contact_type))
 }
 
 /// Creates the router for public profile routes.
@@ -154,18 +353,68 @@ fn create_public_profile_routes() -> Router<AppState> {
     let get_public_certifications = profile::certification::get_public_certifications;
     let get_public_educations = profile::educations::get_public_educations;
     let get_public_experiences = profile::experience::get_public_experiences;
+    let get_public_skills = profile::skill::get_public_skills;
+    let get_public_projects = profile::project::get_public_projects;
+    let get_public_languages = profile::language::get_public_languages;
+    let get_public_publications = profile::publication::get_public_publications;
+    let get_public_patents = profile::patent::get_public_patents;
+    let get_public_awards = profile::award::get_public_awards;
+    let get_public_custom_sections = profile::custom_section::get_public_custom_sections;
+    let get_public_recommendations = profile::recommendation::get_public_recommendations;
     let get_public_contact_information =
         profile::contact_information::get_public_contact_information;
+    let reveal_contact_information = profile::contact_information::reveal_contact_information;
+    let get_public_json_resume = profile::export::get_public_json_resume;
+    let get_public_pdf_resume = profile::export::get_public_pdf_resume;
+    let get_public_europass = profile::export::get_public_europass;
+    let get_vcard = profile::vcard::get_vcard;
+    let get_qr_code = profile::vcard::get_qr_code;
+    let get_og_image = profile::og_image::get_og_image;
 
-    return Router::new()
+    Router::new()
         .route("/:id", get(get_public_profile))
         .route("/:id/certifications", get(get_public_certifications))
         .route("/:id/educations", get(get_public_educations))
         .route("/:id/experiences", get(get_public_experiences))
+        .route("/:id/skills", get(get_public_skills))
+        .route("/:id/projects", get(get_public_projects))
+        .route("/:id/languages", get(get_public_languages))
+        .route("/:id/publications", get(get_public_publications))
+        .route("/:id/patents", get(get_public_patents))
+        .route("/:id/awards", get(get_public_awards))
+        .route("/:id/custom-sections", get(get_public_custom_sections))
+        .route("/:id/recommendations", get(get_public_recommendations))
         .route(
             "/:id/contact-information",
             get(get_public_contact_information),
-        );
+        )
+        .route(
+            "/:id/contact-information/:contact_id/reveal",
+            post(reveal_contact_information),
+        )
+        .route("/:id/export/json-resume", get(get_public_json_resume))
+        .route("/:id/export/pdf", get(get_public_pdf_resume))
+        .route("/:id/export/europass", get(get_public_europass))
+        .route("/:id/vcard", get(get_vcard))
+        .route("/:id/qr-code", get(get_qr_code))
+        .route("/:id/og-image", get(get_og_image))
+}
+
+/// Creates the router for public organization routes.
+///
+/// This function sets up the autocomplete search over organizations and the organization pages.
+///
+/// # Returns
+///
+/// A `Router<AppState>` instance configured to handle the public organization routes.
+///
+fn create_public_organization_routes() -> Router<AppState> {
+    let search_organizations = organization::search_organizations;
+    let get_organization = organization::get_organization;
+
+    Router::new()
+        .route("/search", get(search_organizations))
+        .route("/:slug", get(get_organization))
 }
 
 /// This function creates a new router with the specified configuration.
@@ -176,7 +425,8 @@ fn create_public_profile_routes() -> Router<AppState> {
 /// * `session_layer` - A `SessionManagerLayer<MemoryStore>` to manage sessions in the application.
 /// * `state` - An `AppState` instance representing the application state.
 ///
-/// The function establishes various routes for "/auth" and "/profile" which are nested under "/api/v1".
+/// The function establishes various routes for "/auth", "/profile", "/organization", "/contact-type" and "/data-export" which are nested under "/api/v1",
+/// and the server-rendered profile pages under "/u".
 /// It applies the session_layer and cors middleware layers to the router along with tracing layer for logging.
 /// It also injects the application's state to the router.
 ///
@@ -201,10 +451,17 @@ pub fn router(
 ) -> Router {
     let api_router = Router::new()
         .nest("/auth", create_auth_routes())
-        .nest("/profile", create_public_profile_routes());
+        .nest("/profile", create_public_profile_routes())
+        .nest("/organization", create_public_organization_routes())
+        .route("/contact-type", get(contact_type::get_contact_types))
+        .route(
+            "/data-export/:token",
+            get(data_export::download_data_export),
+        );
 
     Router::new()
         .nest("/api/v1", api_router)
+        .route("/u/:username", get(page::get_profile_page))
         .layer(session_layer)
         .layer(cors)
         .layer(
@@ -3,12 +3,17 @@ use axum::response::IntoResponse;
 use axum::Json;
 use tower_sessions::Session;
 
+use crate::jobs::data_export::spawn_data_export_build;
+use crate::models::data_export::AuthDataExportModel;
 use crate::response::error_handling::AppError;
 use crate::response::success_handling::AppSuccess;
 use crate::services::account_service::{AccountUpdatePayload, PasswordUpdatePayload};
 use crate::services::session_service::SessionService;
 use crate::AppState;
 
+/// How many data exports a user may request per day, as each one stores a full archive for a day.
+const DATA_EXPORTS_PER_DAY: i64 = 3;
+
 pub async fn update_account(
     State(state): State<AppState>,
     session: Session,
@@ -83,7 +88,7 @@ pub async fn update_password(
     let user = state.user_service.check_user(&session).await?;
 
     let password_match = bcrypt::verify(&payload.old_password, &user.password)
-        .map_err(|_| return AppError::InternalError)?;
+        .map_err(|_| AppError::InternalError)?;
 
     if !password_match {
         return Err(AppError::BadRequest {
@@ -92,7 +97,7 @@ pub async fn update_password(
     }
 
     let is_same_password = bcrypt::verify(&payload.new_password, &user.password)
-        .map_err(|_| return AppError::InternalError)?;
+        .map_err(|_| AppError::InternalError)?;
 
     if is_same_password {
         return Err(AppError::BadRequest {
@@ -101,7 +106,7 @@ pub async fn update_password(
     }
 
     let hash = bcrypt::hash(&payload.new_password, bcrypt::DEFAULT_COST)
-        .map_err(|_| return AppError::InternalError)?;
+        .map_err(|_| AppError::InternalError)?;
 
     state.account_service.update_password(user.id, hash).await?;
 
@@ -120,3 +125,78 @@ pub async fn delete_account(
 
     Ok(AppSuccess::DELETED)
 }
+
+/// Asynchronously requests an export of everything Ideno stores about the current user.
+///
+/// The ZIP archive is built in the background. Its download link is listed by `get_data_exports` once it is ready and
+/// stays valid for a day.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+///
+/// # Returns
+///
+/// Returns the pending export.
+///
+/// # Errors
+///
+/// Returns an `AppError::DataConflict` if an export of the user is still being built.
+/// Returns an `AppError::TooManyRequests` if the user requested too many exports within the last day.
+/// Returns an `AppError` if the user is not logged in or there is an error while recording the export.
+///
+pub async fn request_data_export(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<AuthDataExportModel>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let exports = state
+        .data_export_service
+        .get_recent_data_export_count(user.id)
+        .await?;
+
+    if exports >= DATA_EXPORTS_PER_DAY {
+        return Err(AppError::TooManyRequests {
+            error: "Too many data exports requested, try again tomorrow".to_string(),
+        });
+    }
+
+    let export = state.data_export_service.create_data_export(user.id).await?;
+
+    spawn_data_export_build(
+        state.data_export_service.clone(),
+        state.notification_service.clone(),
+        export.id,
+        user.id,
+    );
+
+    Ok(Json(export.into()))
+}
+
+/// Asynchronously retrieves the data exports of the current user whose download link has not expired.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+///
+/// # Returns
+///
+/// Returns the exports, newest first, with the download link of finished exports.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the user is not logged in or there is an error during the retrieval process.
+///
+pub async fn get_data_exports(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<Vec<AuthDataExportModel>>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let exports = state.data_export_service.get_data_exports(user.id).await?;
+
+    Ok(Json(exports.into_iter().map(Into::into).collect()))
+}
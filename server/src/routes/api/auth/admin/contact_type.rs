@@ -0,0 +1,88 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::models::contact_type::ContactValueFormat;
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::services::contact_type_service::{AddContactTypePayload, UpdateContactTypePayload};
+use crate::AppState;
+
+pub async fn admin_get_contact_types(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    state.user_service.check_admin(&session).await?;
+
+    let contact_types = state.contact_type_service.get_contact_types(true).await?;
+
+    Ok(Json(serde_json::to_value(contact_types).unwrap()))
+}
+
+pub async fn admin_add_contact_type(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<AddContactTypePayload>,
+) -> Result<AppSuccess, AppError> {
+    state.user_service.check_admin(&session).await?;
+
+    if payload.name.is_empty()
+        || payload.name.len() > 30
+        || !payload
+            .name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "_-".contains(c))
+    {
+        return Err(AppError::BadRequest {
+            error: Some(
+                "Name must be 1 to 30 lowercase letters, digits, underscores or dashes".to_string(),
+            ),
+        });
+    }
+
+    if ContactValueFormat::from_str(&payload.value_format).is_none() {
+        return Err(AppError::BadRequest {
+            error: Some("Invalid value format".to_string()),
+        });
+    }
+
+    state.contact_type_service.validate_contact_type(
+        &payload.label,
+        &payload.pattern,
+        &payload.hosts,
+        &payload.path_prefix,
+        &payload.url_template,
+    )?;
+
+    state
+        .contact_type_service
+        .create_contact_type(payload)
+        .await?;
+
+    Ok(AppSuccess::CREATED { id: None })
+}
+
+pub async fn admin_update_contact_type(
+    State(state): State<AppState>,
+    session: Session,
+    Path(name): Path<String>,
+    Json(payload): Json<UpdateContactTypePayload>,
+) -> Result<AppSuccess, AppError> {
+    state.user_service.check_admin(&session).await?;
+    state.contact_type_service.get_contact_type(&name).await?;
+
+    state.contact_type_service.validate_contact_type(
+        &payload.label,
+        &payload.pattern,
+        &payload.hosts,
+        &payload.path_prefix,
+        &payload.url_template,
+    )?;
+
+    state
+        .contact_type_service
+        .update_contact_type(&name, payload)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
@@ -0,0 +1,25 @@
+use axum::extract::{Path, State};
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::AppState;
+
+pub async fn admin_delete_custom_section(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<AppSuccess, AppError> {
+    state.user_service.check_admin(&session).await?;
+    state
+        .custom_section_service
+        .custom_section_exists(id)
+        .await?;
+
+    state
+        .custom_section_service
+        .admin_delete_custom_section(id)
+        .await?;
+
+    Ok(AppSuccess::DELETED)
+}
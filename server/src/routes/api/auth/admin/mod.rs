@@ -1,5 +1,15 @@
+pub mod award;
 pub mod certification;
 pub mod contact_information;
+pub mod contact_type;
+pub mod custom_section;
 pub mod education;
 pub mod experience;
+pub mod language;
+pub mod organization;
+pub mod patent;
+pub mod project;
+pub mod publication;
+pub mod recommendation;
+pub mod skill;
 pub mod user;
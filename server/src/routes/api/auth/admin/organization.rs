@@ -0,0 +1,54 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::routes::api::auth::organization::OrganizationType;
+use crate::services::organization_service::UpdateOrganizationPayload;
+use crate::AppState;
+
+pub async fn admin_update_organization(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateOrganizationPayload>,
+) -> Result<AppSuccess, AppError> {
+    state.user_service.check_admin(&session).await?;
+    state.organization_service.get_organization(id).await?;
+
+    if OrganizationType::from_str(&payload.org_type).is_none() {
+        return Err(AppError::BadRequest {
+            error: Some("Invalid organization type".to_string()),
+        });
+    }
+
+    state.organization_service.validate_organization(
+        &payload.name,
+        &payload.website,
+        &payload.logo_url,
+    )?;
+
+    state
+        .organization_service
+        .update_organization(id, payload)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn admin_delete_organization(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<AppSuccess, AppError> {
+    state.user_service.check_admin(&session).await?;
+    state.organization_service.get_organization(id).await?;
+
+    state
+        .organization_service
+        .admin_delete_organization(id)
+        .await?;
+
+    Ok(AppSuccess::DELETED)
+}
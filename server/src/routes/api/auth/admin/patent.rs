@@ -0,0 +1,19 @@
+use axum::extract::{Path, State};
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::AppState;
+
+pub async fn admin_delete_patent(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<AppSuccess, AppError> {
+    state.user_service.check_admin(&session).await?;
+    state.patent_service.patent_exists(id).await?;
+
+    state.patent_service.admin_delete_patent(id).await?;
+
+    Ok(AppSuccess::DELETED)
+}
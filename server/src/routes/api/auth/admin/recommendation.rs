@@ -0,0 +1,39 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::AppState;
+
+pub async fn admin_get_recommendations(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    state.user_service.check_admin(&session).await?;
+    let recommendations = state
+        .recommendation_service
+        .get_all_recommendations()
+        .await?;
+
+    Ok(Json(serde_json::to_value(recommendations).unwrap()))
+}
+
+pub async fn admin_delete_recommendation(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<AppSuccess, AppError> {
+    state.user_service.check_admin(&session).await?;
+    state
+        .recommendation_service
+        .recommendation_exists(id)
+        .await?;
+
+    state
+        .recommendation_service
+        .admin_delete_recommendation(id)
+        .await?;
+
+    Ok(AppSuccess::DELETED)
+}
@@ -39,7 +39,7 @@ pub async fn login(
     };
 
     let is_password_valid =
-        bcrypt::verify(payload.password, &*user.password).map_err(|_| AppError::InternalError)?;
+        bcrypt::verify(payload.password, &user.password).map_err(|_| AppError::InternalError)?;
 
     if !is_password_valid {
         return Err(AppError::Forbidden {
@@ -1,7 +1,10 @@
 pub mod account;
 pub mod admin;
+#[allow(clippy::module_inception)]
 pub mod auth;
 pub mod login;
 pub mod logout;
+pub mod notification;
+pub mod organization;
 pub mod profile;
 pub mod register;
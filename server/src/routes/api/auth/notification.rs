@@ -0,0 +1,51 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::AppState;
+
+pub async fn get_notifications(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let notifications = state
+        .notification_service
+        .get_notifications(user.id)
+        .await?;
+
+    Ok(Json(serde_json::to_value(notifications).unwrap()))
+}
+
+pub async fn mark_notification_read(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state
+        .notification_service
+        .mark_notification_read(user.id, id)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn mark_all_notifications_read(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state
+        .notification_service
+        .mark_all_notifications_read(user.id)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
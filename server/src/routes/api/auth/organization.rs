@@ -0,0 +1,403 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::models::notification::NotificationKind;
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::services::organization_service::{
+    AddOrganizationPayload, GrantOrganizationRolePayload,
+};
+use crate::AppState;
+
+#[derive(PartialEq, Debug)]
+pub enum OrganizationType {
+    Company,
+    School,
+}
+
+impl OrganizationType {
+    pub(crate) fn from_str(s: &str) -> Option<OrganizationType> {
+        match s {
+            "company" => Some(OrganizationType::Company),
+            "school" => Some(OrganizationType::School),
+            _ => None,
+        }
+    }
+}
+
+/// The roles a user can hold on an organization.
+///
+/// Admins manage the roles of the organization and verify experiences, verifiers only verify experiences.
+///
+#[derive(PartialEq, Debug)]
+pub enum OrganizationRole {
+    Admin,
+    Verifier,
+}
+
+impl OrganizationRole {
+    pub(crate) fn from_str(s: &str) -> Option<OrganizationRole> {
+        match s {
+            "admin" => Some(OrganizationRole::Admin),
+            "verifier" => Some(OrganizationRole::Verifier),
+            _ => None,
+        }
+    }
+}
+
+/// Asynchronously creates an organization that experiences and educations can link to.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `payload` - A JSON payload containing the name, type, website and logo of the organization.
+///
+/// # Returns
+///
+/// Returns `AppSuccess::CREATED` with the ID of the new organization.
+///
+/// # Errors
+///
+/// Returns an `AppError::BadRequest` if the organization type, name or links are invalid.
+/// Returns an `AppError::DataConflict` if an organization with the same name already exists.
+///
+pub async fn add_organization(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<AddOrganizationPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    if OrganizationType::from_str(&payload.org_type).is_none() {
+        return Err(AppError::BadRequest {
+            error: Some("Invalid organization type".to_string()),
+        });
+    }
+
+    state.organization_service.validate_organization(
+        &payload.name,
+        &payload.website,
+        &payload.logo_url,
+    )?;
+
+    let organization_id = state
+        .organization_service
+        .create_organization(user.id, payload)
+        .await?;
+
+    Ok(AppSuccess::CREATED {
+        id: Some(organization_id),
+    })
+}
+
+/// Asynchronously retrieves the users holding a role on an organization.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user, who must be an admin of the organization or a site admin.
+/// * `id` - The ID of the organization.
+///
+/// # Returns
+///
+/// Returns a JSON array of the roles of the organization with the username of each member.
+///
+/// # Errors
+///
+/// Returns an `AppError::NotFound` if the organization does not exist.
+/// Returns an `AppError::Forbidden` if the current user may not manage the roles of the organization.
+///
+pub async fn get_organization_roles(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+    state.organization_service.get_organization(id).await?;
+    state
+        .organization_service
+        .check_organization_role(id, &user, &["admin"])
+        .await?;
+
+    let roles = state.organization_service.get_organization_roles(id).await?;
+
+    Ok(Json(serde_json::to_value(roles).unwrap()))
+}
+
+/// Asynchronously grants a user a role on an organization.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user, who must be an admin of the organization or a site admin.
+/// * `id` - The ID of the organization.
+/// * `payload` - A JSON payload containing the username of the user and the role to be granted.
+///
+/// # Returns
+///
+/// Returns `AppSuccess::UPDATED` if the role was granted.
+///
+/// # Errors
+///
+/// Returns an `AppError::Forbidden` if the current user may not manage the roles of the organization.
+/// Returns an `AppError::BadRequest` if the role is not supported.
+/// Returns an `AppError::UserNotFound` if no user has the given username.
+/// Returns an `AppError::DataConflict` if the grant would demote the last admin of the organization.
+///
+pub async fn grant_organization_role(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+    Json(payload): Json<GrantOrganizationRolePayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+    state.organization_service.get_organization(id).await?;
+    state
+        .organization_service
+        .check_organization_role(id, &user, &["admin"])
+        .await?;
+
+    if OrganizationRole::from_str(&payload.role).is_none() {
+        return Err(AppError::BadRequest {
+            error: Some("Invalid organization role".to_string()),
+        });
+    }
+
+    let member = state
+        .user_service
+        .get_user_by_username(payload.username)
+        .await?;
+
+    state
+        .organization_service
+        .grant_organization_role(id, member.id, &payload.role)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+/// Asynchronously revokes the role a user holds on an organization.
+///
+/// The last admin of an organization cannot be removed, so the organization never depends on site admins alone.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user, who must be an admin of the organization or a site admin.
+/// * `id` - The ID of the organization.
+/// * `user_id` - The ID of the user whose role is to be revoked.
+///
+/// # Returns
+///
+/// Returns `AppSuccess::DELETED` if the role was revoked.
+///
+/// # Errors
+///
+/// Returns an `AppError::Forbidden` if the current user may not manage the roles of the organization.
+/// Returns an `AppError::NotFound` if the organization does not exist or the user holds no role on it.
+/// Returns an `AppError::DataConflict` if the user is the last admin of the organization.
+///
+pub async fn revoke_organization_role(
+    State(state): State<AppState>,
+    session: Session,
+    Path((id, user_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+    state.organization_service.get_organization(id).await?;
+    state
+        .organization_service
+        .check_organization_role(id, &user, &["admin"])
+        .await?;
+
+    state
+        .organization_service
+        .revoke_organization_role(id, user_id)
+        .await?;
+
+    Ok(AppSuccess::DELETED)
+}
+
+/// Asynchronously retrieves the experiences linked to an organization, for review by the people verifying for it.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user, who must be an admin or verifier of the organization.
+/// * `id` - The ID of the organization.
+///
+/// # Returns
+///
+/// Returns a JSON array of the linked experiences, unverified experiences first.
+///
+/// # Errors
+///
+/// Returns an `AppError::NotFound` if the organization does not exist.
+/// Returns an `AppError::Forbidden` if the current user may not verify experiences for the organization.
+///
+pub async fn get_organization_experiences(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+    state.organization_service.get_organization(id).await?;
+    state
+        .organization_service
+        .check_organization_role(id, &user, &["admin", "verifier"])
+        .await?;
+
+    let experiences = state
+        .attestation_service
+        .get_organization_experiences(id)
+        .await?;
+
+    Ok(Json(serde_json::to_value(experiences).unwrap()))
+}
+
+/// Asynchronously verifies that a user held a position at an organization, as the experience states it.
+///
+/// The attestation signs the company, title, dates, current flag and organization of the experience.
+/// The owner of the experience is notified.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user, who must be an admin or verifier of the organization.
+/// * `id` - The ID of the organization.
+/// * `experience_id` - The ID of the experience, which must be linked to the organization.
+///
+/// # Returns
+///
+/// Returns `AppSuccess::CREATED` with the ID of the attestation.
+///
+/// # Errors
+///
+/// Returns an `AppError::Forbidden` if the current user may not verify experiences for the organization.
+/// Returns an `AppError::NotFound` if the experience is not linked to the organization.
+/// Returns an `AppError::BadRequest` if the current user tries to verify their own experience.
+///
+pub async fn attest_experience(
+    State(state): State<AppState>,
+    session: Session,
+    Path((id, experience_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+    let organization = state.organization_service.get_organization(id).await?;
+    state
+        .organization_service
+        .check_organization_role(id, &user, &["admin", "verifier"])
+        .await?;
+
+    let experience = state
+        .attestation_service
+        .get_organization_experience(id, experience_id)
+        .await?;
+
+    if experience.user_id == user.id {
+        return Err(AppError::BadRequest {
+            error: Some("You cannot verify your own experience".to_string()),
+        });
+    }
+
+    let attestation_id = state
+        .attestation_service
+        .attest_experience(&experience, user.id)
+        .await?;
+
+    state
+        .notification_service
+        .notify(
+            experience.user_id,
+            NotificationKind::ExperienceVerified,
+            format!(
+                "{} verified your experience as {}",
+                organization.name, experience.title
+            ),
+            Some(experience.id),
+        )
+        .await?;
+
+    Ok(AppSuccess::CREATED {
+        id: Some(attestation_id),
+    })
+}
+
+/// Asynchronously revokes the attestation an organization gave for an experience.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user, who must be an admin or verifier of the organization.
+/// * `id` - The ID of the organization.
+/// * `experience_id` - The ID of the attested experience.
+///
+/// # Returns
+///
+/// Returns `AppSuccess::DELETED` if the attestation was revoked.
+///
+/// # Errors
+///
+/// Returns an `AppError::Forbidden` if the current user may not verify experiences for the organization.
+/// Returns an `AppError::NotFound` if the organization does not exist or did not attest the experience.
+///
+pub async fn revoke_attestation(
+    State(state): State<AppState>,
+    session: Session,
+    Path((id, experience_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+    state.organization_service.get_organization(id).await?;
+    state
+        .organization_service
+        .check_organization_role(id, &user, &["admin", "verifier"])
+        .await?;
+
+    state
+        .attestation_service
+        .revoke_attestation(id, experience_id)
+        .await?;
+
+    Ok(AppSuccess::DELETED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OrganizationRole, OrganizationType};
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            OrganizationType::from_str("company"),
+            Some(OrganizationType::Company)
+        );
+        assert_eq!(
+            OrganizationType::from_str("school"),
+            Some(OrganizationType::School)
+        );
+
+        // Test with strings that do not match any organization type
+        assert_eq!(OrganizationType::from_str("Company"), None);
+        assert_eq!(OrganizationType::from_str("university"), None);
+        assert_eq!(OrganizationType::from_str(""), None);
+    }
+
+    #[test]
+    fn test_role_from_str() {
+        assert_eq!(
+            OrganizationRole::from_str("admin"),
+            Some(OrganizationRole::Admin)
+        );
+        assert_eq!(
+            OrganizationRole::from_str("verifier"),
+            Some(OrganizationRole::Verifier)
+        );
+
+        // Test with strings that do not match any organization role
+        assert_eq!(OrganizationRole::from_str("Admin"), None);
+        assert_eq!(OrganizationRole::from_str("owner"), None);
+        assert_eq!(OrganizationRole::from_str(""), None);
+    }
+}
@@ -0,0 +1,78 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::services::award_service::{AddAwardPayload, UpdateAwardPayload};
+use crate::AppState;
+
+pub async fn get_awards(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let awards = state.award_service.get_all_awards(user.id).await?;
+
+    Ok(Json(serde_json::to_value(&awards).unwrap()))
+}
+
+pub async fn add_award(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<AddAwardPayload>,
+) -> Result<AppSuccess, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let count = state.award_service.get_award_count(user.id).await?;
+
+    if count >= 50 {
+        return Err(AppError::DataConflict {
+            error: "Award limit reached".to_string(),
+        });
+    }
+
+    state.award_service.validate_award(&payload.title)?;
+
+    let new_award = state.award_service.create_award(user.id, payload).await?;
+
+    Ok(AppSuccess::CREATED {
+        id: Some(new_award),
+    })
+}
+
+pub async fn update_award(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateAwardPayload>,
+) -> Result<AppSuccess, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state.award_service.user_owns_award(user.id, id).await?;
+
+    state.award_service.validate_award(&payload.title)?;
+
+    state
+        .award_service
+        .update_award(user.id, id, payload)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn delete_award(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state.award_service.user_owns_award(user.id, id).await?;
+
+    state.award_service.delete_award(user.id, id).await?;
+
+    Ok(AppSuccess::DELETED)
+}
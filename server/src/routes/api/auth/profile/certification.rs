@@ -1,3 +1,4 @@
+use axum::body::Bytes;
 use axum::extract::{Path, State};
 use axum::response::IntoResponse;
 use axum::Json;
@@ -5,6 +6,7 @@ use tower_sessions::Session;
 
 use crate::response::error_handling::AppError;
 use crate::response::success_handling::AppSuccess;
+use crate::services::badge_service;
 use crate::services::certification_service::{AddCertificationPayload, UpdateCertificationPayload};
 use crate::AppState;
 
@@ -40,6 +42,10 @@ pub async fn add_certification(
         });
     }
 
+    state
+        .certification_service
+        .validate_certification_dates(&payload.issue_date, &payload.expiration_date)?;
+
     let new_certification = state
         .certification_service
         .create_certification(user.id, payload)
@@ -50,6 +56,106 @@ pub async fn add_certification(
     })
 }
 
+/// Imports a certification from an Open Badges 2.0 assertion or an Open Badges 3.0 credential.
+///
+/// The request body is the badge file as issued: assertion JSON, a compact JWS / JWT, or a baked PNG or SVG.
+/// Signatures are checked where the signing key can be resolved, and badges with an invalid signature are rejected.
+/// A badge is only marked verified if it is signed by its issuer and was issued to the confirmed account email of the user.
+///
+pub async fn import_certification(
+    State(state): State<AppState>,
+    session: Session,
+    body: Bytes,
+) -> Result<AppSuccess, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let count = state
+        .certification_service
+        .get_certification_count(user.id)
+        .await?;
+
+    if count >= 50 {
+        return Err(AppError::DataConflict {
+            error: "Certification limit reached".to_string(),
+        });
+    }
+
+    let emails: Vec<String> = state
+        .user_service
+        .get_verified_email(user.id)
+        .await?
+        .into_iter()
+        .collect();
+
+    let badge = badge_service::import_badge(&body, &emails)?;
+
+    state
+        .certification_service
+        .validate_certification_dates(&badge.issue_date, &badge.expiration_date)?;
+
+    let new_certification = state
+        .certification_service
+        .create_imported_certification(user.id, badge)
+        .await?;
+
+    Ok(AppSuccess::CREATED {
+        id: Some(new_certification),
+    })
+}
+
+pub async fn get_certification_badge(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let badge = state
+        .certification_service
+        .get_certification_badge(user.id, id)
+        .await?;
+
+    Ok(Json(serde_json::to_value(&badge).unwrap()))
+}
+
+/// Checks the signature of the badge a certification was imported from again and stores the outcome.
+///
+/// A badge whose assertion no longer passes the checks is marked `invalid`.
+///
+pub async fn verify_certification_badge(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let badge = state
+        .certification_service
+        .get_certification_badge(user.id, id)
+        .await?;
+
+    let emails: Vec<String> = state
+        .user_service
+        .get_verified_email(user.id)
+        .await?
+        .into_iter()
+        .collect();
+
+    let verification = match badge_service::read_assertion(&badge.badge_assertion, &emails) {
+        Ok(badge) => badge.verification.as_str(),
+        Err(_) => "invalid",
+    };
+
+    state
+        .certification_service
+        .set_badge_verification(id, verification)
+        .await?;
+
+    Ok(Json(
+        serde_json::json!({ "badge_verification": verification }),
+    ))
+}
+
 pub async fn update_certification(
     State(state): State<AppState>,
     session: Session,
@@ -63,6 +169,10 @@ pub async fn update_certification(
         .user_owns_certification(user.id, id)
         .await?;
 
+    state
+        .certification_service
+        .validate_certification_dates(&payload.issue_date, &payload.expiration_date)?;
+
     state
         .certification_service
         .update_certification(user.id, id, payload)
@@ -10,51 +10,34 @@ use crate::services::contact_information_service::{
 };
 use crate::AppState;
 
+/// Who sees the value of a contact entry: everyone, or nobody but its owner until it is revealed, by anyone or
+/// only by logged-in users.
 #[derive(PartialEq, Debug)]
-enum ContactType {
-    Email,
-    Phone,
-    Website,
-    LinkedIn,
-    GitHub,
-    Twitter,
-    Facebook,
-    Instagram,
+pub(crate) enum ContactVisibility {
+    Public,
+    Masked,
+    Members,
 }
 
-impl ContactType {
-    fn from_str(contact_type: &str) -> Option<Self> {
-        match contact_type {
-            "email" => Some(ContactType::Email),
-            "phone" => Some(ContactType::Phone),
-            "website" => Some(ContactType::Website),
-            "linkedin" => Some(ContactType::LinkedIn),
-            "github" => Some(ContactType::GitHub),
-            "twitter" => Some(ContactType::Twitter),
-            "facebook" => Some(ContactType::Facebook),
-            "instagram" => Some(ContactType::Instagram),
+impl ContactVisibility {
+    pub(crate) fn from_str(visibility: &str) -> Option<Self> {
+        match visibility {
+            "public" => Some(ContactVisibility::Public),
+            "masked" => Some(ContactVisibility::Masked),
+            "members" => Some(ContactVisibility::Members),
             _ => None,
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::ContactType;
-
-    #[test]
-    fn test_from_str() {
-        assert_eq!(ContactType::from_str("email"), Some(ContactType::Email));
-        assert_eq!(ContactType::from_str("phone"), Some(ContactType::Phone));
-        assert_eq!(ContactType::from_str("website"), Some(ContactType::Website));
-        assert_eq!(ContactType::from_str("linkedin"), Some(ContactType::LinkedIn));
-        assert_eq!(ContactType::from_str("github"), Some(ContactType::GitHub));
-        assert_eq!(ContactType::from_str("twitter"), Some(ContactType::Twitter));
-        assert_eq!(ContactType::from_str("facebook"), Some(ContactType::Facebook));
-        assert_eq!(ContactType::from_str("instagram"), Some(ContactType::Instagram));
-
-        // Test with a string that is not a contact type
-        assert_eq!(ContactType::from_str("invalid"), None);
+fn validate_visibility(visibility: &Option<String>) -> Result<(), AppError> {
+    match visibility {
+        Some(visibility) if ContactVisibility::from_str(visibility).is_none() => {
+            Err(AppError::BadRequest {
+                error: Some("Invalid visibility".to_string()),
+            })
+        }
+        _ => Ok(()),
     }
 }
 
@@ -75,7 +58,7 @@ pub async fn get_contact_information(
 pub async fn add_contact_information(
     State(state): State<AppState>,
     session: Session,
-    Json(payload): Json<AddContactInformationPayload>,
+    Json(mut payload): Json<AddContactInformationPayload>,
 ) -> Result<impl IntoResponse, AppError> {
     let user = state.user_service.check_user(&session).await?;
 
@@ -90,12 +73,12 @@ pub async fn add_contact_information(
         });
     }
 
-    let contact_type = ContactType::from_str(&payload.contact_type);
-    if contact_type.is_none() {
-        return Err(AppError::BadRequest {
-            error: Some("Invalid contact type".to_string()),
-        });
-    }
+    validate_visibility(&payload.visibility)?;
+
+    (payload.value, payload.label) = state
+        .contact_type_service
+        .validate_contact_information(&payload.contact_type, &payload.label, &payload.value)
+        .await?;
 
     let contact_information_exists = state
         .contact_information_service
@@ -122,7 +105,7 @@ pub async fn update_contact_information(
     State(state): State<AppState>,
     session: Session,
     Path(id): Path<i32>,
-    Json(payload): Json<UpdateContactInformationPayload>,
+    Json(mut payload): Json<UpdateContactInformationPayload>,
 ) -> Result<impl IntoResponse, AppError> {
     let user = state.user_service.check_user(&session).await?;
 
@@ -131,12 +114,12 @@ pub async fn update_contact_information(
         .user_owns_contact_information(user.id, id)
         .await?;
 
-    let contact_type = ContactType::from_str(&payload.contact_type);
-    if contact_type.is_none() {
-        return Err(AppError::BadRequest {
-            error: Some("Invalid contact type".to_string()),
-        });
-    }
+    validate_visibility(&payload.visibility)?;
+
+    (payload.value, payload.label) = state
+        .contact_type_service
+        .validate_contact_information(&payload.contact_type, &payload.label, &payload.value)
+        .await?;
 
     state
         .contact_information_service
@@ -165,3 +148,33 @@ pub async fn delete_contact_information(
 
     Ok(AppSuccess::DELETED)
 }
+
+/// Asynchronously retrieves who revealed the masked contact entries of the current user, and when.
+pub async fn get_contact_reveals(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let reveals = state
+        .contact_information_service
+        .get_contact_reveals(user.id)
+        .await?;
+
+    Ok(Json(serde_json::to_value(reveals).unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContactVisibility;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(ContactVisibility::from_str("public"), Some(ContactVisibility::Public));
+        assert_eq!(ContactVisibility::from_str("masked"), Some(ContactVisibility::Masked));
+        assert_eq!(ContactVisibility::from_str("members"), Some(ContactVisibility::Members));
+
+        // Test with a string that is not a visibility
+        assert_eq!(ContactVisibility::from_str("private"), None);
+    }
+}
@@ -0,0 +1,239 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::services::custom_section_service::{
+    AddCustomSectionEntryPayload, AddCustomSectionPayload, ReorderPayload,
+    UpdateCustomSectionEntryPayload, UpdateCustomSectionPayload,
+};
+use crate::AppState;
+
+#[derive(PartialEq, Debug)]
+pub enum SectionVisibility {
+    Public,
+    Private,
+}
+
+impl SectionVisibility {
+    pub(crate) fn from_str(s: &str) -> Option<SectionVisibility> {
+        match s {
+            "public" => Some(SectionVisibility::Public),
+            "private" => Some(SectionVisibility::Private),
+            _ => None,
+        }
+    }
+}
+
+pub async fn get_custom_sections(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let sections = state
+        .custom_section_service
+        .get_authenticated_custom_sections(user.id)
+        .await?;
+
+    Ok(Json(serde_json::to_value(sections).unwrap()))
+}
+
+pub async fn add_custom_section(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<AddCustomSectionPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let count = state
+        .custom_section_service
+        .get_custom_section_count(user.id)
+        .await?;
+
+    if count >= 10 {
+        return Err(AppError::DataConflict {
+            error: "Custom section limit reached".to_string(),
+        });
+    }
+
+    state
+        .custom_section_service
+        .validate_custom_section(&payload.title, &payload.visibility)?;
+
+    let new_section = state
+        .custom_section_service
+        .create_custom_section(user.id, payload)
+        .await?;
+
+    Ok(AppSuccess::CREATED {
+        id: Some(new_section),
+    })
+}
+
+pub async fn update_custom_section(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateCustomSectionPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state
+        .custom_section_service
+        .user_owns_custom_section(user.id, id)
+        .await?;
+
+    state
+        .custom_section_service
+        .validate_custom_section(&payload.title, &payload.visibility)?;
+
+    state
+        .custom_section_service
+        .update_custom_section(user.id, id, payload)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn delete_custom_section(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state
+        .custom_section_service
+        .user_owns_custom_section(user.id, id)
+        .await?;
+
+    state
+        .custom_section_service
+        .delete_custom_section(user.id, id)
+        .await?;
+
+    Ok(AppSuccess::DELETED)
+}
+
+pub async fn reorder_custom_sections(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<ReorderPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state
+        .custom_section_service
+        .reorder_custom_sections(user.id, payload.ids)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn add_custom_section_entry(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+    Json(payload): Json<AddCustomSectionEntryPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let count = state
+        .custom_section_service
+        .get_custom_section_entry_count(id)
+        .await?;
+
+    if count >= 50 {
+        return Err(AppError::DataConflict {
+            error: "Custom section entry limit reached".to_string(),
+        });
+    }
+
+    state
+        .custom_section_service
+        .validate_custom_section_entry(&payload.title, &payload.link)?;
+
+    let new_entry = state
+        .custom_section_service
+        .create_custom_section_entry(user.id, id, payload)
+        .await?;
+
+    Ok(AppSuccess::CREATED {
+        id: Some(new_entry),
+    })
+}
+
+pub async fn update_custom_section_entry(
+    State(state): State<AppState>,
+    session: Session,
+    Path((id, entry_id)): Path<(i32, i32)>,
+    Json(payload): Json<UpdateCustomSectionEntryPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state
+        .custom_section_service
+        .validate_custom_section_entry(&payload.title, &payload.link)?;
+
+    state
+        .custom_section_service
+        .update_custom_section_entry(user.id, id, entry_id, payload)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn delete_custom_section_entry(
+    State(state): State<AppState>,
+    session: Session,
+    Path((id, entry_id)): Path<(i32, i32)>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state
+        .custom_section_service
+        .delete_custom_section_entry(user.id, id, entry_id)
+        .await?;
+
+    Ok(AppSuccess::DELETED)
+}
+
+pub async fn reorder_custom_section_entries(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+    Json(payload): Json<ReorderPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state
+        .custom_section_service
+        .reorder_custom_section_entries(user.id, id, payload.ids)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SectionVisibility;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            SectionVisibility::from_str("public"),
+            Some(SectionVisibility::Public)
+        );
+        assert_eq!(
+            SectionVisibility::from_str("private"),
+            Some(SectionVisibility::Private)
+        );
+
+        // Test with a string that is not a visibility
+        assert_eq!(SectionVisibility::from_str("Public"), None);
+        assert_eq!(SectionVisibility::from_str("members"), None);
+    }
+}
@@ -34,6 +34,15 @@ pub async fn add_education(
         });
     }
 
+    state
+        .organization_service
+        .validate_organization_link(payload.organization_id, "school")
+        .await?;
+
+    state
+        .education_service
+        .validate_education_dates(&payload.start_date, &payload.end_date)?;
+
     let created_education = state
         .education_service
         .create_education(user.id, payload)
@@ -57,6 +66,15 @@ pub async fn update_education(
         .user_owns_education(user.id, id)
         .await?;
 
+    state
+        .organization_service
+        .validate_organization_link(payload.organization_id, "school")
+        .await?;
+
+    state
+        .education_service
+        .validate_education_dates(&payload.start_date, &payload.end_date)?;
+
     state
         .education_service
         .update_education(user.id, id, payload)
@@ -1,11 +1,13 @@
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::response::IntoResponse;
 use axum::Json;
 use tower_sessions::Session;
 
 use crate::response::error_handling::AppError;
 use crate::response::success_handling::AppSuccess;
-use crate::services::experience_service::{AddExperiencePayload, UpdateExperiencePayload};
+use crate::services::experience_service::{
+    AddExperiencePayload, ExperienceListQuery, UpdateExperiencePayload,
+};
 use crate::AppState;
 
 #[derive(PartialEq, Debug)]
@@ -40,34 +42,22 @@ impl ExperienceType {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::ExperienceType;
-
-    #[test]
-    fn test_from_str() {
-        assert_eq!(ExperienceType::from_str("Full Time"), Some(ExperienceType::FullTime));
-        assert_eq!(ExperienceType::from_str("Part Time"), Some(ExperienceType::PartTime));
-        assert_eq!(ExperienceType::from_str("Self Employed"), Some(ExperienceType::SelfEmployed));
-        assert_eq!(ExperienceType::from_str("Freelance"), Some(ExperienceType::Freelance));
-        assert_eq!(ExperienceType::from_str("Contract"), Some(ExperienceType::Contract));
-        assert_eq!(ExperienceType::from_str("Internship"), Some(ExperienceType::Internship));
-        assert_eq!(ExperienceType::from_str("Volunteering"), Some(ExperienceType::Volunteering));
-        assert_eq!(ExperienceType::from_str("Seasonal"), Some(ExperienceType::Seasonal));
-        assert_eq!(ExperienceType::from_str("Apprenticeship"), Some(ExperienceType::Apprenticeship));
-        assert_eq!(ExperienceType::from_str("Other"), Some(ExperienceType::Other));
-
-        // Test with a string that is not a contact type
-        assert_eq!(ExperienceType::from_str("invalid"), None);
-    }
-}
-
 pub async fn get_experiences(
     State(state): State<AppState>,
     session: Session,
+    Query(query): Query<ExperienceListQuery>,
 ) -> Result<Json<serde_json::Value>, AppError> {
     let user = state.user_service.check_user(&session).await?;
 
+    if query.grouped.unwrap_or(false) {
+        let experiences = state
+            .experience_service
+            .get_grouped_authenticated_experiences(user.id)
+            .await?;
+
+        return Ok(Json(serde_json::to_value(experiences).unwrap()));
+    }
+
     let experiences = state
         .experience_service
         .get_all_experiences(user.id)
@@ -98,11 +88,26 @@ pub async fn add_experience(
         .experience_service
         .validate_experience_type(&payload.exp_type)?;
 
+    state
+        .organization_service
+        .validate_organization_link(payload.organization_id, "company")
+        .await?;
+
+    state
+        .experience_service
+        .validate_experience_dates(
+            &payload.start_date,
+            &payload.end_date,
+            payload.is_current,
+        )?;
+
     let new_experience = state
         .experience_service
         .create_experience(user.id, payload)
         .await?;
 
+    state.og_image_service.invalidate(user.id);
+
     Ok(AppSuccess::CREATED {
         id: Some(new_experience),
     })
@@ -124,11 +129,26 @@ pub async fn update_experience(
         .experience_service
         .validate_experience_type(&payload.exp_type)?;
 
+    state
+        .organization_service
+        .validate_organization_link(payload.organization_id, "company")
+        .await?;
+
+    state
+        .experience_service
+        .validate_experience_dates(
+            &payload.start_date,
+            &payload.end_date,
+            payload.is_current,
+        )?;
+
     state
         .experience_service
         .update_experience(user.id, id, payload)
         .await?;
 
+    state.og_image_service.invalidate(user.id);
+
     Ok(AppSuccess::UPDATED)
 }
 
@@ -148,5 +168,29 @@ pub async fn delete_experience(
         .delete_experience(user.id, id)
         .await?;
 
+    state.og_image_service.invalidate(user.id);
+
     Ok(AppSuccess::DELETED)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ExperienceType;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(ExperienceType::from_str("Full Time"), Some(ExperienceType::FullTime));
+        assert_eq!(ExperienceType::from_str("Part Time"), Some(ExperienceType::PartTime));
+        assert_eq!(ExperienceType::from_str("Self Employed"), Some(ExperienceType::SelfEmployed));
+        assert_eq!(ExperienceType::from_str("Freelance"), Some(ExperienceType::Freelance));
+        assert_eq!(ExperienceType::from_str("Contract"), Some(ExperienceType::Contract));
+        assert_eq!(ExperienceType::from_str("Internship"), Some(ExperienceType::Internship));
+        assert_eq!(ExperienceType::from_str("Volunteering"), Some(ExperienceType::Volunteering));
+        assert_eq!(ExperienceType::from_str("Seasonal"), Some(ExperienceType::Seasonal));
+        assert_eq!(ExperienceType::from_str("Apprenticeship"), Some(ExperienceType::Apprenticeship));
+        assert_eq!(ExperienceType::from_str("Other"), Some(ExperienceType::Other));
+
+        // Test with a string that is not a contact type
+        assert_eq!(ExperienceType::from_str("invalid"), None);
+    }
+}
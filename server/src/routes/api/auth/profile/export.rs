@@ -0,0 +1,55 @@
+use axum::extract::{Query, State};
+use axum::response::Response;
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::routes::api::profile::export::{
+    get_europass_response, get_pdf_response, get_profile_export, read_pdf_template,
+};
+use crate::services::pdf_service::ResumePdfQuery;
+use crate::services::resume_service;
+use crate::AppState;
+
+/// Asynchronously exports the profile of the current user as a JSON Resume document, including masked contact
+/// entries.
+pub async fn get_json_resume(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let export = get_profile_export(&state, &user, true).await?;
+    let contact_types = state
+        .contact_type_service
+        .get_contact_types_by_name()
+        .await?;
+
+    let resume = resume_service::export_json_resume(&export, &contact_types);
+
+    Ok(Json(serde_json::to_value(&resume).unwrap()))
+}
+
+/// Asynchronously renders the profile of the current user as a PDF resume, including masked contact entries and
+/// private custom sections.
+pub async fn get_pdf_resume(
+    State(state): State<AppState>,
+    session: Session,
+    Query(query): Query<ResumePdfQuery>,
+) -> Result<Response, AppError> {
+    let template = read_pdf_template(&query)?;
+    let user = state.user_service.check_user(&session).await?;
+
+    get_pdf_response(&state, &user, true, template).await
+}
+
+/// Asynchronously exports the profile of the current user as a Europass XML document, including masked contact
+/// entries and private custom sections.
+pub async fn get_europass(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Response, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    get_europass_response(&state, &user, true).await
+}
@@ -0,0 +1,451 @@
+use axum::body::Bytes;
+use axum::extract::{Query, State};
+use axum::Json;
+use serde_json::json;
+use tower_sessions::Session;
+
+use crate::models::resume::JsonResumeModel;
+use crate::response::error_handling::AppError;
+use crate::services::contact_information_service::AddContactInformationPayload;
+use crate::services::linkedin_service;
+use crate::services::resume_service::{
+    self, ImportResumeQuery, PendingResumeImport, ResumeImport, ResumeImportItem,
+    ResumeImportProblem,
+};
+use crate::AppState;
+
+/// The maximum number of entries of each type on a profile.
+const MAX_ENTRIES: i64 = 50;
+
+/// The session key of an import waiting for confirmation.
+const PENDING_IMPORT_KEY: &str = "pending_import";
+
+/// Whether an import is added to the existing profile or replaces it.
+#[derive(PartialEq, Debug)]
+pub(crate) enum ImportMode {
+    Merge,
+    Replace,
+}
+
+impl ImportMode {
+    pub(crate) fn from_str(mode: &str) -> Option<Self> {
+        match mode {
+            "merge" => Some(ImportMode::Merge),
+            "replace" => Some(ImportMode::Replace),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the import mode from the query, merging by default.
+pub(crate) fn read_import_mode(query: &ImportResumeQuery) -> Result<ImportMode, AppError> {
+    match query.mode.as_deref() {
+        None => Ok(ImportMode::Merge),
+        Some(mode) => ImportMode::from_str(mode).ok_or(AppError::BadRequest {
+            error: Some("Invalid import mode".to_string()),
+        }),
+    }
+}
+
+/// Turns the error of a failed validation into the message of an import problem.
+fn problem_message(error: AppError) -> Result<String, AppError> {
+    match error {
+        AppError::BadRequest { error } => Ok(error.unwrap_or("Invalid entry".to_string())),
+        AppError::DataConflict { error } => Ok(error),
+        error => Err(error),
+    }
+}
+
+/// Keeps the entries that pass the validation and fit into the entry limit, and reports the others as problems.
+fn retain_valid<T>(
+    items: Vec<ResumeImportItem<T>>,
+    existing: i64,
+    limit_error: &str,
+    problems: &mut Vec<ResumeImportProblem>,
+    validate: impl Fn(&T) -> Result<bool, AppError>,
+) -> Result<Vec<ResumeImportItem<T>>, AppError> {
+    let mut valid = Vec::new();
+
+    for item in items {
+        if let Err(error) = validate(&item.payload) {
+            problems.push(item.problem(&problem_message(error)?));
+        } else if existing + valid.len() as i64 >= MAX_ENTRIES {
+            problems.push(item.problem(limit_error));
+        } else {
+            valid.push(item);
+        }
+    }
+
+    Ok(valid)
+}
+
+/// Asynchronously validates the entries of an import with the rules of the add endpoints of each entry type.
+///
+/// Entries that fail a rule, duplicate an existing contact entry, or do not fit into the limit of 50 entries per
+/// type are left out of the import and reported as problems. Contact values are converted to their canonical form.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `user_id` - The ID of the user whose profile is imported into.
+/// * `import` - The `ResumeImport` read from the imported document.
+/// * `mode` - Whether the import is added to the existing profile or replaces it.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the `ResumeImport` with only the valid entries.
+///
+/// # Errors
+///
+/// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+///
+pub(crate) async fn validate_resume_import(
+    state: &AppState,
+    user_id: i32,
+    mut import: ResumeImport,
+    mode: &ImportMode,
+) -> Result<ResumeImport, AppError> {
+    let merge = *mode == ImportMode::Merge;
+    let existing = |count: i64| if merge { count } else { 0 };
+    let mut problems = std::mem::take(&mut import.problems);
+
+    let experience_count = state
+        .experience_service
+        .get_experience_count(user_id)
+        .await?;
+    import.experiences = retain_valid(
+        import.experiences,
+        existing(experience_count),
+        "Experiences limit reached",
+        &mut problems,
+        |payload| {
+            state
+                .experience_service
+                .validate_experience_type(&payload.exp_type)?;
+            state.experience_service.validate_experience_dates(
+                &payload.start_date,
+                &payload.end_date,
+                payload.is_current,
+            )
+        },
+    )?;
+
+    let education_count = state.education_service.get_education_count(user_id).await?;
+    import.educations = retain_valid(
+        import.educations,
+        existing(education_count),
+        "Education limit reached",
+        &mut problems,
+        |payload| {
+            state
+                .education_service
+                .validate_education_dates(&payload.start_date, &payload.end_date)
+        },
+    )?;
+
+    let certification_count = state
+        .certification_service
+        .get_certification_count(user_id)
+        .await?;
+    import.certifications = retain_valid(
+        import.certifications,
+        existing(certification_count),
+        "Certification limit reached",
+        &mut problems,
+        |payload| {
+            state
+                .certification_service
+                .validate_certification_dates(&payload.issue_date, &payload.expiration_date)
+        },
+    )?;
+
+    let contact_count = state
+        .contact_information_service
+        .get_contact_information_count(user_id)
+        .await?;
+    let mut contact_information: Vec<ResumeImportItem<AddContactInformationPayload>> = Vec::new();
+
+    for mut item in std::mem::take(&mut import.contact_information) {
+        let payload = &mut item.payload;
+
+        match state
+            .contact_type_service
+            .validate_contact_information(&payload.contact_type, &payload.label, &payload.value)
+            .await
+        {
+            Ok((value, label)) => (payload.value, payload.label) = (value, label),
+            Err(error) => {
+                problems.push(item.problem(&problem_message(error)?));
+                continue;
+            }
+        }
+
+        let is_duplicate = contact_information.iter().any(|added| {
+            added.payload.contact_type == payload.contact_type
+                && added.payload.value == payload.value
+                && added.payload.label == payload.label
+        }) || (merge
+            && state
+                .contact_information_service
+                .get_existing_contact_information(user_id, payload)
+                .await?);
+
+        if is_duplicate {
+            problems.push(item.problem("Contact information already exists"));
+        } else if existing(contact_count) + contact_information.len() as i64 >= MAX_ENTRIES {
+            problems.push(item.problem("Contact information limit reached"));
+        } else {
+            contact_information.push(item);
+        }
+    }
+
+    import.contact_information = contact_information;
+    import.problems = problems;
+
+    Ok(import)
+}
+
+/// Asynchronously imports a JSON Resume document into the profile of the current user.
+///
+/// The profile fields, experiences, educations, certifications and contact information of the document are
+/// imported in one transaction. With the `mode` query parameter set to `replace`, the existing entries are
+/// replaced, otherwise the imported entries are added to them.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `query` - The import mode, `merge` or `replace`.
+/// * `resume` - The JSON Resume document.
+///
+/// # Returns
+///
+/// Returns the number of imported entries per type, and the entries that were left out with the reason.
+///
+/// # Errors
+///
+/// Returns an `AppError::BadRequest` if the import mode is invalid.
+/// Returns an `AppError::InternalError` if there is an internal error while importing the document.
+///
+pub async fn import_json_resume(
+    State(state): State<AppState>,
+    session: Session,
+    Query(query): Query<ImportResumeQuery>,
+    Json(resume): Json<JsonResumeModel>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+    let mode = read_import_mode(&query)?;
+
+    let contact_types = state
+        .contact_type_service
+        .get_contact_types_by_name()
+        .await?;
+    let import = resume_service::read_json_resume(resume, &contact_types);
+    let import = validate_resume_import(&state, user.id, import, &mode).await?;
+
+    let response = import_summary(&import);
+
+    state
+        .resume_service
+        .import_resume(user.id, import, mode == ImportMode::Replace)
+        .await?;
+
+    state.og_image_service.invalidate(user.id);
+
+    Ok(Json(response))
+}
+
+/// Summarizes an import as the number of imported entries per type and the entries that were left out.
+fn import_summary(import: &ResumeImport) -> serde_json::Value {
+    json!({
+        "imported": {
+            "profile": import.profile.is_some(),
+            "experiences": import.experiences.len(),
+            "educations": import.educations.len(),
+            "certifications": import.certifications.len(),
+            "contact_information": import.contact_information.len(),
+        },
+        "problems": import.problems,
+    })
+}
+
+/// Asynchronously compares an import with the current profile of a user.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `user_id` - The ID of the user whose profile is imported into.
+/// * `import` - The validated `ResumeImport`.
+/// * `mode` - Whether the import is added to the existing profile or replaces it.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the changed profile fields with their current and imported values, the entries
+/// that are added and, when replacing, the entries that are removed, per type, and the problems of the import.
+///
+/// # Errors
+///
+/// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+///
+async fn preview_resume_import(
+    state: &AppState,
+    user_id: i32,
+    import: &ResumeImport,
+    mode: &ImportMode,
+) -> Result<serde_json::Value, AppError> {
+    let replace = *mode == ImportMode::Replace;
+    let current = state.profile_service.get_public_profile(user_id).await?;
+
+    let profile_changes = match &import.profile {
+        Some(profile) => [
+            ("first_name", &current.first_name, &profile.first_name),
+            ("last_name", &current.last_name, &profile.last_name),
+            ("headline", &current.headline, &profile.headline),
+            ("country", &current.country, &profile.country),
+            ("city", &current.city, &profile.city),
+            ("bio", &current.bio, &profile.bio),
+        ]
+        .into_iter()
+        .filter(|(_, current, imported)| current != imported && (replace || imported.is_some()))
+        .map(|(field, current, imported)| {
+            json!({ "field": field, "current": current, "imported": imported })
+        })
+        .collect(),
+        None => Vec::new(),
+    };
+
+    let removed = match replace {
+        true => json!({
+            "experiences": state.experience_service.get_all_experiences(user_id).await?,
+            "educations": state.education_service.get_all_educations(user_id).await?,
+            "certifications": state.certification_service.get_all_certifications(user_id).await?,
+            "contact_information": state.contact_information_service.get_all_contact_information(user_id).await?,
+        }),
+        false => json!({}),
+    };
+
+    Ok(json!({
+        "mode": if replace { "replace" } else { "merge" },
+        "profile": profile_changes,
+        "added": {
+            "experiences": import.experiences,
+            "educations": import.educations,
+            "certifications": import.certifications,
+            "contact_information": import.contact_information,
+        },
+        "removed": removed,
+        "problems": import.problems,
+    }))
+}
+
+/// Asynchronously previews the import of a LinkedIn data export into the profile of the current user.
+///
+/// The body is the ZIP archive from LinkedIn's "Get a copy of your data". Nothing is written to the profile: the
+/// import is kept in the session until it is confirmed, replacing any import waiting there.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `query` - The import mode, `merge` or `replace`.
+/// * `body` - The archive.
+///
+/// # Returns
+///
+/// Returns the differences between the import and the current profile.
+///
+/// # Errors
+///
+/// Returns an `AppError::BadRequest` if the import mode is invalid or the upload is not a LinkedIn data export.
+/// Returns an `AppError::InternalError` if there is an internal error while reading the current profile.
+///
+pub async fn preview_linkedin_import(
+    State(state): State<AppState>,
+    session: Session,
+    Query(query): Query<ImportResumeQuery>,
+    body: Bytes,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+    let mode = read_import_mode(&query)?;
+
+    let import = linkedin_service::read_linkedin_archive(&body)?;
+    let import = validate_resume_import(&state, user.id, import, &mode).await?;
+    let preview = preview_resume_import(&state, user.id, &import, &mode).await?;
+
+    let pending = PendingResumeImport {
+        user_id: user.id,
+        replace: mode == ImportMode::Replace,
+        import,
+    };
+
+    session
+        .insert(PENDING_IMPORT_KEY, pending)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+    Ok(Json(preview))
+}
+
+/// Asynchronously writes the import waiting in the session of the current user to their profile.
+///
+/// The entries are validated again, as the profile may have changed since the preview.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+///
+/// # Returns
+///
+/// Returns the number of imported entries per type, and the entries that were left out with the reason.
+///
+/// # Errors
+///
+/// Returns an `AppError::NotFound` if there is no import waiting for confirmation.
+/// Returns an `AppError::InternalError` if there is an internal error while importing.
+///
+pub async fn confirm_import(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let pending = session
+        .remove::<PendingResumeImport>(PENDING_IMPORT_KEY)
+        .await
+        .map_err(|_| AppError::InternalError)?
+        .filter(|pending| pending.user_id == user.id)
+        .ok_or(AppError::NotFound {
+            error: "No import to confirm".to_string(),
+        })?;
+
+    let mode = match pending.replace {
+        true => ImportMode::Replace,
+        false => ImportMode::Merge,
+    };
+    let import = validate_resume_import(&state, user.id, pending.import, &mode).await?;
+    let response = import_summary(&import);
+
+    state
+        .resume_service
+        .import_resume(user.id, import, pending.replace)
+        .await?;
+
+    state.og_image_service.invalidate(user.id);
+
+    Ok(Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ImportMode;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(ImportMode::from_str("merge"), Some(ImportMode::Merge));
+        assert_eq!(ImportMode::from_str("replace"), Some(ImportMode::Replace));
+
+        // Test with a string that is not an import mode
+        assert_eq!(ImportMode::from_str("append"), None);
+    }
+}
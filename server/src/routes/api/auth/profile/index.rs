@@ -1,18 +1,39 @@
 use axum::extract::State;
+use axum::response::IntoResponse;
 use axum::Json;
 use tower_sessions::Session;
 
 use crate::models::profile::PublicProfileModel;
 use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::services::profile_service::UpdateProfileVisibilityPayload;
 use crate::AppState;
 
+#[derive(PartialEq, Debug)]
+pub enum ProfileVisibility {
+    Public,
+    Members,
+    Private,
+}
+
+impl ProfileVisibility {
+    pub(crate) fn from_str(s: &str) -> Option<ProfileVisibility> {
+        match s {
+            "public" => Some(ProfileVisibility::Public),
+            "members" => Some(ProfileVisibility::Members),
+            "private" => Some(ProfileVisibility::Private),
+            _ => None,
+        }
+    }
+}
+
 pub async fn get_profile(
     State(state): State<AppState>,
     session: Session,
 ) -> Result<Json<serde_json::Value>, AppError> {
     let user = state.user_service.check_user(&session).await?;
 
-    let profile = state.profile_service.get_public_profile(user.id).await?;
+    let profile = state.profile_service.get_profile(user.id).await?;
 
     Ok(Json(serde_json::to_value(profile).unwrap()))
 }
@@ -70,5 +91,70 @@ pub async fn update_profile(
         .update_profile(user.id, updated_payload)
         .await?;
 
+    state.og_image_service.invalidate(user.id);
+
     Ok(Json(serde_json::to_value(&profile).unwrap()))
 }
+
+/// Asynchronously updates who may see the profile of a user.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `payload` - A JSON payload containing the new visibility: `public`, `members` or `private`.
+///
+/// # Returns
+///
+/// Returns `AppSuccess::UPDATED` if the visibility was updated.
+///
+/// # Errors
+///
+/// Returns an `AppError::BadRequest` if the visibility is not supported.
+///
+pub async fn update_profile_visibility(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<UpdateProfileVisibilityPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    if ProfileVisibility::from_str(&payload.visibility).is_none() {
+        return Err(AppError::BadRequest {
+            error: Some("Invalid profile visibility".to_string()),
+        });
+    }
+
+    state
+        .profile_service
+        .update_profile_visibility(user.id, &payload.visibility)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ProfileVisibility;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            ProfileVisibility::from_str("public"),
+            Some(ProfileVisibility::Public)
+        );
+        assert_eq!(
+            ProfileVisibility::from_str("members"),
+            Some(ProfileVisibility::Members)
+        );
+        assert_eq!(
+            ProfileVisibility::from_str("private"),
+            Some(ProfileVisibility::Private)
+        );
+
+        // Test with strings that do not match any visibility
+        assert_eq!(ProfileVisibility::from_str("Public"), None);
+        assert_eq!(ProfileVisibility::from_str("friends"), None);
+        assert_eq!(ProfileVisibility::from_str(""), None);
+    }
+}
@@ -0,0 +1,192 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::services::language_service::{AddLanguagePayload, UpdateLanguagePayload};
+use crate::AppState;
+
+/// ISO 639-1 two-letter language codes.
+const ISO_639_1_CODES: [&str; 184] = [
+    "aa", "ab", "ae", "af", "ak", "am", "an", "ar", "as", "av", "ay", "az", "ba", "be", "bg", "bh",
+    "bi", "bm", "bn", "bo", "br", "bs", "ca", "ce", "ch", "co", "cr", "cs", "cu", "cv", "cy", "da",
+    "de", "dv", "dz", "ee", "el", "en", "eo", "es", "et", "eu", "fa", "ff", "fi", "fj", "fo", "fr",
+    "fy", "ga", "gd", "gl", "gn", "gu", "gv", "ha", "he", "hi", "ho", "hr", "ht", "hu", "hy", "hz",
+    "ia", "id", "ie", "ig", "ii", "ik", "io", "is", "it", "iu", "ja", "jv", "ka", "kg", "ki", "kj",
+    "kk", "kl", "km", "kn", "ko", "kr", "ks", "ku", "kv", "kw", "ky", "la", "lb", "lg", "li", "ln",
+    "lo", "lt", "lu", "lv", "mg", "mh", "mi", "mk", "ml", "mn", "mr", "ms", "mt", "my", "na", "nb",
+    "nd", "ne", "ng", "nl", "nn", "no", "nr", "nv", "ny", "oc", "oj", "om", "or", "os", "pa", "pi",
+    "pl", "ps", "pt", "qu", "rm", "rn", "ro", "ru", "rw", "sa", "sc", "sd", "se", "sg", "si", "sk",
+    "sl", "sm", "sn", "so", "sq", "sr", "ss", "st", "su", "sv", "sw", "ta", "te", "tg", "th", "ti",
+    "tk", "tl", "tn", "to", "tr", "ts", "tt", "tw", "ty", "ug", "uk", "ur", "uz", "ve", "vi", "vo",
+    "wa", "wo", "xh", "yi", "yo", "za", "zh", "zu",
+];
+
+pub(crate) fn is_language_code(code: &str) -> bool {
+    ISO_639_1_CODES.contains(&code.to_lowercase().as_str())
+}
+
+#[derive(PartialEq, Debug)]
+pub enum LanguageLevel {
+    A1,
+    A2,
+    B1,
+    B2,
+    C1,
+    C2,
+    Native,
+}
+
+impl LanguageLevel {
+    pub(crate) fn from_str(s: &str) -> Option<LanguageLevel> {
+        match s {
+            "A1" => Some(LanguageLevel::A1),
+            "A2" => Some(LanguageLevel::A2),
+            "B1" => Some(LanguageLevel::B1),
+            "B2" => Some(LanguageLevel::B2),
+            "C1" => Some(LanguageLevel::C1),
+            "C2" => Some(LanguageLevel::C2),
+            "Native" => Some(LanguageLevel::Native),
+            _ => None,
+        }
+    }
+}
+
+pub async fn get_languages(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let languages = state.language_service.get_all_languages(user.id).await?;
+
+    Ok(Json(serde_json::to_value(languages).unwrap()))
+}
+
+pub async fn add_language(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<AddLanguagePayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let count = state.language_service.get_language_count(user.id).await?;
+
+    if count >= 50 {
+        return Err(AppError::DataConflict {
+            error: "Language limit reached".to_string(),
+        });
+    }
+
+    state
+        .language_service
+        .validate_language(&payload.language_code, &payload.level)?;
+
+    let language_exists = state
+        .language_service
+        .get_existing_language(user.id, &payload.language_code, None)
+        .await?;
+
+    if language_exists {
+        return Err(AppError::DataConflict {
+            error: "Language already exists".to_string(),
+        });
+    }
+
+    let new_language = state
+        .language_service
+        .create_language(user.id, payload)
+        .await?;
+
+    Ok(AppSuccess::CREATED {
+        id: Some(new_language),
+    })
+}
+
+pub async fn update_language(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateLanguagePayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state
+        .language_service
+        .user_owns_language(user.id, id)
+        .await?;
+
+    state
+        .language_service
+        .validate_language(&payload.language_code, &payload.level)?;
+
+    let language_exists = state
+        .language_service
+        .get_existing_language(user.id, &payload.language_code, Some(id))
+        .await?;
+
+    if language_exists {
+        return Err(AppError::DataConflict {
+            error: "Language already exists".to_string(),
+        });
+    }
+
+    state
+        .language_service
+        .update_language(user.id, id, payload)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn delete_language(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state
+        .language_service
+        .user_owns_language(user.id, id)
+        .await?;
+
+    state
+        .language_service
+        .delete_language(user.id, id)
+        .await?;
+
+    Ok(AppSuccess::DELETED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_language_code, LanguageLevel};
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(LanguageLevel::from_str("A1"), Some(LanguageLevel::A1));
+        assert_eq!(LanguageLevel::from_str("A2"), Some(LanguageLevel::A2));
+        assert_eq!(LanguageLevel::from_str("B1"), Some(LanguageLevel::B1));
+        assert_eq!(LanguageLevel::from_str("B2"), Some(LanguageLevel::B2));
+        assert_eq!(LanguageLevel::from_str("C1"), Some(LanguageLevel::C1));
+        assert_eq!(LanguageLevel::from_str("C2"), Some(LanguageLevel::C2));
+        assert_eq!(LanguageLevel::from_str("Native"), Some(LanguageLevel::Native));
+
+        // Test with a string that is not a language level
+        assert_eq!(LanguageLevel::from_str("D1"), None);
+    }
+
+    #[test]
+    fn test_is_language_code() {
+        assert!(is_language_code("en"));
+        assert!(is_language_code("DE"));
+        assert!(is_language_code("zh"));
+
+        assert!(!is_language_code("eng"));
+        assert!(!is_language_code("xx"));
+        assert!(!is_language_code(""));
+    }
+}
@@ -1,5 +1,15 @@
+pub mod award;
 pub mod certification;
 pub mod contact_information;
+pub mod custom_section;
 pub mod education;
 pub mod experience;
+pub mod export;
+pub mod import;
 pub mod index;
+pub mod language;
+pub mod patent;
+pub mod project;
+pub mod publication;
+pub mod recommendation;
+pub mod skill;
@@ -0,0 +1,124 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::services::patent_service::{AddPatentPayload, UpdatePatentPayload};
+use crate::AppState;
+
+#[derive(PartialEq, Debug)]
+pub enum PatentStatus {
+    Pending,
+    Granted,
+    Expired,
+    Abandoned,
+}
+
+impl PatentStatus {
+    pub(crate) fn from_str(s: &str) -> Option<PatentStatus> {
+        match s {
+            "Pending" => Some(PatentStatus::Pending),
+            "Granted" => Some(PatentStatus::Granted),
+            "Expired" => Some(PatentStatus::Expired),
+            "Abandoned" => Some(PatentStatus::Abandoned),
+            _ => None,
+        }
+    }
+}
+
+pub async fn get_patents(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let patents = state.patent_service.get_all_patents(user.id).await?;
+
+    Ok(Json(serde_json::to_value(&patents).unwrap()))
+}
+
+pub async fn add_patent(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<AddPatentPayload>,
+) -> Result<AppSuccess, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let count = state.patent_service.get_patent_count(user.id).await?;
+
+    if count >= 50 {
+        return Err(AppError::DataConflict {
+            error: "Patent limit reached".to_string(),
+        });
+    }
+
+    state.patent_service.validate_patent(
+        &payload.title,
+        &payload.patent_number,
+        &payload.office,
+        &payload.status,
+    )?;
+
+    let new_patent = state.patent_service.create_patent(user.id, payload).await?;
+
+    Ok(AppSuccess::CREATED {
+        id: Some(new_patent),
+    })
+}
+
+pub async fn update_patent(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdatePatentPayload>,
+) -> Result<AppSuccess, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state.patent_service.user_owns_patent(user.id, id).await?;
+
+    state.patent_service.validate_patent(
+        &payload.title,
+        &payload.patent_number,
+        &payload.office,
+        &payload.status,
+    )?;
+
+    state
+        .patent_service
+        .update_patent(user.id, id, payload)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn delete_patent(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state.patent_service.user_owns_patent(user.id, id).await?;
+
+    state.patent_service.delete_patent(user.id, id).await?;
+
+    Ok(AppSuccess::DELETED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PatentStatus;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(PatentStatus::from_str("Pending"), Some(PatentStatus::Pending));
+        assert_eq!(PatentStatus::from_str("Granted"), Some(PatentStatus::Granted));
+        assert_eq!(PatentStatus::from_str("Expired"), Some(PatentStatus::Expired));
+        assert_eq!(PatentStatus::from_str("Abandoned"), Some(PatentStatus::Abandoned));
+
+        // Test with a string that is not a patent status
+        assert_eq!(PatentStatus::from_str("invalid"), None);
+    }
+}
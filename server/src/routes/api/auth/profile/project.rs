@@ -0,0 +1,91 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::services::project_service::{AddProjectPayload, UpdateProjectPayload};
+use crate::AppState;
+
+pub async fn get_projects(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let projects = state.project_service.get_all_projects(user.id).await?;
+
+    Ok(Json(serde_json::to_value(projects).unwrap()))
+}
+
+pub async fn add_project(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<AddProjectPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let count = state.project_service.get_project_count(user.id).await?;
+
+    if count >= 50 {
+        return Err(AppError::DataConflict {
+            error: "Project limit reached".to_string(),
+        });
+    }
+
+    state.project_service.validate_project(
+        &payload.name,
+        [&payload.url, &payload.repository_url],
+        &payload.skills,
+        &payload.images,
+    )?;
+
+    let new_project = state
+        .project_service
+        .create_project(user.id, payload)
+        .await?;
+
+    Ok(AppSuccess::CREATED {
+        id: Some(new_project),
+    })
+}
+
+pub async fn update_project(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateProjectPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state.project_service.user_owns_project(user.id, id).await?;
+
+    state.project_service.validate_project(
+        &payload.name,
+        [&payload.url, &payload.repository_url],
+        &payload.skills,
+        &payload.images,
+    )?;
+
+    state
+        .project_service
+        .update_project(user.id, id, payload)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn delete_project(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state.project_service.user_owns_project(user.id, id).await?;
+
+    state.project_service.delete_project(user.id, id).await?;
+
+    Ok(AppSuccess::DELETED)
+}
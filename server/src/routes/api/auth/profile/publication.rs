@@ -0,0 +1,82 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::services::publication_service::{AddPublicationPayload, UpdatePublicationPayload};
+use crate::AppState;
+
+pub async fn get_publications(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let publications = state.publication_service.get_all_publications(user.id).await?;
+
+    Ok(Json(serde_json::to_value(&publications).unwrap()))
+}
+
+pub async fn add_publication(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<AddPublicationPayload>,
+) -> Result<AppSuccess, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let count = state.publication_service.get_publication_count(user.id).await?;
+
+    if count >= 50 {
+        return Err(AppError::DataConflict {
+            error: "Publication limit reached".to_string(),
+        });
+    }
+
+    state
+        .publication_service
+        .validate_publication(&payload.title, &payload.doi)?;
+
+    let new_publication = state.publication_service.create_publication(user.id, payload).await?;
+
+    Ok(AppSuccess::CREATED {
+        id: Some(new_publication),
+    })
+}
+
+pub async fn update_publication(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdatePublicationPayload>,
+) -> Result<AppSuccess, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state.publication_service.user_owns_publication(user.id, id).await?;
+
+    state
+        .publication_service
+        .validate_publication(&payload.title, &payload.doi)?;
+
+    state
+        .publication_service
+        .update_publication(user.id, id, payload)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn delete_publication(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state.publication_service.user_owns_publication(user.id, id).await?;
+
+    state.publication_service.delete_publication(user.id, id).await?;
+
+    Ok(AppSuccess::DELETED)
+}
@@ -0,0 +1,319 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::models::notification::NotificationKind;
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::services::recommendation_service::{
+    AddRecommendationPayload, RequestRecommendationPayload,
+};
+use crate::AppState;
+
+/// How many recommendation requests a user may send per hour.
+const RECOMMENDATION_REQUESTS_PER_HOUR: i64 = 10;
+
+#[derive(PartialEq, Debug)]
+pub enum RecommendationRelationship {
+    Manager,
+    DirectReport,
+    Colleague,
+    Client,
+    Mentor,
+    Teacher,
+    Student,
+    Other,
+}
+
+impl RecommendationRelationship {
+    pub(crate) fn from_str(s: &str) -> Option<RecommendationRelationship> {
+        match s {
+            "Manager" => Some(RecommendationRelationship::Manager),
+            "Direct Report" => Some(RecommendationRelationship::DirectReport),
+            "Colleague" => Some(RecommendationRelationship::Colleague),
+            "Client" => Some(RecommendationRelationship::Client),
+            "Mentor" => Some(RecommendationRelationship::Mentor),
+            "Teacher" => Some(RecommendationRelationship::Teacher),
+            "Student" => Some(RecommendationRelationship::Student),
+            "Other" => Some(RecommendationRelationship::Other),
+            _ => None,
+        }
+    }
+}
+
+pub async fn get_recommendations(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let recommendations = state
+        .recommendation_service
+        .get_authenticated_recommendations(user.id)
+        .await?;
+
+    Ok(Json(serde_json::to_value(recommendations).unwrap()))
+}
+
+pub async fn get_written_recommendations(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let recommendations = state
+        .recommendation_service
+        .get_written_recommendations(user.id)
+        .await?;
+
+    Ok(Json(serde_json::to_value(recommendations).unwrap()))
+}
+
+pub async fn add_recommendation(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<AddRecommendationPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+    let recipient = state
+        .user_service
+        .get_user_by_username(payload.username.clone())
+        .await?;
+
+    let count = state
+        .recommendation_service
+        .get_written_recommendation_count(user.id)
+        .await?;
+
+    if count >= 50 {
+        return Err(AppError::DataConflict {
+            error: "Recommendation limit reached".to_string(),
+        });
+    }
+
+    state.recommendation_service.validate_recommendation(
+        user.id,
+        recipient.id,
+        &payload.relationship,
+        &payload.body,
+    )?;
+
+    if let Some(experience_id) = payload.experience_id {
+        state
+            .recommendation_service
+            .validate_recommendation_experience(recipient.id, experience_id)
+            .await?;
+    }
+
+    let pending = state
+        .recommendation_service
+        .has_pending_recommendation(user.id, recipient.id)
+        .await?;
+
+    if pending {
+        return Err(AppError::DataConflict {
+            error: "Recommendation already pending".to_string(),
+        });
+    }
+
+    let new_recommendation = state
+        .recommendation_service
+        .create_recommendation(user.id, recipient.id, payload)
+        .await?;
+
+    state
+        .notification_service
+        .notify(
+            recipient.id,
+            NotificationKind::RecommendationReceived,
+            format!("{} wrote you a recommendation", user.username),
+            Some(new_recommendation as i32),
+        )
+        .await?;
+
+    Ok(AppSuccess::CREATED {
+        id: Some(new_recommendation),
+    })
+}
+
+pub async fn request_recommendation(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<RequestRecommendationPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+    let author = state
+        .user_service
+        .get_user_by_username(payload.username)
+        .await?;
+
+    if author.id == user.id {
+        return Err(AppError::BadRequest {
+            error: Some("You cannot recommend yourself".to_string()),
+        });
+    }
+
+    let requests = state
+        .recommendation_service
+        .get_recent_recommendation_request_count(user.id)
+        .await?;
+
+    if requests >= RECOMMENDATION_REQUESTS_PER_HOUR {
+        return Err(AppError::TooManyRequests {
+            error: "Too many recommendation requests, try again later".to_string(),
+        });
+    }
+
+    let open = state
+        .recommendation_service
+        .has_open_recommendation_request(user.id, author.id)
+        .await?;
+
+    if open {
+        return Err(AppError::DataConflict {
+            error: "Recommendation already requested".to_string(),
+        });
+    }
+
+    state
+        .recommendation_service
+        .create_recommendation_request(user.id, author.id)
+        .await?;
+
+    state
+        .notification_service
+        .notify(
+            author.id,
+            NotificationKind::RecommendationRequested,
+            format!("{} asked you for a recommendation", user.username),
+            Some(user.id),
+        )
+        .await?;
+
+    Ok(AppSuccess::CREATED { id: None })
+}
+
+pub async fn approve_recommendation(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let author_id = state
+        .recommendation_service
+        .transition_recommendation(user.id, id, &["pending", "hidden"], "approved")
+        .await?;
+
+    state
+        .notification_service
+        .notify(
+            author_id,
+            NotificationKind::RecommendationApproved,
+            format!("{} approved your recommendation", user.username),
+            Some(id),
+        )
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn decline_recommendation(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let author_id = state
+        .recommendation_service
+        .transition_recommendation(user.id, id, &["pending"], "declined")
+        .await?;
+
+    state
+        .notification_service
+        .notify(
+            author_id,
+            NotificationKind::RecommendationDeclined,
+            format!("{} declined your recommendation", user.username),
+            Some(id),
+        )
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn hide_recommendation(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state
+        .recommendation_service
+        .transition_recommendation(user.id, id, &["approved"], "hidden")
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn delete_recommendation(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state
+        .recommendation_service
+        .delete_recommendation(user.id, id)
+        .await?;
+
+    Ok(AppSuccess::DELETED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RecommendationRelationship;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            RecommendationRelationship::from_str("Manager"),
+            Some(RecommendationRelationship::Manager)
+        );
+        assert_eq!(
+            RecommendationRelationship::from_str("Direct Report"),
+            Some(RecommendationRelationship::DirectReport)
+        );
+        assert_eq!(
+            RecommendationRelationship::from_str("Colleague"),
+            Some(RecommendationRelationship::Colleague)
+        );
+        assert_eq!(
+            RecommendationRelationship::from_str("Client"),
+            Some(RecommendationRelationship::Client)
+        );
+        assert_eq!(
+            RecommendationRelationship::from_str("Mentor"),
+            Some(RecommendationRelationship::Mentor)
+        );
+        assert_eq!(
+            RecommendationRelationship::from_str("Teacher"),
+            Some(RecommendationRelationship::Teacher)
+        );
+        assert_eq!(
+            RecommendationRelationship::from_str("Student"),
+            Some(RecommendationRelationship::Student)
+        );
+        assert_eq!(
+            RecommendationRelationship::from_str("Other"),
+            Some(RecommendationRelationship::Other)
+        );
+
+        // Test with a string that is not a relationship
+        assert_eq!(RecommendationRelationship::from_str("Friend"), None);
+    }
+}
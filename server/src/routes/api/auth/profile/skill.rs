@@ -0,0 +1,150 @@
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::response::success_handling::AppSuccess;
+use crate::services::skill_service::{AddSkillPayload, UpdateSkillPayload};
+use crate::AppState;
+
+#[derive(PartialEq, Debug)]
+pub enum SkillProficiency {
+    Beginner,
+    Intermediate,
+    Advanced,
+    Expert,
+}
+
+impl SkillProficiency {
+    pub(crate) fn from_str(s: &str) -> Option<SkillProficiency> {
+        match s {
+            "Beginner" => Some(SkillProficiency::Beginner),
+            "Intermediate" => Some(SkillProficiency::Intermediate),
+            "Advanced" => Some(SkillProficiency::Advanced),
+            "Expert" => Some(SkillProficiency::Expert),
+            _ => None,
+        }
+    }
+}
+
+pub async fn get_skills(
+    State(state): State<AppState>,
+    session: Session,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let skills = state.skill_service.get_all_skills(user.id).await?;
+
+    Ok(Json(serde_json::to_value(skills).unwrap()))
+}
+
+pub async fn add_skill(
+    State(state): State<AppState>,
+    session: Session,
+    Json(payload): Json<AddSkillPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    let count = state.skill_service.get_skill_count(user.id).await?;
+
+    if count >= 50 {
+        return Err(AppError::DataConflict {
+            error: "Skill limit reached".to_string(),
+        });
+    }
+
+    state
+        .skill_service
+        .validate_skill(&payload.name, &payload.proficiency)?;
+
+    state
+        .skill_service
+        .validate_skill_experiences(user.id, &payload.experience_ids)
+        .await?;
+
+    let skill_exists = state
+        .skill_service
+        .get_existing_skill(user.id, &payload.name, None)
+        .await?;
+
+    if skill_exists {
+        return Err(AppError::DataConflict {
+            error: "Skill already exists".to_string(),
+        });
+    }
+
+    let new_skill = state.skill_service.create_skill(user.id, payload).await?;
+
+    Ok(AppSuccess::CREATED {
+        id: Some(new_skill),
+    })
+}
+
+pub async fn update_skill(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateSkillPayload>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state.skill_service.user_owns_skill(user.id, id).await?;
+
+    state
+        .skill_service
+        .validate_skill(&payload.name, &payload.proficiency)?;
+
+    state
+        .skill_service
+        .validate_skill_experiences(user.id, &payload.experience_ids)
+        .await?;
+
+    let skill_exists = state
+        .skill_service
+        .get_existing_skill(user.id, &payload.name, Some(id))
+        .await?;
+
+    if skill_exists {
+        return Err(AppError::DataConflict {
+            error: "Skill already exists".to_string(),
+        });
+    }
+
+    state
+        .skill_service
+        .update_skill(user.id, id, payload)
+        .await?;
+
+    Ok(AppSuccess::UPDATED)
+}
+
+pub async fn delete_skill(
+    State(state): State<AppState>,
+    session: Session,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = state.user_service.check_user(&session).await?;
+
+    state.skill_service.user_owns_skill(user.id, id).await?;
+
+    state.skill_service.delete_skill(user.id, id).await?;
+
+    Ok(AppSuccess::DELETED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SkillProficiency;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(SkillProficiency::from_str("Beginner"), Some(SkillProficiency::Beginner));
+        assert_eq!(SkillProficiency::from_str("Intermediate"), Some(SkillProficiency::Intermediate));
+        assert_eq!(SkillProficiency::from_str("Advanced"), Some(SkillProficiency::Advanced));
+        assert_eq!(SkillProficiency::from_str("Expert"), Some(SkillProficiency::Expert));
+
+        // Test with a string that is not a skill proficiency
+        assert_eq!(SkillProficiency::from_str("invalid"), None);
+    }
+}
@@ -0,0 +1,27 @@
+use axum::extract::State;
+use axum::Json;
+
+use crate::response::error_handling::AppError;
+use crate::AppState;
+
+/// Asynchronously retrieves the contact types users can choose for their contact information.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+///
+/// # Returns
+///
+/// Returns a JSON array of the enabled contact types with their validation rules and URL templates.
+///
+/// # Errors
+///
+/// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+///
+pub async fn get_contact_types(
+    State(state): State<AppState>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let contact_types = state.contact_type_service.get_contact_types(false).await?;
+
+    Ok(Json(serde_json::to_value(contact_types).unwrap()))
+}
@@ -0,0 +1,46 @@
+use axum::extract::{Path, State};
+use axum::http::header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::StatusCode;
+use axum::response::Response;
+
+use crate::response::error_handling::AppError;
+use crate::AppState;
+
+/// Asynchronously downloads the archive of a data export through its time-limited link.
+///
+/// The token of the link is the only credential, so the archive can also be downloaded outside the web client.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `token` - The token of the download link.
+///
+/// # Returns
+///
+/// Returns the ZIP archive as an attachment named after the username.
+///
+/// # Errors
+///
+/// Returns an `AppError::NotFound` if there is no finished export with the token or its link has expired.
+/// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+///
+pub async fn download_data_export(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Response, AppError> {
+    let (username, archive) = state
+        .data_export_service
+        .get_data_export_archive(&token)
+        .await?;
+
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/zip")
+        .header(CACHE_CONTROL, "no-store")
+        .header(
+            CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}-ideno-data.zip\"", username),
+        )
+        .body(archive.into())
+        .unwrap())
+}
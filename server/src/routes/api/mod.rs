@@ -1,2 +1,5 @@
 pub mod auth;
+pub mod contact_type;
+pub mod data_export;
+pub mod organization;
 pub mod profile;
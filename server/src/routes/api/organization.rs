@@ -0,0 +1,88 @@
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::models::organization::OrganizationPageResponse;
+use crate::response::error_handling::AppError;
+use crate::routes::api::auth::organization::OrganizationType;
+use crate::services::organization_service::OrganizationSearchQuery;
+use crate::AppState;
+
+/// Asynchronously searches organizations by name, for autocompleting the company or school of an entry.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `query` - The query parameters: the text to search for in `q` and an optional `org_type`.
+///
+/// # Returns
+///
+/// Returns a JSON array of at most ten matching organizations. An empty query returns no organizations.
+///
+/// # Errors
+///
+/// Returns an `AppError::BadRequest` if the organization type is not supported.
+///
+pub async fn search_organizations(
+    State(state): State<AppState>,
+    Query(query): Query<OrganizationSearchQuery>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    if let Some(org_type) = &query.org_type {
+        if OrganizationType::from_str(org_type).is_none() {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid organization type".to_string()),
+            });
+        }
+    }
+
+    if query.q.trim().is_empty() {
+        return Ok(Json(serde_json::json!([])));
+    }
+
+    let organizations = state
+        .organization_service
+        .search_organizations(&query.q, query.org_type)
+        .await?;
+
+    Ok(Json(serde_json::to_value(organizations).unwrap()))
+}
+
+/// Asynchronously retrieves the page of an organization.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `slug` - The slug of the organization.
+///
+/// # Returns
+///
+/// Returns a JSON representation of the organization and of its members whose profiles the current user may see.
+///
+/// # Errors
+///
+/// Returns an `AppError::NotFound` if no organization has the given slug.
+///
+pub async fn get_organization(
+    State(state): State<AppState>,
+    session: Session,
+    Path(slug): Path<String>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let optional_user = state.user_service.check_user_optional(&session).await?;
+    let organization = state
+        .organization_service
+        .get_organization_by_slug(&slug)
+        .await?;
+
+    let members = state
+        .organization_service
+        .get_organization_members(organization.id, &optional_user)
+        .await?;
+
+    let response = OrganizationPageResponse {
+        organization,
+        members,
+    };
+
+    Ok(Json(serde_json::to_value(response).unwrap()))
+}
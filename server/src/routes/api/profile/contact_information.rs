@@ -1,10 +1,17 @@
-use axum::extract::{Path, State};
+use std::net::SocketAddr;
+
+use axum::extract::{ConnectInfo, Path, State};
+use axum::http::HeaderMap;
 use axum::Json;
+use sha2::{Digest, Sha256};
 use tower_sessions::Session;
 
 use crate::response::error_handling::AppError;
 use crate::AppState;
 
+/// How many masked contact entries a viewer may reveal per hour.
+const REVEALS_PER_HOUR: i64 = 20;
+
 /// Asynchronously retrieves public contact information for a user.
 ///
 /// # Arguments
@@ -31,6 +38,10 @@ pub async fn get_public_contact_information(
 ) -> Result<Json<serde_json::Value>, AppError> {
     let optional_user = state.user_service.check_user_optional(&session).await?;
     let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &optional_user)
+        .await?;
 
     let contact_information = match optional_user {
         Some(logged_in_user) if logged_in_user.id == user.id => {
@@ -51,3 +62,88 @@ pub async fn get_public_contact_information(
 
     Ok(Json(contact_information))
 }
+
+/// Asynchronously reveals the value of a masked contact entry.
+///
+/// Entries with the `members` visibility can only be revealed by logged-in users. Each viewer, identified by
+/// their user ID or else by their IP address, may reveal a limited number of entries per hour, and every reveal
+/// is recorded for the owner. Owners and public entries are not limited nor recorded.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `address` - The address of the peer, a trusted reverse proxy forwards the client address in `X-Forwarded-For`.
+/// * `headers` - The headers of the request.
+/// * `identifier` - The username of the user who owns the entry.
+/// * `id` - The ID of the contact information entry.
+///
+/// # Returns
+///
+/// Returns a JSON representation of the unmasked contact information entry.
+///
+/// # Errors
+///
+/// Returns an `AppError::NotFound` if the user has no contact information entry with the given ID.
+/// Returns an `AppError::NotLoggedIn` if the entry is only revealed to logged-in users.
+/// Returns an `AppError::TooManyRequests` if the viewer revealed too many entries within the last hour.
+///
+pub async fn reveal_contact_information(
+    State(state): State<AppState>,
+    session: Session,
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Path((identifier, id)): Path<(String, i32)>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let optional_user = state.user_service.check_user_optional(&session).await?;
+    let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &optional_user)
+        .await?;
+
+    let entry = state
+        .contact_information_service
+        .get_revealed_contact_information(user.id, id)
+        .await?;
+
+    let is_owner = optional_user
+        .as_ref()
+        .is_some_and(|viewer| viewer.id == user.id);
+
+    if entry.visibility != "public" && !is_owner {
+        if entry.visibility == "members" && optional_user.is_none() {
+            return Err(AppError::NotLoggedIn);
+        }
+
+        let viewer_key = match &optional_user {
+            Some(viewer) => format!("user:{}", viewer.id),
+            None => format!(
+                "ip:{}",
+                state
+                    .contact_information_service
+                    .client_address(address.ip(), &headers)
+            ),
+        };
+        let viewer_key = hex::encode(Sha256::digest(viewer_key.as_bytes()));
+
+        let recorded = state
+            .contact_information_service
+            .record_contact_reveal(
+                entry.id,
+                user.id,
+                optional_user.as_ref().map(|viewer| viewer.id),
+                &viewer_key,
+                REVEALS_PER_HOUR,
+            )
+            .await?;
+
+        if !recorded {
+            return Err(AppError::TooManyRequests {
+                error: "Too many contact information reveals, try again later".to_string(),
+            });
+        }
+    }
+
+    Ok(Json(serde_json::to_value(entry).unwrap()))
+}
@@ -0,0 +1,57 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::AppState;
+
+/// Asynchronously retrieves public custom sections for a user.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `identifier` - The username of the user whose custom sections are to be retrieved.
+///
+/// # Returns
+///
+/// Returns a JSON representation of the custom sections and their entries associated with the user.
+/// The outcome differs based on whether the current user is the owner of the profile or not.
+/// If the current user is the owner, it retrieves all sections including private ones.
+/// If the current user is not the owner, it retrieves public sections only.
+///
+/// # Errors
+///
+/// Returns an `AppError` if there is an error during the retrieval process.
+///
+pub async fn get_public_custom_sections(
+    State(state): State<AppState>,
+    session: Session,
+    Path(identifier): Path<String>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let optional_user = state.user_service.check_user_optional(&session).await?;
+    let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &optional_user)
+        .await?;
+
+    let sections = match optional_user {
+        Some(logged_in_user) if logged_in_user.id == user.id => {
+            let sections = state
+                .custom_section_service
+                .get_authenticated_custom_sections(logged_in_user.id)
+                .await?;
+            serde_json::to_value(&sections).unwrap()
+        }
+        _ => {
+            let sections = state
+                .custom_section_service
+                .get_public_custom_sections(user.id)
+                .await?;
+            serde_json::to_value(&sections).unwrap()
+        }
+    };
+
+    Ok(Json(sections))
+}
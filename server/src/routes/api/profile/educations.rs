@@ -31,6 +31,10 @@ pub async fn get_public_educations(
 ) -> Result<Json<serde_json::Value>, AppError> {
     let optional_user = state.user_service.check_user_optional(&session).await?;
     let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &optional_user)
+        .await?;
 
     let educations = match optional_user {
         Some(logged_in_user) if logged_in_user.id == user.id => {
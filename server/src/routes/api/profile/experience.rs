@@ -1,8 +1,9 @@
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::Json;
 use tower_sessions::Session;
 
 use crate::response::error_handling::AppError;
+use crate::services::experience_service::ExperienceListQuery;
 use crate::AppState;
 
 /// Asynchronously retrieves public experiences for a user.
@@ -12,6 +13,7 @@ use crate::AppState;
 /// * `state` - The application state containing service instances.
 /// * `session` - The session information for the current user.
 /// * `identifier` - The username of the user whose experiences are to be retrieved.
+/// * `query` - The query parameters. With `grouped=true`, consecutive positions at the same company are grouped.
 ///
 /// # Returns
 ///
@@ -28,11 +30,24 @@ pub async fn get_public_experiences(
     State(state): State<AppState>,
     session: Session,
     Path(identifier): Path<String>,
+    Query(query): Query<ExperienceListQuery>,
 ) -> Result<Json<serde_json::Value>, AppError> {
+    let grouped = query.grouped.unwrap_or(false);
     let optional_user = state.user_service.check_user_optional(&session).await?;
     let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &optional_user)
+        .await?;
 
     let experiences = match optional_user {
+        Some(logged_in_user) if logged_in_user.id == user.id && grouped => {
+            let experiences = state
+                .experience_service
+                .get_grouped_authenticated_experiences(logged_in_user.id)
+                .await?;
+            serde_json::to_value(&experiences).unwrap()
+        }
         Some(logged_in_user) if logged_in_user.id == user.id => {
             let experiences = state
                 .experience_service
@@ -40,6 +55,13 @@ pub async fn get_public_experiences(
                 .await?;
             serde_json::to_value(&experiences).unwrap()
         }
+        _ if grouped => {
+            let experiences = state
+                .experience_service
+                .get_grouped_public_experiences(user.id)
+                .await?;
+            serde_json::to_value(&experiences).unwrap()
+        }
         _ => {
             let experiences = state
                 .experience_service
@@ -0,0 +1,304 @@
+use axum::extract::{Path, Query, State};
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::models::resume::ProfileExportModel;
+use crate::models::user::UserModel;
+use crate::response::error_handling::AppError;
+use crate::services::europass_service;
+use crate::services::pdf_service::{self, PdfTemplate, ResumePdfQuery};
+use crate::services::resume_service;
+use crate::AppState;
+
+/// Reads the template of a PDF resume, which defaults to the classic template.
+pub(crate) fn read_pdf_template(query: &ResumePdfQuery) -> Result<PdfTemplate, AppError> {
+    match query.template.as_deref() {
+        None => Ok(PdfTemplate::Classic),
+        Some(template) => PdfTemplate::from_str(template).ok_or(AppError::BadRequest {
+            error: Some("Invalid template".to_string()),
+        }),
+    }
+}
+
+/// Asynchronously renders the profile of a user as a PDF resume.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `user` - The user whose profile is rendered.
+/// * `is_owner` - Whether the resume is rendered for the owner of the profile, see `get_profile_export`.
+/// * `template` - The layout of the resume.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the response with the PDF document, named after the username.
+///
+/// # Errors
+///
+/// Returns an `AppError` if there is an error during the retrieval process or the rendering of the document.
+///
+pub(crate) async fn get_pdf_response(
+    state: &AppState,
+    user: &UserModel,
+    is_owner: bool,
+    template: PdfTemplate,
+) -> Result<Response, AppError> {
+    let export = get_profile_export(state, user, is_owner).await?;
+    let contact_types = state
+        .contact_type_service
+        .get_contact_types_by_name()
+        .await?;
+
+    let pdf = tokio::task::spawn_blocking(move || {
+        pdf_service::render_resume_pdf(&export, &contact_types, template)
+    })
+    .await
+    .map_err(|_| AppError::InternalError)??;
+
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/pdf")
+        .header(
+            CONTENT_DISPOSITION,
+            format!("inline; filename=\"{}-resume.pdf\"", user.username),
+        )
+        .body(pdf.into())
+        .unwrap())
+}
+
+/// Asynchronously exports the profile of a user as a Europass XML document.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `user` - The user whose profile is exported.
+/// * `is_owner` - Whether the profile is exported for its owner, see `get_profile_export`.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the response with the XML document as an attachment named after the username.
+///
+/// # Errors
+///
+/// Returns an `AppError` if there is an error during the retrieval process or the writing of the document.
+///
+pub(crate) async fn get_europass_response(
+    state: &AppState,
+    user: &UserModel,
+    is_owner: bool,
+) -> Result<Response, AppError> {
+    let export = get_profile_export(state, user, is_owner).await?;
+    let contact_types = state
+        .contact_type_service
+        .get_contact_types_by_name()
+        .await?;
+
+    let xml = europass_service::export_europass(&export, &contact_types)?;
+
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/xml; charset=utf-8")
+        .header(
+            CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}-europass.xml\"", user.username),
+        )
+        .body(xml.into())
+        .unwrap())
+}
+
+/// Asynchronously collects the parts of a profile that are exported into resume documents.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `user` - The user whose profile is exported.
+/// * `is_owner` - Whether the profile is exported for its owner, who sees masked contact entries unmasked and
+///   private custom sections.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the `ProfileExportModel` of the user.
+///
+/// # Errors
+///
+/// Returns an `AppError` if there is an error during the retrieval process.
+///
+pub(crate) async fn get_profile_export(
+    state: &AppState,
+    user: &UserModel,
+    is_owner: bool,
+) -> Result<ProfileExportModel, AppError> {
+    let profile = state.profile_service.get_public_profile(user.id).await?;
+    let experiences = state
+        .experience_service
+        .get_public_experiences(user.id, None)
+        .await?;
+    let educations = state
+        .education_service
+        .get_public_educations(user.id, None)
+        .await?;
+    let certifications = state
+        .certification_service
+        .get_public_certifications(user.id, None)
+        .await?;
+    let contact_information = match is_owner {
+        true => state
+            .contact_information_service
+            .get_authenticated_contact_information(user.id)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        false => {
+            state
+                .contact_information_service
+                .get_public_contact_information(user.id, None)
+                .await?
+        }
+    };
+    let projects = state
+        .project_service
+        .get_public_projects(user.id, None)
+        .await?;
+    let languages = state
+        .language_service
+        .get_public_languages(user.id, None)
+        .await?;
+    let custom_sections = match is_owner {
+        true => state
+            .custom_section_service
+            .get_authenticated_custom_sections(user.id)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        false => {
+            state
+                .custom_section_service
+                .get_public_custom_sections(user.id)
+                .await?
+        }
+    };
+
+    Ok(ProfileExportModel {
+        profile,
+        experiences,
+        educations,
+        certifications,
+        contact_information,
+        projects,
+        languages,
+        custom_sections,
+    })
+}
+
+/// Asynchronously exports the profile of a user as a JSON Resume document.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `identifier` - The username of the user whose profile is to be exported.
+///
+/// # Returns
+///
+/// Returns the JSON Resume document of the profile, with only the contact entries visible to the viewer.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the profile is not visible to the viewer or there is an error during the retrieval process.
+///
+pub async fn get_public_json_resume(
+    State(state): State<AppState>,
+    session: Session,
+    Path(identifier): Path<String>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let optional_user = state.user_service.check_user_optional(&session).await?;
+    let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &optional_user)
+        .await?;
+
+    let is_owner = optional_user.is_some_and(|logged_in_user| logged_in_user.id == user.id);
+    let export = get_profile_export(&state, &user, is_owner).await?;
+    let contact_types = state
+        .contact_type_service
+        .get_contact_types_by_name()
+        .await?;
+
+    let resume = resume_service::export_json_resume(&export, &contact_types);
+
+    Ok(Json(serde_json::to_value(&resume).unwrap()))
+}
+
+/// Asynchronously renders the profile of a user as a PDF resume.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `identifier` - The username of the user whose profile is to be rendered.
+/// * `query` - The template of the resume, `classic` or `sidebar`.
+///
+/// # Returns
+///
+/// Returns the PDF document of the profile, with only the contact entries and custom sections visible to the
+/// viewer.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the template is invalid, the profile is not visible to the viewer or there is an error
+/// during the rendering process.
+///
+pub async fn get_public_pdf_resume(
+    State(state): State<AppState>,
+    session: Session,
+    Path(identifier): Path<String>,
+    Query(query): Query<ResumePdfQuery>,
+) -> Result<Response, AppError> {
+    let template = read_pdf_template(&query)?;
+    let optional_user = state.user_service.check_user_optional(&session).await?;
+    let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &optional_user)
+        .await?;
+
+    get_pdf_response(&state, &user, false, template).await
+}
+
+/// Asynchronously exports the profile of a user as a Europass XML document.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `identifier` - The username of the user whose profile is to be exported.
+///
+/// # Returns
+///
+/// Returns the Europass XML document of the profile, with only the contact entries and custom sections visible to
+/// the viewer.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the profile is not visible to the viewer or there is an error during the export process.
+///
+pub async fn get_public_europass(
+    State(state): State<AppState>,
+    session: Session,
+    Path(identifier): Path<String>,
+) -> Result<Response, AppError> {
+    let optional_user = state.user_service.check_user_optional(&session).await?;
+    let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &optional_user)
+        .await?;
+
+    get_europass_response(&state, &user, false).await
+}
@@ -1,6 +1,7 @@
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
+use tower_sessions::Session;
 
 use crate::models::profile::PublicProfileResponse;
 use crate::response::error_handling::AppError;
@@ -8,9 +9,15 @@ use crate::AppState;
 
 pub async fn get_public_profile(
     State(state): State<AppState>,
+    session: Session,
     Path(identifier): Path<String>,
 ) -> Result<impl IntoResponse, AppError> {
+    let optional_user = state.user_service.check_user_optional(&session).await?;
     let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &optional_user)
+        .await?;
     let found_profile = state.profile_service.get_public_profile(user.id).await?;
 
     let certifications = state
@@ -25,17 +32,42 @@ pub async fn get_public_profile(
         .experience_service
         .get_public_experiences(user.id, Some(3))
         .await?;
+    let experience_metrics = state
+        .experience_service
+        .get_experience_metrics(user.id)
+        .await?;
     let contact_information = state
         .contact_information_service
         .get_public_contact_information(user.id, Some(4))
         .await?;
+    let projects = state
+        .project_service
+        .get_public_projects(user.id, Some(3))
+        .await?;
+    let languages = state
+        .language_service
+        .get_public_languages(user.id, None)
+        .await?;
+    let custom_sections = state
+        .custom_section_service
+        .get_public_custom_sections(user.id)
+        .await?;
+    let recommendations = state
+        .recommendation_service
+        .get_public_recommendations(user.id, Some(3))
+        .await?;
 
     let response = PublicProfileResponse {
         profile: found_profile,
         certification: certifications,
         education: educations,
         experience: experiences,
+        experience_metrics,
         contact_information,
+        project: projects,
+        language: languages,
+        custom_sections,
+        recommendation: recommendations,
     };
 
     Ok(Response::builder()
@@ -2,4 +2,15 @@ pub mod index;
 pub mod certification;
 pub mod educations;
 pub mod experience;
-pub mod contact_information;
\ No newline at end of file
+pub mod language;
+pub mod award;
+pub mod patent;
+pub mod publication;
+pub mod project;
+pub mod skill;
+pub mod custom_section;
+pub mod recommendation;
+pub mod contact_information;
+pub mod export;
+pub mod vcard;
+pub mod og_image;
\ No newline at end of file
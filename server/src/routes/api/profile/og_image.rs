@@ -0,0 +1,73 @@
+use axum::extract::{Path, State};
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE, ETAG};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::Response;
+
+use crate::response::error_handling::AppError;
+use crate::routes::page::{matches_entity_tag, PROFILE_PAGE_CACHE_CONTROL};
+use crate::services::og_image_service::ProfileCard;
+use crate::AppState;
+
+/// Asynchronously renders the Open Graph preview image of a profile, a 1200×630 PNG card.
+///
+/// Like the profile page, only public profiles have a preview image and the session is ignored. Cards are cached
+/// and rendered again when the profile or its experiences change. Requests with a matching `If-None-Match` header
+/// get an empty `304 Not Modified` response.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `identifier` - The username of the user whose card is to be rendered.
+/// * `headers` - The request headers, read for conditional requests.
+///
+/// # Returns
+///
+/// Returns the PNG image with an entity tag.
+///
+/// # Errors
+///
+/// Returns an `AppError::UserNotFound` if the user does not exist or the profile is not public.
+/// Returns an `AppError` if there is an error during the retrieval process or the rendering of the image.
+///
+pub async fn get_og_image(
+    State(state): State<AppState>,
+    Path(identifier): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &None)
+        .await?;
+
+    let profile = state.profile_service.get_public_profile(user.id).await?;
+    let experiences = state
+        .experience_service
+        .get_public_experiences(user.id, None)
+        .await?;
+    let card = ProfileCard::new(&user.username, &profile, &experiences);
+
+    let og_image_service = state.og_image_service.clone();
+    let (key, image) =
+        tokio::task::spawn_blocking(move || og_image_service.get_og_image(user.id, &card))
+            .await
+            .map_err(|_| AppError::InternalError)??;
+    let entity_tag = format!("\"{}\"", key);
+
+    let response = Response::builder()
+        .header(ETAG, &entity_tag)
+        .header(CACHE_CONTROL, PROFILE_PAGE_CACHE_CONTROL);
+
+    if matches_entity_tag(&headers, &entity_tag) {
+        return Ok(response
+            .status(StatusCode::NOT_MODIFIED)
+            .body(Default::default())
+            .unwrap());
+    }
+
+    Ok(response
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "image/png")
+        .body(image.to_vec().into())
+        .unwrap())
+}
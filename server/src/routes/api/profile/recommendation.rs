@@ -0,0 +1,57 @@
+use axum::extract::{Path, State};
+use axum::Json;
+use tower_sessions::Session;
+
+use crate::response::error_handling::AppError;
+use crate::AppState;
+
+/// Asynchronously retrieves public recommendations for a user.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `identifier` - The username of the user whose recommendations are to be retrieved.
+///
+/// # Returns
+///
+/// Returns a JSON representation of the recommendations associated with the user.
+/// The outcome differs based on whether the current user is the owner of the profile or not.
+/// If the current user is the owner, it retrieves all received recommendations including pending ones.
+/// If the current user is not the owner, it retrieves approved recommendations only.
+///
+/// # Errors
+///
+/// Returns an `AppError` if there is an error during the retrieval process.
+///
+pub async fn get_public_recommendations(
+    State(state): State<AppState>,
+    session: Session,
+    Path(identifier): Path<String>,
+) -> Result<Json<serde_json::Value>, AppError> {
+    let optional_user = state.user_service.check_user_optional(&session).await?;
+    let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &optional_user)
+        .await?;
+
+    let recommendations = match optional_user {
+        Some(logged_in_user) if logged_in_user.id == user.id => {
+            let recommendations = state
+                .recommendation_service
+                .get_authenticated_recommendations(logged_in_user.id)
+                .await?;
+            serde_json::to_value(&recommendations).unwrap()
+        }
+        _ => {
+            let recommendations = state
+                .recommendation_service
+                .get_public_recommendations(user.id, None)
+                .await?;
+            serde_json::to_value(&recommendations).unwrap()
+        }
+    };
+
+    Ok(Json(recommendations))
+}
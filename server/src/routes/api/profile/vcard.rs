@@ -0,0 +1,150 @@
+use axum::extract::{Path, Query, State};
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::StatusCode;
+use axum::response::Response;
+use tower_sessions::Session;
+
+use crate::models::user::UserModel;
+use crate::response::error_handling::AppError;
+use crate::services::qr_service::{self, QrCodeContent, QrCodeFormat, QrCodeQuery};
+use crate::services::vcard_service;
+use crate::AppState;
+
+/// Asynchronously builds the vCard of a profile for a viewer.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `identifier` - The username of the user whose vCard is to be built.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the user and the vCard of the profile, with only the public contact entries.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the profile is not visible to the viewer or there is an error during the retrieval process.
+///
+async fn build_vcard(
+    state: &AppState,
+    session: &Session,
+    identifier: String,
+) -> Result<(UserModel, String), AppError> {
+    let optional_user = state.user_service.check_user_optional(session).await?;
+    let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &optional_user)
+        .await?;
+
+    let profile = state.profile_service.get_public_profile(user.id).await?;
+    let contact_information = state
+        .contact_information_service
+        .get_public_contact_information(user.id, None)
+        .await?;
+    let contact_types = state
+        .contact_type_service
+        .get_contact_types_by_name()
+        .await?;
+
+    let vcard = vcard_service::export_vcard(
+        &user.username,
+        &profile,
+        &contact_information,
+        &contact_types,
+        &state.profile_service.profile_url(&user.username),
+    );
+
+    Ok((user, vcard))
+}
+
+/// Asynchronously exports the profile of a user as a vCard 4.0.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `identifier` - The username of the user whose profile is to be exported.
+///
+/// # Returns
+///
+/// Returns the vCard of the profile as a `.vcf` attachment.
+///
+/// # Errors
+///
+/// Returns an `AppError` if the profile is not visible to the viewer or there is an error during the retrieval process.
+///
+pub async fn get_vcard(
+    State(state): State<AppState>,
+    session: Session,
+    Path(identifier): Path<String>,
+) -> Result<Response, AppError> {
+    let (user, vcard) = build_vcard(&state, &session, identifier).await?;
+
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "text/vcard; charset=utf-8")
+        .header(
+            CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}.vcf\"", user.username),
+        )
+        .body(vcard.into())
+        .unwrap())
+}
+
+/// Asynchronously renders a QR code of a profile, encoding either the link to the profile page or its vCard.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `session` - The session information for the current user.
+/// * `identifier` - The username of the user whose profile is encoded.
+/// * `query` - The image format, `svg` (default) or `png`, and the content, `url` (default) or `vcard`.
+///
+/// # Returns
+///
+/// Returns the QR code as an SVG or PNG image.
+///
+/// # Errors
+///
+/// Returns an `AppError::BadRequest` if the format or content is invalid or the vCard does not fit into a QR code.
+/// Returns an `AppError` if the profile is not visible to the viewer or there is an error during the retrieval process.
+///
+pub async fn get_qr_code(
+    State(state): State<AppState>,
+    session: Session,
+    Path(identifier): Path<String>,
+    Query(query): Query<QrCodeQuery>,
+) -> Result<Response, AppError> {
+    let format = QrCodeFormat::from_str(query.format.as_deref().unwrap_or("svg")).ok_or(
+        AppError::BadRequest {
+            error: Some("Invalid QR code format".to_string()),
+        },
+    )?;
+    let content = QrCodeContent::from_str(query.content.as_deref().unwrap_or("url")).ok_or(
+        AppError::BadRequest {
+            error: Some("Invalid QR code content".to_string()),
+        },
+    )?;
+
+    let (user, vcard) = build_vcard(&state, &session, identifier).await?;
+    let data = match content {
+        QrCodeContent::Url => state.profile_service.profile_url(&user.username),
+        QrCodeContent::Vcard => vcard,
+    };
+
+    let (content_type, image) = match format {
+        QrCodeFormat::Svg => (
+            "image/svg+xml",
+            qr_service::render_qr_svg(&data)?.into_bytes(),
+        ),
+        QrCodeFormat::Png => ("image/png", qr_service::render_qr_png(&data)?),
+    };
+
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, content_type)
+        .body(image.into())
+        .unwrap())
+}
@@ -0,0 +1,87 @@
+use axum::extract::{Path, State};
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE, ETAG, IF_NONE_MATCH};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::Response;
+
+use crate::response::error_handling::AppError;
+use crate::routes::api::profile::export::get_profile_export;
+use crate::services::profile_page_service;
+use crate::AppState;
+
+/// How long shared caches may serve a profile page or preview image without revalidating it.
+pub(crate) const PROFILE_PAGE_CACHE_CONTROL: &str = "public, max-age=300";
+
+/// Checks whether an `If-None-Match` header matches the entity tag of a page.
+pub(crate) fn matches_entity_tag(headers: &HeaderMap, entity_tag: &str) -> bool {
+    headers
+        .get_all(IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|candidate| candidate.trim().trim_start_matches("W/"))
+        .any(|candidate| candidate == "*" || candidate == entity_tag)
+}
+
+/// Asynchronously renders the public profile page of a user as HTML, for link previews and search engines.
+///
+/// Only public profiles are rendered. The session is ignored, so the page is the same for every viewer and can be
+/// kept by shared caches. Requests with a matching `If-None-Match` header get an empty `304 Not Modified` response.
+///
+/// # Arguments
+///
+/// * `state` - The application state containing service instances.
+/// * `identifier` - The username of the user whose profile is to be rendered.
+/// * `headers` - The request headers, read for conditional requests.
+///
+/// # Returns
+///
+/// Returns the HTML document with an entity tag.
+///
+/// # Errors
+///
+/// Returns an `AppError::UserNotFound` if the user does not exist or the profile is not public.
+/// Returns an `AppError` if there is an error during the retrieval process.
+///
+pub async fn get_profile_page(
+    State(state): State<AppState>,
+    Path(identifier): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let user = state.user_service.get_user_by_username(identifier).await?;
+    state
+        .profile_service
+        .check_profile_visible(user.id, &None)
+        .await?;
+
+    let export = get_profile_export(&state, &user, false).await?;
+    let contact_types = state
+        .contact_type_service
+        .get_contact_types_by_name()
+        .await?;
+
+    let html = profile_page_service::render_profile_page(
+        &user.username,
+        &export,
+        &contact_types,
+        &state.profile_service.profile_url(&user.username),
+        state.og_image_service.image_url(&user.username).as_deref(),
+    );
+    let entity_tag = profile_page_service::entity_tag(&html);
+
+    let response = Response::builder()
+        .header(ETAG, &entity_tag)
+        .header(CACHE_CONTROL, PROFILE_PAGE_CACHE_CONTROL);
+
+    if matches_entity_tag(&headers, &entity_tag) {
+        return Ok(response
+            .status(StatusCode::NOT_MODIFIED)
+            .body(Default::default())
+            .unwrap());
+    }
+
+    Ok(response
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "text/html; charset=utf-8")
+        .body(html.into())
+        .unwrap())
+}
@@ -59,7 +59,7 @@ impl AccountService {
         .bind(hash)
         .fetch_one(&self.db_pool)
         .await
-        .map_err(|_| return AppError::InternalError)
+        .map_err(|_| AppError::InternalError)
     }
 
     /// Asynchronously updates the password for a user in the database.
@@ -88,7 +88,7 @@ impl AccountService {
             .bind(user_id)
             .execute(&self.db_pool)
             .await
-            .map_err(|_| return AppError::InternalError)
+            .map_err(|_| AppError::InternalError)
     }
 
     /// Asynchronously updates the username for a user in the database.
@@ -117,11 +117,13 @@ impl AccountService {
             .bind(user_id)
             .execute(&self.db_pool)
             .await
-            .map_err(|_| return AppError::InternalError)
+            .map_err(|_| AppError::InternalError)
     }
 
     /// Asynchronously updates the email address for a user in the database.
     ///
+    /// The new address is unconfirmed until the user verifies it again.
+    ///
     /// # Arguments
     ///
     /// * `user_id` - The ID of the user whose email address is to be updated.
@@ -141,12 +143,12 @@ impl AccountService {
         user_id: i32,
         email: String,
     ) -> Result<IdenoDBResult, AppError> {
-        sqlx::query("UPDATE users SET email = $1 WHERE id = $2")
+        sqlx::query("UPDATE users SET email = $1, email_verified_at = NULL WHERE id = $2")
             .bind(email)
             .bind(user_id)
             .execute(&self.db_pool)
             .await
-            .map_err(|_| return AppError::InternalError)
+            .map_err(|_| AppError::InternalError)
     }
 
     /// Asynchronously checks if an email address exists in the database.
@@ -168,7 +170,7 @@ impl AccountService {
             .bind(email)
             .fetch_optional(&self.db_pool)
             .await
-            .map_err(|_| return AppError::InternalError);
+            .map_err(|_| AppError::InternalError);
 
         match result {
             Ok(Some(_row)) => true,
@@ -196,7 +198,7 @@ impl AccountService {
             .bind(username)
             .fetch_optional(&self.db_pool)
             .await
-            .map_err(|_| return AppError::InternalError);
+            .map_err(|_| AppError::InternalError);
 
         match result {
             Ok(Some(_row)) => true,
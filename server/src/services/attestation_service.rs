@@ -0,0 +1,362 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::models::experience::{AttestedExperienceModel, OrganizationExperienceModel};
+use crate::response::error_handling::AppError;
+use crate::IdenoPool;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// The placeholder secret shipped in `.env.example`, which must never be used to sign attestations.
+const EXAMPLE_SECRET: &str = "change-me";
+
+/// The secret attestations are signed with.
+///
+/// An attestation signs the verified fields of an experience: its owner, organization, company, title,
+/// dates and current flag. It only counts as long as the signature matches the experience as it is stored.
+///
+/// Without a secret nothing can be signed, and no attestation counts as valid.
+///
+#[derive(Clone)]
+pub struct AttestationKey {
+    secret: Option<Vec<u8>>,
+}
+
+impl AttestationKey {
+    pub fn new(secret: &str) -> Self {
+        AttestationKey {
+            secret: Some(secret.as_bytes().to_vec()),
+        }
+    }
+
+    /// Creates the key from the configured secret, if it is usable.
+    ///
+    /// A missing or blank secret, or the placeholder from `.env.example`, disables attestations, as anyone
+    /// knowing the secret could forge them.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret` - The configured secret, if any.
+    ///
+    /// # Returns
+    ///
+    /// Returns an `AttestationKey`, which is disabled if the secret is not usable.
+    ///
+    pub fn from_secret(secret: Option<&str>) -> Self {
+        match secret.map(str::trim) {
+            Some(secret) if !secret.is_empty() && secret != EXAMPLE_SECRET => {
+                AttestationKey::new(secret)
+            }
+            _ => AttestationKey { secret: None },
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.secret.is_some()
+    }
+
+    /// Signs the verified fields of an experience.
+    ///
+    /// # Returns
+    ///
+    /// Returns the hex encoded HMAC-SHA256 signature, or `None` if the key is disabled.
+    ///
+    pub fn sign(&self, experience: &AttestedExperienceModel) -> Option<String> {
+        let mut mac = self.mac()?;
+        mac.update(attestation_message(experience).as_bytes());
+
+        Some(hex::encode(mac.finalize().into_bytes()))
+    }
+
+    /// Checks that the signature of an attestation matches the current verified fields of an experience.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the experience carries a signature and the signature is valid.
+    ///
+    pub fn verify(&self, experience: &AttestedExperienceModel) -> bool {
+        let Some(signature) = experience
+            .signature
+            .as_ref()
+            .and_then(|signature| hex::decode(signature).ok())
+        else {
+            return false;
+        };
+
+        let Some(mut mac) = self.mac() else {
+            return false;
+        };
+        mac.update(attestation_message(experience).as_bytes());
+
+        mac.verify_slice(&signature).is_ok()
+    }
+
+    fn mac(&self) -> Option<HmacSha256> {
+        let secret = self.secret.as_ref()?;
+
+        Some(HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any length"))
+    }
+}
+
+/// Encodes the verified fields of an experience unambiguously, as the message an attestation signs.
+fn attestation_message(experience: &AttestedExperienceModel) -> String {
+    serde_json::json!([
+        experience.id,
+        experience.user_id,
+        experience.organization_id,
+        experience.company,
+        experience.title,
+        experience.start_date,
+        experience.end_date,
+        experience.is_current,
+    ])
+    .to_string()
+}
+
+#[derive(Clone)]
+pub struct AttestationService {
+    db_pool: IdenoPool,
+    key: AttestationKey,
+}
+
+impl AttestationService {
+    pub fn new(db_pool: IdenoPool, key: AttestationKey) -> Self {
+        AttestationService { db_pool, key }
+    }
+
+    /// Asynchronously retrieves the experiences linked to an organization, for review by the people verifying for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` - The ID of the organization.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `OrganizationExperienceModel` instances, unverified experiences first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_organization_experiences(
+        &self,
+        organization_id: i32,
+    ) -> Result<Vec<OrganizationExperienceModel>, AppError> {
+        let mut experiences = sqlx::query_as::<_, OrganizationExperienceModel>(
+            "SELECT
+                experiences.id,
+                users.username,
+                experiences.company,
+                experiences.title,
+                experiences.start_date,
+                experiences.end_date,
+                experiences.is_current,
+                experiences.user_id,
+                experiences.organization_id,
+                experience_attestations.signature
+              FROM experiences
+              JOIN users ON users.id = experiences.user_id
+              LEFT JOIN experience_attestations ON experience_attestations.experience_id = experiences.id
+              WHERE experiences.organization_id = $1
+              ORDER BY experiences.created_at DESC",
+        )
+        .bind(organization_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        for experience in experiences.iter_mut() {
+            experience.verified = self
+                .key
+                .verify(&AttestedExperienceModel::from(&*experience));
+        }
+        experiences.sort_by_key(|experience| experience.verified);
+
+        Ok(experiences)
+    }
+
+    /// Asynchronously retrieves the verified fields of an experience linked to an organization.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` - The ID of the organization the experience must be linked to.
+    /// * `experience_id` - The ID of the experience.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `AttestedExperienceModel` of the experience.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the experience does not exist or is not linked to the organization.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_organization_experience(
+        &self,
+        organization_id: i32,
+        experience_id: i32,
+    ) -> Result<AttestedExperienceModel, AppError> {
+        sqlx::query_as::<_, AttestedExperienceModel>(
+            "SELECT
+                experiences.id,
+                experiences.user_id,
+                experiences.organization_id,
+                experiences.company,
+                experiences.title,
+                experiences.start_date,
+                experiences.end_date,
+                experiences.is_current,
+                experience_attestations.signature
+              FROM experiences
+              LEFT JOIN experience_attestations ON experience_attestations.experience_id = experiences.id
+              WHERE experiences.id = $1 AND experiences.organization_id = $2",
+        )
+        .bind(experience_id)
+        .bind(organization_id)
+        .fetch_optional(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?
+        .ok_or(AppError::NotFound {
+            error: "Experience not found".to_string(),
+        })
+    }
+
+    /// Asynchronously signs and stores an attestation for an experience, replacing any previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `experience` - The verified fields of the experience, as returned by `get_organization_experience`.
+    /// * `attester_id` - The ID of the user verifying the experience.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the attestation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotAllowed` if attestations are disabled because no secret is configured.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn attest_experience(
+        &self,
+        experience: &AttestedExperienceModel,
+        attester_id: i32,
+    ) -> Result<i64, AppError> {
+        let signature = self.key.sign(experience).ok_or(AppError::NotAllowed {
+            error: "Attestations are disabled on this server".to_string(),
+        })?;
+
+        sqlx::query_as::<_, (i64,)>(
+            "INSERT INTO experience_attestations (experience_id, organization_id, attester_id, signature)
+              VALUES ($1, $2, $3, $4)
+              ON CONFLICT (experience_id) DO UPDATE SET
+                organization_id = excluded.organization_id,
+                attester_id = excluded.attester_id,
+                signature = excluded.signature,
+                created_at = CURRENT_TIMESTAMP
+              RETURNING id",
+        )
+        .bind(experience.id)
+        .bind(experience.organization_id)
+        .bind(attester_id)
+        .bind(signature)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|id| id.0)
+    }
+
+    /// Asynchronously revokes the attestation an organization gave for an experience.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` - The ID of the organization that gave the attestation.
+    /// * `experience_id` - The ID of the attested experience.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the attestation was revoked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the organization did not attest the experience.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn revoke_attestation(
+        &self,
+        organization_id: i32,
+        experience_id: i32,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "DELETE FROM experience_attestations WHERE experience_id = $1 AND organization_id = $2",
+        )
+        .bind(experience_id)
+        .bind(organization_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound {
+                error: "Attestation not found".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AttestationKey;
+    use crate::models::date::ProfileDate;
+    use crate::models::experience::AttestedExperienceModel;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let key = AttestationKey::new("secret");
+        let mut experience = AttestedExperienceModel {
+            id: 1,
+            user_id: 2,
+            organization_id: Some(3),
+            company: "Acme".to_string(),
+            title: "Engineer".to_string(),
+            start_date: ProfileDate::parse("2020-01"),
+            end_date: None,
+            is_current: true,
+            signature: None,
+        };
+        assert!(!key.verify(&experience));
+
+        experience.signature = key.sign(&experience);
+        assert!(key.verify(&experience));
+        assert!(!AttestationKey::new("other secret").verify(&experience));
+
+        // Changing any verified field voids the signature
+        let mut edited = experience.clone();
+        edited.title = "Senior Engineer".to_string();
+        assert!(!key.verify(&edited));
+
+        let mut edited = experience.clone();
+        edited.end_date = ProfileDate::parse("2023");
+        edited.is_current = false;
+        assert!(!key.verify(&edited));
+
+        let mut edited = experience.clone();
+        edited.organization_id = None;
+        assert!(!key.verify(&edited));
+
+        let mut edited = experience.clone();
+        edited.signature = Some("not hex".to_string());
+        assert!(!key.verify(&edited));
+
+        // Without a usable secret nothing is signed and nothing verifies
+        for secret in [None, Some(""), Some("  "), Some("change-me")] {
+            let disabled = AttestationKey::from_secret(secret);
+            assert!(!disabled.is_enabled());
+            assert_eq!(disabled.sign(&experience), None);
+            assert!(!disabled.verify(&experience));
+        }
+        assert!(AttestationKey::from_secret(Some("secret")).verify(&experience));
+    }
+}
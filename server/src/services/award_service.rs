@@ -0,0 +1,365 @@
+use crate::models::award::{AuthAwardModel, AwardModel, PublicAwardModel};
+use crate::models::date::{deserialize_optional_date, ProfileDate};
+use crate::response::error_handling::AppError;
+use crate::{IdenoDBResult, IdenoPool};
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AddAwardPayload {
+    pub title: String,
+    pub issuer: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub award_date: Option<ProfileDate>,
+    pub description: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdateAwardPayload {
+    pub title: String,
+    pub issuer: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub award_date: Option<ProfileDate>,
+    pub description: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct AwardService {
+    db_pool: IdenoPool,
+}
+
+impl AwardService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        AwardService { db_pool }
+    }
+
+    /// Asynchronously retrieves authenticated awards associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the authenticated awards are being retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `AuthAwardModel` representing authenticated awards associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_authenticated_awards(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<AuthAwardModel>, AppError> {
+        sqlx::query_as::<_, AuthAwardModel>(
+            "SELECT
+                id,
+                title,
+                issuer,
+                award_date,
+                description
+              FROM awards
+              WHERE user_id = ?
+              ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously retrieves public awards associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the public awards are being retrieved.
+    /// * `limit` - An optional limit on the number of awards to retrieve. If not provided, all awards are fetched.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `PublicAwardModel` representing public awards associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_public_awards(
+        &self,
+        user_id: i32,
+        limit: Option<i32>,
+    ) -> Result<Vec<PublicAwardModel>, AppError> {
+        let limit = limit.unwrap_or(-1);
+        sqlx::query_as::<_, PublicAwardModel>(
+            "SELECT
+                title,
+                issuer,
+                award_date,
+                description
+              FROM awards
+              WHERE user_id = $1
+              ORDER BY created_at DESC
+              LIMIT $2",
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously checks if a user owns a specific award.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose ownership of the award is being checked.
+    /// * `award_id` - The ID of the award being checked for ownership.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an optional boolean value indicating whether the user owns the award.
+    /// - `Some(true)` if the user owns the award.
+    /// - `Some(false)` if the user does not own the award.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the award with the provided ID could not be checked.
+    ///
+    pub async fn user_owns_award(
+        &self,
+        user_id: i32,
+        award_id: i32,
+    ) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM awards WHERE id = $1 AND user_id = $2",
+        )
+        .bind(award_id)
+        .bind(user_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map_or_else(|_| None, |count| Some(count.0 > 0));
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Award not found".to_string(),
+            }),
+        }
+    }
+
+    /// Asynchronously deletes a award entry associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the award entry to be deleted.
+    /// * `award_id` - The ID of the award entry to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the delete operation. If the award entry is successfully deleted,
+    /// it returns `Ok(IdenoDBResult)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn delete_award(
+        &self,
+        user_id: i32,
+        award_id: i32,
+    ) -> Result<IdenoDBResult, AppError> {
+        sqlx::query("DELETE FROM awards WHERE id = $1 AND user_id = $2")
+            .bind(award_id)
+            .bind(user_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously updates a award entry associated with a user in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the award entry to be updated.
+    /// * `award_id` - The ID of the award entry to be updated.
+    /// * `payload` - An `UpdateAwardPayload` containing the updated award details.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the update operation. If the award entry is successfully updated,
+    /// it returns `Ok(IdenoDBResult)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn update_award(
+        &self,
+        user_id: i32,
+        award_id: i32,
+        payload: UpdateAwardPayload,
+    ) -> Result<IdenoDBResult, AppError> {
+        sqlx::query("UPDATE awards SET title = $1, issuer = $2, award_date = $3, description = $4 WHERE id = $5 AND user_id = $6")
+            .bind(payload.title)
+            .bind(payload.issuer)
+            .bind(payload.award_date)
+            .bind(payload.description)
+            .bind(award_id)
+            .bind(user_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously creates a new award entry associated with a user in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the award is being created.
+    /// * `payload` - An `AddAwardPayload` containing the details of the award to be created.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created award entry if successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_award(
+        &self,
+        user_id: i32,
+        payload: AddAwardPayload,
+    ) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>("INSERT INTO awards (user_id, title, issuer, award_date, description) VALUES ($1, $2, $3, $4, $5) RETURNING id")
+            .bind(user_id)
+            .bind(payload.title)
+            .bind(payload.issuer)
+            .bind(payload.award_date)
+            .bind(payload.description)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|id| id.0)
+    }
+
+    /// Validates the award title.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the award.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the award is valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the title is empty.
+    ///
+    pub fn validate_award(&self, title: &str) -> Result<bool, AppError> {
+        if title.trim().is_empty() {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid award title".to_string()),
+            });
+        }
+
+        Ok(true)
+    }
+
+    /// Asynchronously retrieves the count of award entries associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the count of award entries is being retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the count of award entries associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_award_count(&self, user_id: i32) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM awards WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|count| count.0)
+    }
+
+    /// Asynchronously retrieves all award entries associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the award entries are being retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `AwardModel` representing all award entries associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_all_awards(&self, user_id: i32) -> Result<Vec<AwardModel>, AppError> {
+        sqlx::query_as::<_, AwardModel>("SELECT * FROM awards WHERE user_id = $1 ORDER BY created_at DESC")
+            .bind(user_id)
+            .fetch_all(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously deletes a award entry from the database for administrative purposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `award_id` - The ID of the award entry to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the delete operation. If the operation is successful, it returns `Ok(())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn admin_delete_award(&self, award_id: i32) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM awards WHERE id = $1")
+            .bind(award_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+
+    /// Asynchronously checks if a award entry exists in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `award_id` - The ID of the award entry to be checked.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an optional boolean value.
+    /// If the award entry exists, it returns `Some(true)`, otherwise `Some(false)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` with an error message if the award entry could not be checked.
+    ///
+    pub async fn award_exists(&self, award_id: i32) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM awards WHERE id = $1")
+            .bind(award_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map_or_else(|_| None, |count| Some(count.0 > 0));
+
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Award not found".to_string(),
+            }),
+        }
+    }
+}
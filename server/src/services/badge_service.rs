@@ -0,0 +1,909 @@
+use std::io::Read;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use flate2::read::ZlibDecoder;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::models::badge::{BadgeFormat, BadgeVerification, ImportedBadgeModel};
+use crate::models::date::ProfileDate;
+use crate::response::error_handling::AppError;
+use crate::services::project_service::is_http_url;
+
+const PNG_SIGNATURE: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+/// The PNG text chunk keywords badges are baked under, for Open Badges 2.0 and 3.0.
+const PNG_BADGE_KEYWORDS: [&str; 2] = ["openbadges", "openbadgecredential"];
+
+/// The SVG elements badges are baked into, for Open Badges 2.0 and 3.0.
+const SVG_BADGE_ELEMENTS: [&str; 2] = ["openbadges:assertion", "openbadges:credential"];
+
+/// The maximum size of a decompressed assertion baked into a PNG.
+const MAX_ASSERTION_BYTES: u64 = 1024 * 1024;
+
+/// Reads a certification from an Open Badges file.
+///
+/// The file may be an assertion or credential in JSON, a compact JWS / JWT, or a PNG or SVG image with the
+/// assertion baked in. The format is detected from the content.
+///
+/// # Arguments
+///
+/// * `content` - The uploaded file.
+/// * `recipient_emails` - The confirmed email addresses of the user importing the badge.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the `ImportedBadgeModel` read from the badge.
+///
+/// # Errors
+///
+/// Returns an `AppError::BadRequest` if no assertion can be found, the assertion is malformed or its signature is invalid.
+///
+pub fn import_badge(
+    content: &[u8],
+    recipient_emails: &[String],
+) -> Result<ImportedBadgeModel, AppError> {
+    let assertion = extract_assertion(content)?;
+
+    read_assertion(&assertion, recipient_emails)
+}
+
+/// Reads a certification from a raw Open Badges assertion and checks its signature.
+///
+/// Signatures are checked for Ed25519 keys that can be resolved without contacting the issuer: keys embedded
+/// as a JWK in a JWS header and `did:key` identifiers, for compact JWS / JWT and for `eddsa-jcs-2022` data
+/// integrity proofs. A key in the document alone proves nothing, as anyone can mint one, so a badge is only
+/// verified when the issuer is identified by the `did:key` of the signing key and the badge was issued to one
+/// of the email addresses of the user. Other badges are kept as unverified.
+///
+/// # Arguments
+///
+/// * `assertion` - The raw assertion, either JSON or a compact JWS / JWT.
+/// * `recipient_emails` - The confirmed email addresses of the user the badge should have been issued to.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the `ImportedBadgeModel` read from the assertion.
+///
+/// # Errors
+///
+/// Returns an `AppError::BadRequest` if the assertion is malformed or its signature is invalid.
+///
+pub fn read_assertion(
+    assertion: &str,
+    recipient_emails: &[String],
+) -> Result<ImportedBadgeModel, AppError> {
+    let (document, signing_key) = decode_assertion(assertion)?;
+    let types = type_list(&document["type"]);
+
+    let (format, name, issuer, recipient, issue_date, expiration_date) =
+        if types.contains(&"VerifiableCredential") {
+            if !types
+                .iter()
+                .any(|kind| *kind == "OpenBadgeCredential" || *kind == "AchievementCredential")
+            {
+                return Err(bad_request("Credential is not an Open Badges credential"));
+            }
+
+            (
+                BadgeFormat::OpenBadges3,
+                &document["credentialSubject"]["achievement"]["name"],
+                &document["issuer"],
+                &document["credentialSubject"],
+                first_present(&document, &["validFrom", "issuanceDate"]),
+                first_present(&document, &["validUntil", "expirationDate"]),
+            )
+        } else if types.contains(&"Assertion") {
+            if !document["badge"].is_object() {
+                return Err(bad_request(
+                    "The badge class must be embedded in the assertion",
+                ));
+            }
+
+            if !document["recipient"].is_object() {
+                return Err(bad_request("Badge assertion has no recipient"));
+            }
+
+            (
+                BadgeFormat::OpenBadges2,
+                &document["badge"]["name"],
+                &document["badge"]["issuer"],
+                &document["recipient"],
+                &document["issuedOn"],
+                &document["expires"],
+            )
+        } else {
+            return Err(bad_request(
+                "Badge must be an Open Badges 2.0 assertion or an Open Badges 3.0 credential",
+            ));
+        };
+
+    let name = name
+        .as_str()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| bad_request("Badge has no name"))?;
+    let organization =
+        issuer_name(issuer).ok_or_else(|| bad_request("Badge issuer has no name"))?;
+    let credential_id = document["id"].as_str().map(str::to_string);
+    let credential_url = credential_id.clone().filter(|id| is_http_url(id));
+
+    let is_issued_by_signer = signing_key.is_some() && signing_key == issuer_key(issuer);
+    let verification = if is_issued_by_signer && recipient_matches(recipient, recipient_emails) {
+        BadgeVerification::Verified
+    } else {
+        BadgeVerification::Unverified
+    };
+
+    Ok(ImportedBadgeModel {
+        format,
+        verification,
+        name: name.to_string(),
+        organization,
+        issue_date: read_date(issue_date),
+        expiration_date: read_date(expiration_date),
+        credential_id,
+        credential_url,
+        assertion: assertion.to_string(),
+    })
+}
+
+/// Finds the raw assertion in a JSON, JWS, PNG or SVG badge file.
+fn extract_assertion(content: &[u8]) -> Result<String, AppError> {
+    let assertion = if content.starts_with(&PNG_SIGNATURE) {
+        extract_png_assertion(content)?
+    } else {
+        let text = std::str::from_utf8(content)
+            .map_err(|_| bad_request("Badge must be JSON, a JWS, a PNG or an SVG"))?
+            .trim_start_matches('\u{feff}')
+            .trim();
+
+        if text.starts_with('<') {
+            extract_svg_assertion(text)?
+        } else {
+            text.to_string()
+        }
+    };
+
+    if assertion.starts_with("http://") || assertion.starts_with("https://") {
+        return Err(bad_request(
+            "The badge only links to a hosted assertion, upload the assertion JSON instead",
+        ));
+    }
+
+    Ok(assertion)
+}
+
+/// Reads the assertion from the `iTXt` or `tEXt` chunk of a baked PNG.
+fn extract_png_assertion(content: &[u8]) -> Result<String, AppError> {
+    let mut offset = PNG_SIGNATURE.len();
+
+    while offset + 12 <= content.len() {
+        let length = u32::from_be_bytes(content[offset..offset + 4].try_into().unwrap()) as usize;
+        let chunk_type = &content[offset + 4..offset + 8];
+        let data_start = offset + 8;
+        let data_end = data_start
+            .checked_add(length)
+            .filter(|end| end + 4 <= content.len())
+            .ok_or_else(|| bad_request("PNG is truncated"))?;
+        let data = &content[data_start..data_end];
+
+        let assertion = match chunk_type {
+            b"iTXt" => read_itxt_chunk(data)?,
+            b"tEXt" => read_text_chunk(data),
+            b"IEND" => break,
+            _ => None,
+        };
+
+        if let Some(assertion) = assertion {
+            return Ok(assertion.trim().to_string());
+        }
+
+        offset = data_end + 4;
+    }
+
+    Err(bad_request("PNG does not contain an Open Badges assertion"))
+}
+
+/// Reads an international text chunk: keyword, compression flag and method, language, translated keyword and text.
+fn read_itxt_chunk(data: &[u8]) -> Result<Option<String>, AppError> {
+    let mut parts = data.splitn(2, |byte| *byte == 0);
+    let keyword = parts.next().unwrap_or_default();
+
+    if !PNG_BADGE_KEYWORDS.contains(&String::from_utf8_lossy(keyword).as_ref()) {
+        return Ok(None);
+    }
+
+    let rest = parts
+        .next()
+        .filter(|rest| rest.len() >= 2)
+        .ok_or_else(|| bad_request("PNG badge chunk is malformed"))?;
+    let is_compressed = rest[0] == 1;
+    let text = rest[2..]
+        .splitn(3, |byte| *byte == 0)
+        .nth(2)
+        .ok_or_else(|| bad_request("PNG badge chunk is malformed"))?;
+
+    let text = if is_compressed {
+        let mut decompressed = Vec::new();
+        ZlibDecoder::new(text)
+            .take(MAX_ASSERTION_BYTES)
+            .read_to_end(&mut decompressed)
+            .map_err(|_| bad_request("PNG badge chunk is malformed"))?;
+        decompressed
+    } else {
+        text.to_vec()
+    };
+
+    String::from_utf8(text)
+        .map(Some)
+        .map_err(|_| bad_request("PNG badge chunk is malformed"))
+}
+
+/// Reads a Latin-1 text chunk: keyword and text.
+fn read_text_chunk(data: &[u8]) -> Option<String> {
+    let mut parts = data.splitn(2, |byte| *byte == 0);
+    let keyword = parts.next()?;
+
+    if !PNG_BADGE_KEYWORDS.contains(&String::from_utf8_lossy(keyword).as_ref()) {
+        return None;
+    }
+
+    Some(parts.next()?.iter().map(|byte| *byte as char).collect())
+}
+
+/// Reads the assertion from the content or the `verify` attribute of the badge element of a baked SVG.
+fn extract_svg_assertion(svg: &str) -> Result<String, AppError> {
+    for element in SVG_BADGE_ELEMENTS {
+        let Some(start) = svg.find(&format!("<{}", element)) else {
+            continue;
+        };
+        let rest = &svg[start..];
+        let tag_end = rest
+            .find('>')
+            .ok_or_else(|| bad_request("SVG badge element is malformed"))?;
+        let tag = &rest[..tag_end];
+
+        if !tag.ends_with('/') {
+            if let Some(content_end) = rest.find(&format!("</{}>", element)) {
+                let content = rest[tag_end + 1..content_end].trim();
+                let content = match content
+                    .strip_prefix("<![CDATA[")
+                    .and_then(|content| content.strip_suffix("]]>"))
+                {
+                    Some(data) => data.trim().to_string(),
+                    None => unescape_xml(content),
+                };
+
+                if !content.is_empty() {
+                    return Ok(content);
+                }
+            }
+        }
+
+        if let Some(verify) = xml_attribute(tag, "verify") {
+            return Ok(unescape_xml(verify));
+        }
+    }
+
+    Err(bad_request("SVG does not contain an Open Badges assertion"))
+}
+
+fn xml_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    ['"', '\''].into_iter().find_map(|quote| {
+        let start = tag.find(&format!(" {}={}", name, quote))? + name.len() + 3;
+        let length = tag[start..].find(quote)?;
+
+        Some(&tag[start..start + length])
+    })
+}
+
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Parses an assertion as JSON or as a compact JWS / JWT and checks its signature.
+///
+/// Returns the document together with the key it is signed with, if the key could be resolved.
+///
+fn decode_assertion(assertion: &str) -> Result<(Value, Option<VerifyingKey>), AppError> {
+    if assertion.starts_with('{') {
+        let document: Value = serde_json::from_str(assertion)
+            .map_err(|_| bad_request("Badge assertion is not valid JSON"))?;
+        let signing_key = verify_data_integrity_proof(&document)?;
+
+        return Ok((document, signing_key));
+    }
+
+    let segments = assertion.split('.').collect::<Vec<&str>>();
+    if segments.len() != 3 {
+        return Err(bad_request("Badge assertion must be JSON or a compact JWS"));
+    }
+
+    let header = decode_json_segment(segments[0])?;
+    let payload = decode_json_segment(segments[1])?;
+    let signing_key = verify_jws(&header, &segments)?;
+
+    // JWTs for version 1.1 of the verifiable credentials data model wrap the credential in a `vc` claim
+    let document = match payload.get("vc") {
+        Some(credential) if credential.is_object() => credential.clone(),
+        _ => payload,
+    };
+
+    Ok((document, signing_key))
+}
+
+fn decode_json_segment(segment: &str) -> Result<Value, AppError> {
+    URL_SAFE_NO_PAD
+        .decode(segment)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .ok_or_else(|| bad_request("Badge JWS is malformed"))
+}
+
+fn verify_jws(header: &Value, segments: &[&str]) -> Result<Option<VerifyingKey>, AppError> {
+    if header["alg"] != "EdDSA" {
+        return Ok(None);
+    }
+
+    let key = header
+        .get("jwk")
+        .and_then(ed25519_key_from_jwk)
+        .or_else(|| header["kid"].as_str().and_then(ed25519_key_from_did_key));
+    let Some(key) = key else {
+        return Ok(None);
+    };
+
+    let signature = URL_SAFE_NO_PAD
+        .decode(segments[2])
+        .ok()
+        .and_then(|bytes| Signature::from_slice(&bytes).ok())
+        .ok_or_else(invalid_signature)?;
+
+    key.verify(
+        format!("{}.{}", segments[0], segments[1]).as_bytes(),
+        &signature,
+    )
+    .map_err(|_| invalid_signature())?;
+
+    Ok(Some(key))
+}
+
+fn verify_data_integrity_proof(document: &Value) -> Result<Option<VerifyingKey>, AppError> {
+    let proofs = match &document["proof"] {
+        Value::Array(proofs) => proofs.iter().collect::<Vec<&Value>>(),
+        Value::Object(_) => vec![&document["proof"]],
+        _ => return Ok(None),
+    };
+
+    let Some(proof) = proofs.into_iter().find(|proof| {
+        proof["type"] == "DataIntegrityProof" && proof["cryptosuite"] == "eddsa-jcs-2022"
+    }) else {
+        return Ok(None);
+    };
+
+    let Some(key) = proof["verificationMethod"]
+        .as_str()
+        .and_then(ed25519_key_from_did_key)
+    else {
+        return Ok(None);
+    };
+
+    let signature = proof["proofValue"]
+        .as_str()
+        .and_then(|value| value.strip_prefix('z'))
+        .and_then(|value| bs58::decode(value).into_vec().ok())
+        .and_then(|bytes| Signature::from_slice(&bytes).ok())
+        .ok_or_else(invalid_signature)?;
+
+    key.verify(&jcs_signing_input(document, proof), &signature)
+        .map_err(|_| invalid_signature())?;
+
+    Ok(Some(key))
+}
+
+/// Builds the data an `eddsa-jcs-2022` proof signs: the hash of the canonical proof options followed by the
+/// hash of the canonical document without its proof.
+///
+/// `serde_json` keeps object keys sorted, so serializing a value yields its JSON canonicalization for the
+/// strings, integers and booleans credentials consist of.
+///
+fn jcs_signing_input(document: &Value, proof: &Value) -> Vec<u8> {
+    let mut unsecured_document = document.clone();
+    if let Some(unsecured_document) = unsecured_document.as_object_mut() {
+        unsecured_document.remove("proof");
+    }
+
+    let mut proof_options = proof.clone();
+    if let Some(proof_options) = proof_options.as_object_mut() {
+        proof_options.remove("proofValue");
+        if let Some(context) = document.get("@context") {
+            proof_options.insert("@context".to_string(), context.clone());
+        }
+    }
+
+    let mut input = Sha256::digest(proof_options.to_string().as_bytes()).to_vec();
+    input.extend(Sha256::digest(unsecured_document.to_string().as_bytes()));
+    input
+}
+
+fn ed25519_key_from_jwk(jwk: &Value) -> Option<VerifyingKey> {
+    if jwk["kty"] != "OKP" || jwk["crv"] != "Ed25519" {
+        return None;
+    }
+
+    let bytes = URL_SAFE_NO_PAD.decode(jwk["x"].as_str()?).ok()?;
+
+    VerifyingKey::from_bytes(&bytes.try_into().ok()?).ok()
+}
+
+/// Decodes the Ed25519 public key of a `did:key` identifier, with or without a fragment.
+fn ed25519_key_from_did_key(did: &str) -> Option<VerifyingKey> {
+    let encoded = did
+        .strip_prefix("did:key:")?
+        .split('#')
+        .next()?
+        .strip_prefix('z')?;
+    let bytes = bs58::decode(encoded).into_vec().ok()?;
+    let key = bytes.strip_prefix(&[0xed, 0x01])?;
+
+    VerifyingKey::from_bytes(&key.try_into().ok()?).ok()
+}
+
+/// Resolves the key of an issuer identified by a `did:key`.
+fn issuer_key(issuer: &Value) -> Option<VerifyingKey> {
+    let id = issuer.as_str().or_else(|| issuer["id"].as_str())?;
+
+    ed25519_key_from_did_key(id)
+}
+
+/// Checks whether a badge was issued to one of the given email addresses.
+///
+/// The recipient is the `recipient` identity of an Open Badges 2.0 assertion, or the `identifier` identities
+/// and the `mailto:` ID of the subject of an Open Badges 3.0 credential. Hashed identities are compared as
+/// `sha256$` followed by the hash of the address and the salt.
+///
+fn recipient_matches(recipient: &Value, emails: &[String]) -> bool {
+    let identities = match &recipient["identifier"] {
+        Value::Array(identities) => identities.iter().collect::<Vec<&Value>>(),
+        Value::Object(_) => vec![&recipient["identifier"]],
+        _ => vec![recipient],
+    };
+
+    let matches_identity = |identity: &Value| {
+        let kind = first_present(identity, &["identityType", "type"]);
+        if kind != "email" && kind != "emailAddress" {
+            return false;
+        }
+
+        let Some(value) = first_present(identity, &["identity", "identityHash"]).as_str() else {
+            return false;
+        };
+        let salt = identity["salt"].as_str().unwrap_or_default();
+
+        emails.iter().any(|email| {
+            if identity["hashed"].as_bool().unwrap_or(false) {
+                [email.clone(), email.to_lowercase()].iter().any(|email| {
+                    let hash = Sha256::digest(format!("{}{}", email, salt).as_bytes());
+                    value.eq_ignore_ascii_case(&format!("sha256${:x}", hash))
+                })
+            } else {
+                value.trim().eq_ignore_ascii_case(email.trim())
+            }
+        })
+    };
+
+    let subject_email = recipient["id"]
+        .as_str()
+        .and_then(|id| id.strip_prefix("mailto:"));
+
+    identities.into_iter().any(matches_identity)
+        || subject_email.is_some_and(|subject_email| {
+            emails
+                .iter()
+                .any(|email| subject_email.eq_ignore_ascii_case(email.trim()))
+        })
+}
+
+fn type_list(value: &Value) -> Vec<&str> {
+    match value {
+        Value::String(kind) => vec![kind.as_str()],
+        Value::Array(kinds) => kinds.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn first_present<'a>(document: &'a Value, keys: &[&str]) -> &'a Value {
+    keys.iter()
+        .map(|key| &document[*key])
+        .find(|value| !value.is_null())
+        .unwrap_or(&Value::Null)
+}
+
+/// Reads the name of an issuer, falling back to the host of its ID.
+fn issuer_name(issuer: &Value) -> Option<String> {
+    if let Some(name) = issuer["name"].as_str().map(str::trim) {
+        if !name.is_empty() {
+            return Some(name.to_string());
+        }
+    }
+
+    let id = issuer.as_str().or_else(|| issuer["id"].as_str())?;
+    let host = id
+        .strip_prefix("https://")
+        .or_else(|| id.strip_prefix("http://"))?
+        .split('/')
+        .next()?;
+
+    (!host.is_empty()).then(|| host.to_string())
+}
+
+/// Reads the date part of an ISO 8601 date or timestamp.
+fn read_date(value: &Value) -> Option<ProfileDate> {
+    let value = value.as_str()?;
+
+    ProfileDate::parse(value).or_else(|| ProfileDate::parse(value.get(..10)?))
+}
+
+fn bad_request(error: &str) -> AppError {
+    AppError::BadRequest {
+        error: Some(error.to_string()),
+    }
+}
+
+fn invalid_signature() -> AppError {
+    bad_request("Badge signature is invalid")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+    use ed25519_dalek::{Signer, SigningKey};
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use serde_json::{json, Value};
+
+    use sha2::{Digest, Sha256};
+
+    use super::{
+        extract_assertion, import_badge, jcs_signing_input, read_assertion, recipient_matches,
+    };
+    use crate::models::badge::{BadgeFormat, BadgeVerification};
+    use crate::services::user_service::UserService;
+    use crate::test_utils::{create_test_user, test_pool};
+
+    fn emails() -> Vec<String> {
+        vec!["alice@example.org".to_string()]
+    }
+
+    fn open_badges_2_assertion() -> Value {
+        json!({
+            "@context": "https://w3id.org/openbadges/v2",
+            "type": "Assertion",
+            "id": "https://badges.example.org/assertions/42",
+            "recipient": { "type": "email", "hashed": false, "identity": "alice@example.org" },
+            "issuedOn": "2023-05-04T10:00:00Z",
+            "expires": "2026-05-04T10:00:00Z",
+            "verification": { "type": "hosted" },
+            "badge": {
+                "type": "BadgeClass",
+                "name": "Cloud Practitioner",
+                "issuer": { "type": "Profile", "name": "Example Academy" }
+            }
+        })
+    }
+
+    fn did_key(key: &SigningKey) -> String {
+        let mut bytes = vec![0xed, 0x01];
+        bytes.extend(key.verifying_key().to_bytes());
+        format!("did:key:z{}", bs58::encode(bytes).into_string())
+    }
+
+    fn sign_credential(credential: &Value, key: &SigningKey) -> Value {
+        let proof = json!({
+            "type": "DataIntegrityProof",
+            "cryptosuite": "eddsa-jcs-2022",
+            "created": "2024-01-15T00:00:00Z",
+            "verificationMethod": format!("{}#key-1", did_key(key)),
+            "proofPurpose": "assertionMethod"
+        });
+        let signature = key.sign(&jcs_signing_input(credential, &proof));
+        let mut signed_proof = proof;
+        signed_proof["proofValue"] = json!(format!(
+            "z{}",
+            bs58::encode(signature.to_bytes()).into_string()
+        ));
+
+        let mut signed = credential.clone();
+        signed["proof"] = signed_proof;
+        signed
+    }
+
+    fn png_with_text_chunk(chunk_type: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut png = vec![137, 80, 78, 71, 13, 10, 26, 10];
+        for (kind, data) in [
+            (b"IHDR".as_slice(), [0u8; 13].as_slice()),
+            (chunk_type, data),
+            (b"IEND", &[]),
+        ] {
+            png.extend((data.len() as u32).to_be_bytes());
+            png.extend(kind);
+            png.extend(data);
+            png.extend([0; 4]);
+        }
+        png
+    }
+
+    #[test]
+    fn test_read_open_badges_2() {
+        let badge = read_assertion(&open_badges_2_assertion().to_string(), &emails())
+            .ok()
+            .unwrap();
+
+        assert_eq!(badge.format, BadgeFormat::OpenBadges2);
+        assert_eq!(badge.verification, BadgeVerification::Unverified);
+        assert_eq!(badge.name, "Cloud Practitioner");
+        assert_eq!(badge.organization, "Example Academy");
+        assert_eq!(badge.issue_date.unwrap().to_string(), "2023-05-04");
+        assert_eq!(badge.expiration_date.unwrap().to_string(), "2026-05-04");
+        assert_eq!(
+            badge.credential_url.as_deref(),
+            Some("https://badges.example.org/assertions/42")
+        );
+
+        // Test with assertions that are missing required parts
+        let mut assertion = open_badges_2_assertion();
+        assertion["badge"] = json!("https://badges.example.org/classes/1");
+        assert!(read_assertion(&assertion.to_string(), &emails()).is_err());
+        assert!(read_assertion(&json!({ "type": "Profile" }).to_string(), &emails()).is_err());
+        assert!(read_assertion("not a badge", &emails()).is_err());
+    }
+
+    #[test]
+    fn test_read_signed_credential() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let salt = "s4lt";
+        let identity_hash = format!(
+            "sha256${:x}",
+            Sha256::digest(format!("alice@example.org{}", salt).as_bytes())
+        );
+        let credential = json!({
+            "@context": ["https://www.w3.org/ns/credentials/v2", "https://purl.imsglobal.org/spec/ob/v3p0/context-3.0.3.json"],
+            "type": ["VerifiableCredential", "OpenBadgeCredential"],
+            "id": "urn:uuid:2b2a5b30-7d3b-4a5e-9f5e-5a5a5a5a5a5a",
+            "issuer": { "id": did_key(&key), "type": ["Profile"], "name": "Example University" },
+            "validFrom": "2024-01-15T00:00:00Z",
+            "credentialSubject": {
+                "type": ["AchievementSubject"],
+                "identifier": [{
+                    "type": "IdentityObject",
+                    "identityType": "emailAddress",
+                    "hashed": true,
+                    "salt": salt,
+                    "identityHash": identity_hash
+                }],
+                "achievement": { "type": ["Achievement"], "name": "Data Science Certificate" }
+            }
+        });
+
+        let signed = sign_credential(&credential, &key);
+        let badge = read_assertion(&signed.to_string(), &emails()).ok().unwrap();
+        assert_eq!(badge.format, BadgeFormat::OpenBadges3);
+        assert_eq!(badge.verification, BadgeVerification::Verified);
+        assert_eq!(badge.name, "Data Science Certificate");
+        assert_eq!(badge.organization, "Example University");
+        assert_eq!(badge.issue_date.unwrap().to_string(), "2024-01-15");
+        assert_eq!(badge.credential_url, None);
+
+        // Test with a badge issued to someone else
+        let other_emails = vec!["mallory@example.org".to_string()];
+        let badge = read_assertion(&signed.to_string(), &other_emails).ok().unwrap();
+        assert_eq!(badge.verification, BadgeVerification::Unverified);
+
+        // Test with a badge signed by a key that does not identify the issuer
+        let mut self_signed = credential.clone();
+        self_signed["issuer"]["id"] = json!("https://university.example.org");
+        let self_signed = sign_credential(&self_signed, &key);
+        let badge = read_assertion(&self_signed.to_string(), &emails()).ok().unwrap();
+        assert_eq!(badge.verification, BadgeVerification::Unverified);
+
+        let mut forged = signed.clone();
+        forged["credentialSubject"]["achievement"]["name"] = json!("Forged Certificate");
+        assert!(read_assertion(&forged.to_string(), &emails()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_read_credential_for_contact_email() {
+        let pool = test_pool().await;
+        let mallory_id = create_test_user(&pool, "mallory").await;
+        let alice_id = create_test_user(&pool, "alice").await;
+        let user_service = UserService::new(pool.clone());
+
+        let key = SigningKey::from_bytes(&[5; 32]);
+        let credential = sign_credential(
+            &json!({
+                "@context": ["https://www.w3.org/ns/credentials/v2", "https://purl.imsglobal.org/spec/ob/v3p0/context-3.0.3.json"],
+                "type": ["VerifiableCredential", "OpenBadgeCredential"],
+                "issuer": { "id": did_key(&key), "type": ["Profile"], "name": "Example University" },
+                "validFrom": "2024-01-15T00:00:00Z",
+                "credentialSubject": {
+                    "type": ["AchievementSubject"],
+                    "identifier": [{
+                        "type": "IdentityObject",
+                        "identityType": "emailAddress",
+                        "hashed": false,
+                        "identityHash": "alice@example.com"
+                    }],
+                    "achievement": { "type": ["Achievement"], "name": "Data Science Certificate" }
+                }
+            }),
+            &key,
+        );
+
+        // The address of the recipient is only a contact entry of this user, which proves nothing
+        sqlx::query(
+            "INSERT INTO contact_information (user_id, type_field, value) VALUES ($1, 'email', 'alice@example.com')",
+        )
+        .bind(mallory_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("UPDATE users SET email_verified_at = CURRENT_TIMESTAMP")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let emails: Vec<String> = user_service
+            .get_verified_email(mallory_id)
+            .await
+            .ok()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(emails, vec!["mallory@example.com".to_string()]);
+        let badge = read_assertion(&credential.to_string(), &emails).ok().unwrap();
+        assert_eq!(badge.verification, BadgeVerification::Unverified);
+
+        let emails: Vec<String> = user_service
+            .get_verified_email(alice_id)
+            .await
+            .ok()
+            .unwrap()
+            .into_iter()
+            .collect();
+        let badge = read_assertion(&credential.to_string(), &emails).ok().unwrap();
+        assert_eq!(badge.verification, BadgeVerification::Verified);
+
+        // An unconfirmed account email does not count either
+        sqlx::query("UPDATE users SET email_verified_at = NULL WHERE id = $1")
+            .bind(alice_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(user_service.get_verified_email(alice_id).await.ok().unwrap(), None);
+    }
+
+    #[test]
+    fn test_read_jws() {
+        let key = SigningKey::from_bytes(&[9; 32]);
+        let encode = |value: &Value| URL_SAFE_NO_PAD.encode(value.to_string());
+        let sign = |header: &Value, assertion: &Value| {
+            let signing_input = format!("{}.{}", encode(header), encode(assertion));
+            let signature = URL_SAFE_NO_PAD.encode(key.sign(signing_input.as_bytes()).to_bytes());
+            format!("{}.{}", signing_input, signature)
+        };
+        let jwk_header = json!({
+            "alg": "EdDSA",
+            "jwk": { "kty": "OKP", "crv": "Ed25519", "x": URL_SAFE_NO_PAD.encode(key.verifying_key().to_bytes()) }
+        });
+        let mut assertion = open_badges_2_assertion();
+        assertion["badge"]["issuer"]["id"] = json!(did_key(&key));
+
+        let badge = read_assertion(&sign(&jwk_header, &assertion), &emails())
+            .ok()
+            .unwrap();
+        assert_eq!(badge.verification, BadgeVerification::Verified);
+        assert_eq!(badge.name, "Cloud Practitioner");
+
+        let kid_header = json!({ "alg": "EdDSA", "kid": format!("{}#key-1", did_key(&key)) });
+        let badge = read_assertion(&sign(&kid_header, &assertion), &emails())
+            .ok()
+            .unwrap();
+        assert_eq!(badge.verification, BadgeVerification::Verified);
+
+        // Test with a valid signature by a key that does not identify the issuer
+        let badge = read_assertion(&sign(&jwk_header, &open_badges_2_assertion()), &emails())
+            .ok()
+            .unwrap();
+        assert_eq!(badge.verification, BadgeVerification::Unverified);
+
+        let signed = sign(&jwk_header, &assertion);
+        let signature = signed.rsplit('.').next().unwrap();
+        let mut forged = assertion.clone();
+        forged["badge"]["name"] = json!("Forged");
+        let forged = format!("{}.{}.{}", encode(&jwk_header), encode(&forged), signature);
+        assert!(read_assertion(&forged, &emails()).is_err());
+
+        let unsigned_header = json!({ "alg": "RS256" });
+        let unsigned = format!(
+            "{}.{}.{}",
+            encode(&unsigned_header),
+            encode(&assertion),
+            signature
+        );
+        assert_eq!(
+            read_assertion(&unsigned, &emails()).ok().unwrap().verification,
+            BadgeVerification::Unverified
+        );
+    }
+
+    #[test]
+    fn test_recipient_matches() {
+        let recipient = json!({ "type": "email", "hashed": false, "identity": "Alice@Example.org" });
+        assert!(recipient_matches(&recipient, &emails()));
+        assert!(!recipient_matches(&recipient, &[]));
+
+        let hash = Sha256::digest(b"alice@example.orgpepper");
+        let recipient = json!({
+            "type": "email",
+            "hashed": true,
+            "salt": "pepper",
+            "identity": format!("sha256${:x}", hash)
+        });
+        assert!(recipient_matches(&recipient, &emails()));
+        assert!(!recipient_matches(&recipient, &["bob@example.org".to_string()]));
+
+        let subject = json!({ "id": "mailto:alice@example.org" });
+        assert!(recipient_matches(&subject, &emails()));
+
+        // Test with identities that are not email addresses
+        let recipient = json!({ "type": "url", "hashed": false, "identity": "alice@example.org" });
+        assert!(!recipient_matches(&recipient, &emails()));
+    }
+
+    #[test]
+    fn test_extract_assertion() {
+        let assertion = open_badges_2_assertion().to_string();
+
+        let mut itxt = b"openbadges\0\0\0\0\0".to_vec();
+        itxt.extend(assertion.as_bytes());
+        let png = png_with_text_chunk(b"iTXt", &itxt);
+        assert_eq!(extract_assertion(&png).ok().unwrap(), assertion);
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(assertion.as_bytes()).unwrap();
+        let mut compressed = b"openbadges\0\x01\0\0\0".to_vec();
+        compressed.extend(encoder.finish().unwrap());
+        let png = png_with_text_chunk(b"iTXt", &compressed);
+        assert_eq!(extract_assertion(&png).ok().unwrap(), assertion);
+
+        let svg = format!(
+            "<svg xmlns:openbadges=\"https://openbadges.org\"><openbadges:assertion verify=\"https://badges.example.org/assertions/42\"><![CDATA[{}]]></openbadges:assertion></svg>",
+            assertion
+        );
+        assert_eq!(extract_assertion(svg.as_bytes()).ok().unwrap(), assertion);
+        assert_eq!(
+            import_badge(svg.as_bytes(), &emails()).ok().unwrap().name,
+            "Cloud Practitioner"
+        );
+
+        let svg = "<svg><openbadges:assertion verify=\"a.b.c\"/></svg>";
+        assert_eq!(extract_assertion(svg.as_bytes()).ok().unwrap(), "a.b.c");
+
+        // Test with files that only link to a hosted assertion or carry no badge
+        let svg = "<svg><openbadges:assertion verify=\"https://badges.example.org/assertions/42\"/></svg>";
+        assert!(extract_assertion(svg.as_bytes()).is_err());
+        assert!(extract_assertion(&png_with_text_chunk(b"tEXt", b"Comment\0hello")).is_err());
+        assert!(extract_assertion(b"<svg></svg>").is_err());
+    }
+}
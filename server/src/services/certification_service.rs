@@ -1,6 +1,9 @@
+use crate::models::badge::{CertificationBadgeModel, ImportedBadgeModel};
 use crate::models::certification::{
-    AuthCertificationModel, CertificationModel, PublicCertificationModel,
+    AuthCertificationModel, CertificationModel, CertificationStatus, CredentialLinkModel,
+    ExpiringCertificationModel, PublicCertificationModel,
 };
+use crate::models::date::{deserialize_optional_date, ProfileDate};
 use crate::response::error_handling::AppError;
 use crate::{IdenoDBResult, IdenoPool};
 
@@ -8,22 +11,29 @@ use crate::{IdenoDBResult, IdenoPool};
 pub struct AddCertificationPayload {
     pub name: String,
     pub organization: String,
-    pub issue_date: Option<String>,
-    pub expiration_date: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub issue_date: Option<ProfileDate>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub expiration_date: Option<ProfileDate>,
     pub credential_id: Option<String>,
     pub credential_url: Option<String>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct UpdateCertificationPayload {
-    name: String,
-    organization: String,
-    issue_date: Option<String>,
-    expiration_date: Option<String>,
-    credential_id: Option<String>,
-    credential_url: Option<String>,
+    pub name: String,
+    pub organization: String,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub issue_date: Option<ProfileDate>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub expiration_date: Option<ProfileDate>,
+    pub credential_id: Option<String>,
+    pub credential_url: Option<String>,
 }
 
+/// Certifications expiring within this many days are reported as expiring soon.
+pub const EXPIRING_SOON_DAYS: i64 = 30;
+
 #[derive(Clone)]
 pub struct CertificationService {
     db_pool: IdenoPool,
@@ -59,8 +69,12 @@ impl CertificationService {
                 organization,
                 issue_date,
                 expiration_date,
+                issue_date_legacy,
+                expiration_date_legacy,
                 credential_id,
-                credential_url
+                credential_url,
+                credential_url_status,
+                badge_verification
               FROM certification
               WHERE user_id = ?
               ORDER BY created_at DESC",
@@ -69,6 +83,14 @@ impl CertificationService {
         .fetch_all(&self.db_pool)
         .await
         .map_err(|_| AppError::InternalError)
+        .map(|mut certifications| {
+            let today = ProfileDate::today();
+            for certification in certifications.iter_mut() {
+                certification.status =
+                    certification_status(&certification.expiration_date, today);
+            }
+            certifications
+        })
     }
 
     /// Asynchronously retrieves public certifications associated with a user from the database.
@@ -99,7 +121,9 @@ impl CertificationService {
                 issue_date,
                 expiration_date,
                 credential_id,
-                credential_url
+                credential_url,
+                credential_url_status,
+                badge_verification
               FROM certification
               WHERE user_id = $1
               ORDER BY created_at DESC
@@ -110,6 +134,14 @@ impl CertificationService {
         .fetch_all(&self.db_pool)
         .await
         .map_err(|_| AppError::InternalError)
+        .map(|mut certifications| {
+            let today = ProfileDate::today();
+            for certification in certifications.iter_mut() {
+                certification.status =
+                    certification_status(&certification.expiration_date, today);
+            }
+            certifications
+        })
     }
 
     /// Asynchronously checks if a user owns a specific certification.
@@ -184,6 +216,9 @@ impl CertificationService {
 
     /// Asynchronously updates a certification entry associated with a user in the database.
     ///
+    /// A certification imported from a badge is detached from it if the name, organization, dates or credential ID
+    /// change, as the badge no longer vouches for the entry.
+    ///
     /// # Arguments
     ///
     /// * `user_id` - The ID of the user who owns the certification entry to be updated.
@@ -205,7 +240,31 @@ impl CertificationService {
         certification_id: i32,
         payload: UpdateCertificationPayload,
     ) -> Result<IdenoDBResult, AppError> {
-        sqlx::query("UPDATE certification SET name = $1, organization = $2, issue_date = $3, expiration_date = $4, credential_id = $5, credential_url = $6 WHERE id = $7 AND user_id = $8")
+        // Editing a field read from the badge detaches it, even if the field is changed back later
+        sqlx::query(
+            "UPDATE certification
+              SET badge_assertion = NULL, badge_format = NULL, badge_verification = NULL
+              WHERE id = $1
+                AND user_id = $2
+                AND badge_assertion IS NOT NULL
+                AND (name IS NOT $3
+                  OR organization IS NOT $4
+                  OR issue_date IS NOT $5
+                  OR expiration_date IS NOT $6
+                  OR credential_id IS NOT $7)",
+        )
+        .bind(certification_id)
+        .bind(user_id)
+        .bind(&payload.name)
+        .bind(&payload.organization)
+        .bind(payload.issue_date)
+        .bind(payload.expiration_date)
+        .bind(&payload.credential_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        sqlx::query("UPDATE certification SET name = $1, organization = $2, issue_date = $3, expiration_date = $4, issue_date_legacy = CASE WHEN $3 IS NULL THEN issue_date_legacy END, expiration_date_legacy = CASE WHEN $4 IS NULL THEN expiration_date_legacy END, credential_id = $5, credential_url = $6, credential_url_status = CASE WHEN credential_url IS $6 THEN credential_url_status END, credential_url_checked_at = CASE WHEN credential_url IS $6 THEN credential_url_checked_at END WHERE id = $7 AND user_id = $8")
             .bind(payload.name)
             .bind(payload.organization)
             .bind(payload.issue_date)
@@ -254,6 +313,143 @@ impl CertificationService {
             .map(|id| id.0)
     }
 
+    /// Asynchronously creates a new certification entry from an imported Open Badges assertion.
+    ///
+    /// The raw assertion is stored with the certification so its signature can be checked again later.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the certification is being created.
+    /// * `badge` - An `ImportedBadgeModel` containing the certification details read from the badge.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created certification entry if successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_imported_certification(
+        &self,
+        user_id: i32,
+        badge: ImportedBadgeModel,
+    ) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64, )>
+            ("INSERT INTO certification (user_id, name, organization, issue_date, expiration_date, credential_id, credential_url, badge_assertion, badge_format, badge_verification) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) RETURNING id")
+            .bind(user_id)
+            .bind(badge.name)
+            .bind(badge.organization)
+            .bind(badge.issue_date)
+            .bind(badge.expiration_date)
+            .bind(badge.credential_id)
+            .bind(badge.credential_url)
+            .bind(badge.assertion)
+            .bind(badge.format.as_str())
+            .bind(badge.verification.as_str())
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|id| id.0)
+    }
+
+    /// Asynchronously retrieves the badge a certification was imported from.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the certification.
+    /// * `certification_id` - The ID of the certification.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `CertificationBadgeModel` of the certification.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the certification does not exist or was not imported from a badge.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_certification_badge(
+        &self,
+        user_id: i32,
+        certification_id: i32,
+    ) -> Result<CertificationBadgeModel, AppError> {
+        sqlx::query_as::<_, CertificationBadgeModel>(
+            "SELECT
+                badge_format,
+                badge_verification,
+                badge_assertion
+              FROM certification
+              WHERE id = $1 AND user_id = $2 AND badge_assertion IS NOT NULL",
+        )
+        .bind(certification_id)
+        .bind(user_id)
+        .fetch_optional(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?
+        .ok_or(AppError::NotFound {
+            error: "Badge not found".to_string(),
+        })
+    }
+
+    /// Asynchronously stores the outcome of checking the signature of the badge a certification was imported from.
+    ///
+    /// # Arguments
+    ///
+    /// * `certification_id` - The ID of the certification.
+    /// * `verification` - The outcome of the check: `verified`, `unverified` or `invalid`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the operation was successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn set_badge_verification(
+        &self,
+        certification_id: i32,
+        verification: &str,
+    ) -> Result<(), AppError> {
+        sqlx::query("UPDATE certification SET badge_verification = $1 WHERE id = $2")
+            .bind(verification)
+            .bind(certification_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+
+    /// Validates that the expiration date of a certification does not come before its issue date.
+    ///
+    /// # Arguments
+    ///
+    /// * `issue_date` - An optional reference to the issue date.
+    /// * `expiration_date` - An optional reference to the expiration date.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the dates are valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the expiration date is before the issue date.
+    ///
+    pub fn validate_certification_dates(
+        &self,
+        issue_date: &Option<ProfileDate>,
+        expiration_date: &Option<ProfileDate>,
+    ) -> Result<bool, AppError> {
+        if !ProfileDate::is_valid_range(issue_date, expiration_date) {
+            return Err(AppError::BadRequest {
+                error: Some("Expiration date must not be before issue date".to_string()),
+            });
+        }
+
+        Ok(true)
+    }
+
     /// Asynchronously retrieves the count of certification entries associated with a user from the database.
     ///
     /// # Arguments
@@ -300,6 +496,14 @@ impl CertificationService {
             .fetch_all(&self.db_pool)
             .await
             .map_err(|_| AppError::InternalError)
+            .map(|mut certifications| {
+                let today = ProfileDate::today();
+                for certification in certifications.iter_mut() {
+                    certification.status =
+                        certification_status(&certification.expiration_date, today);
+                }
+                certifications
+            })
     }
 
     /// Asynchronously deletes a certification entry from the database foe administrative purposes.
@@ -359,4 +563,283 @@ impl CertificationService {
             }),
         }
     }
+
+    /// Asynchronously retrieves the certifications whose owners have not been notified about their expiration date yet.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `ExpiringCertificationModel` instances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_unnotified_expiring_certifications(
+        &self,
+    ) -> Result<Vec<ExpiringCertificationModel>, AppError> {
+        sqlx::query_as::<_, ExpiringCertificationModel>(
+            "SELECT
+                id,
+                user_id,
+                name,
+                expiration_date
+              FROM certification
+              WHERE expiration_date IS NOT NULL
+                AND expiry_notified_for IS NOT expiration_date",
+        )
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously records that the owner of a certification was notified about its expiration date.
+    ///
+    /// # Arguments
+    ///
+    /// * `certification_id` - The ID of the certification.
+    /// * `expiration_date` - The expiration date the owner was notified about.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the notification was recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn mark_expiry_notified(
+        &self,
+        certification_id: i32,
+        expiration_date: ProfileDate,
+    ) -> Result<(), AppError> {
+        sqlx::query("UPDATE certification SET expiry_notified_for = $1 WHERE id = $2")
+            .bind(expiration_date)
+            .bind(certification_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+
+    /// Asynchronously retrieves the credential links that were never checked or not checked within the given number of days.
+    ///
+    /// # Arguments
+    ///
+    /// * `recheck_after_days` - How many days a check result stays fresh.
+    /// * `limit` - The maximum number of links to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `CredentialLinkModel` instances, the least recently checked first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_credential_links_due(
+        &self,
+        recheck_after_days: i64,
+        limit: i32,
+    ) -> Result<Vec<CredentialLinkModel>, AppError> {
+        sqlx::query_as::<_, CredentialLinkModel>(
+            "SELECT
+                id,
+                credential_url
+              FROM certification
+              WHERE credential_url IS NOT NULL
+                AND (credential_url_checked_at IS NULL
+                  OR credential_url_checked_at < datetime('now', '-' || $1 || ' days'))
+              ORDER BY credential_url_checked_at IS NOT NULL, credential_url_checked_at
+              LIMIT $2",
+        )
+        .bind(recheck_after_days)
+        .bind(limit)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously stores the result of a credential link check.
+    ///
+    /// The result is discarded if the link changed while it was being checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `link` - The checked credential link.
+    /// * `reachable` - Whether the link could be reached.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the operation was successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn set_credential_url_status(
+        &self,
+        link: &CredentialLinkModel,
+        reachable: bool,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE certification SET credential_url_status = $1, credential_url_checked_at = CURRENT_TIMESTAMP WHERE id = $2 AND credential_url = $3",
+        )
+        .bind(if reachable { "reachable" } else { "broken" })
+        .bind(link.id)
+        .bind(&link.credential_url)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|_| ())
+    }
+}
+
+/// Derives the status of a certification from its expiration date.
+///
+/// A certification expires after the last day its expiration date may refer to, so one expiring in `2025-06`
+/// is valid until the end of June. Certifications without an expiration date never expire.
+///
+pub(crate) fn certification_status(
+    expiration_date: &Option<ProfileDate>,
+    today: ProfileDate,
+) -> CertificationStatus {
+    let Some(expiration_date) = expiration_date else {
+        return CertificationStatus::Valid;
+    };
+
+    let days_left = expiration_date.last_day().days_since_epoch() - today.days_since_epoch();
+
+    if days_left < 0 {
+        CertificationStatus::Expired
+    } else if days_left <= EXPIRING_SOON_DAYS {
+        CertificationStatus::ExpiringSoon
+    } else {
+        CertificationStatus::Valid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{certification_status, CertificationService, UpdateCertificationPayload};
+    use crate::models::badge::{BadgeFormat, BadgeVerification, ImportedBadgeModel};
+    use crate::models::certification::CertificationStatus;
+    use crate::models::date::ProfileDate;
+    use crate::test_utils::{create_test_user, test_pool};
+
+    #[test]
+    fn test_certification_status() {
+        let today = ProfileDate::parse("2024-06-15").unwrap();
+        let status = |value: &str| certification_status(&ProfileDate::parse(value), today);
+
+        assert_eq!(certification_status(&None, today), CertificationStatus::Valid);
+        assert_eq!(status("2025"), CertificationStatus::Valid);
+        assert_eq!(status("2024-07-16"), CertificationStatus::Valid);
+        assert_eq!(status("2024-07-15"), CertificationStatus::ExpiringSoon);
+        assert_eq!(status("2024-06"), CertificationStatus::ExpiringSoon);
+        assert_eq!(status("2024-06-15"), CertificationStatus::ExpiringSoon);
+        assert_eq!(status("2024"), CertificationStatus::Valid);
+        assert_eq!(status("2024-06-14"), CertificationStatus::Expired);
+        assert_eq!(status("2024-05"), CertificationStatus::Expired);
+        assert_eq!(status("2023"), CertificationStatus::Expired);
+    }
+
+    #[tokio::test]
+    async fn test_update_imported_certification() {
+        let pool = test_pool().await;
+        let user_id = create_test_user(&pool, "alice").await;
+        let service = CertificationService::new(pool);
+
+        let certification_id = service
+            .create_imported_certification(
+                user_id,
+                ImportedBadgeModel {
+                    format: BadgeFormat::OpenBadges3,
+                    verification: BadgeVerification::Verified,
+                    name: "Data Science Certificate".to_string(),
+                    organization: "Example University".to_string(),
+                    issue_date: ProfileDate::parse("2024-01-15"),
+                    expiration_date: None,
+                    credential_id: None,
+                    credential_url: None,
+                    assertion: "{}".to_string(),
+                },
+            )
+            .await
+            .ok()
+            .unwrap() as i32;
+        let payload = |name: &str| UpdateCertificationPayload {
+            name: name.to_string(),
+            organization: "Example University".to_string(),
+            issue_date: ProfileDate::parse("2024-01-15"),
+            expiration_date: None,
+            credential_id: None,
+            credential_url: Some("https://university.example.org".to_string()),
+        };
+
+        // Editing a field the badge does not cover keeps the badge
+        service
+            .update_certification(user_id, certification_id, payload("Data Science Certificate"))
+            .await
+            .ok()
+            .unwrap();
+        let certifications = service.get_all_certifications(user_id).await.ok().unwrap();
+        assert_eq!(certifications[0].badge_verification.as_deref(), Some("verified"));
+
+        // Renaming the certification detaches the badge
+        service
+            .update_certification(user_id, certification_id, payload("Forged Certificate"))
+            .await
+            .ok()
+            .unwrap();
+        let certifications = service.get_all_certifications(user_id).await.ok().unwrap();
+        assert_eq!(certifications[0].badge_verification, None);
+        assert!(service
+            .get_certification_badge(user_id, certification_id)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_legacy_certification_dates() {
+        let pool = test_pool().await;
+        let user_id = create_test_user(&pool, "alice").await;
+        let service = CertificationService::new(pool.clone());
+
+        // A date the migration could not read is kept next to the empty date
+        let certification_id = sqlx::query_as::<_, (i32,)>(
+            "INSERT INTO certification (user_id, name, organization, issue_date_legacy, expiration_date_legacy)
+              VALUES ($1, 'First Aid', 'Red Cross', 'spring 2019', 'never') RETURNING id",
+        )
+        .bind(user_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap()
+        .0;
+
+        let certifications = service.get_all_certifications(user_id).await.ok().unwrap();
+        assert_eq!(certifications[0].issue_date, None);
+        assert_eq!(certifications[0].issue_date_legacy.as_deref(), Some("spring 2019"));
+
+        // Entering the date again replaces the legacy value, dates left empty keep theirs
+        service
+            .update_certification(
+                user_id,
+                certification_id,
+                UpdateCertificationPayload {
+                    name: "First Aid".to_string(),
+                    organization: "Red Cross".to_string(),
+                    issue_date: ProfileDate::parse("2019-04"),
+                    expiration_date: None,
+                    credential_id: None,
+                    credential_url: None,
+                },
+            )
+            .await
+            .ok()
+            .unwrap();
+        let certifications = service.get_all_certifications(user_id).await.ok().unwrap();
+        assert_eq!(certifications[0].issue_date, ProfileDate::parse("2019-04"));
+        assert_eq!(certifications[0].issue_date_legacy, None);
+        assert_eq!(certifications[0].expiration_date_legacy.as_deref(), Some("never"));
+    }
 }
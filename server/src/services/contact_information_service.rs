@@ -1,29 +1,149 @@
+use std::net::IpAddr;
+
+use axum::http::HeaderMap;
+
 use crate::models::contact_information::{
-    AuthContactInformationModel, ContactInformationModel, PublicContactInformationModel,
+    AuthContactInformationModel, ContactInformationModel, ContactRevealModel,
+    PublicContactInformationModel,
 };
+use crate::services::contact_type_service::ContactTypeService;
 use crate::response::error_handling::AppError;
 use crate::{IdenoDBResult, IdenoPool};
 
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 pub struct AddContactInformationPayload {
     pub contact_type: String,
+    pub label: Option<String>,
     pub value: String,
+    pub visibility: Option<String>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct UpdateContactInformationPayload {
     pub contact_type: String,
+    pub label: Option<String>,
     pub value: String,
+    pub visibility: Option<String>,
+}
+
+/// The reverse proxies whose `X-Forwarded-For` header is trusted to name the client, as addresses or CIDR ranges.
+#[derive(Clone, Default)]
+pub struct TrustedProxies {
+    networks: Vec<(IpAddr, u32)>,
+}
+
+impl TrustedProxies {
+    /// Parses a comma separated list of addresses and CIDR ranges, like `127.0.0.1,172.16.0.0/12`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `TrustedProxies`, or an `Err` with the first entry that is not a valid address or range.
+    ///
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let networks = value
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (address, prefix) = entry.split_once('/').unwrap_or((entry, ""));
+                let address: IpAddr = address.parse().map_err(|_| entry.to_string())?;
+                let max_prefix = if address.is_ipv4() { 32 } else { 128 };
+                let prefix = match prefix {
+                    "" => max_prefix,
+                    prefix => prefix
+                        .parse()
+                        .ok()
+                        .filter(|prefix| *prefix <= max_prefix)
+                        .ok_or(entry.to_string())?,
+                };
+
+                Ok((address, prefix))
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(TrustedProxies { networks })
+    }
+
+    fn contains(&self, address: IpAddr) -> bool {
+        self.networks
+            .iter()
+            .any(|(network, prefix)| match (network, address) {
+                (IpAddr::V4(network), IpAddr::V4(address)) => {
+                    let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+                    u32::from(*network) & mask == u32::from(address) & mask
+                }
+                (IpAddr::V6(network), IpAddr::V6(address)) => {
+                    let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+                    u128::from(*network) & mask == u128::from(address) & mask
+                }
+                _ => false,
+            })
+    }
+
+    /// Determines the address of the client behind any trusted reverse proxies.
+    ///
+    /// The `X-Forwarded-For` addresses are read from right to left, skipping trusted proxies, as only the entries
+    /// appended by trusted proxies can be relied on.
+    ///
+    /// # Arguments
+    ///
+    /// * `peer` - The address the connection came from.
+    /// * `headers` - The headers of the request.
+    ///
+    /// # Returns
+    ///
+    /// Returns the address of the client, or the peer address if the peer is not a trusted proxy.
+    ///
+    pub fn client_address(&self, peer: IpAddr, headers: &HeaderMap) -> IpAddr {
+        if !self.contains(peer) {
+            return peer;
+        }
+
+        let forwarded: Vec<&str> = headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .collect();
+
+        let mut client = peer;
+        for entry in forwarded.into_iter().rev() {
+            let Ok(address) = entry.trim().parse::<IpAddr>() else {
+                break;
+            };
+            client = address;
+            if !self.contains(address) {
+                break;
+            }
+        }
+
+        client
+    }
 }
 
 #[derive(Clone)]
 pub struct ContactInformationService {
     db_pool: IdenoPool,
+    contact_type_service: ContactTypeService,
+    trusted_proxies: TrustedProxies,
 }
 
 impl ContactInformationService {
-    pub fn new(db_pool: IdenoPool) -> Self {
-        ContactInformationService { db_pool }
+    pub fn new(
+        db_pool: IdenoPool,
+        contact_type_service: ContactTypeService,
+        trusted_proxies: TrustedProxies,
+    ) -> Self {
+        ContactInformationService {
+            db_pool,
+            contact_type_service,
+            trusted_proxies,
+        }
+    }
+
+    /// Determines the address of the client of a request, see `TrustedProxies::client_address`.
+    pub fn client_address(&self, peer: IpAddr, headers: &HeaderMap) -> IpAddr {
+        self.trusted_proxies.client_address(peer, headers)
     }
 
     /// Asynchronously retrieves authenticated contact information associated with a user from the database.
@@ -44,19 +164,30 @@ impl ContactInformationService {
         &self,
         user_id: i32,
     ) -> Result<Vec<AuthContactInformationModel>, AppError> {
-        sqlx::query_as::<_, AuthContactInformationModel>(
+        let mut contact_information = sqlx::query_as::<_, AuthContactInformationModel>(
             "SELECT
                 id,
                 type_field,
-                value
+                label,
+                value,
+                visibility
                 FROM contact_information
                 WHERE user_id = $1
                 ORDER BY created_at DESC",
         )
-        .bind(&user_id)
+        .bind(user_id)
         .fetch_all(&self.db_pool)
         .await
-        .map_err(|_| AppError::InternalError)
+        .map_err(|_| AppError::InternalError)?;
+
+        let contact_types = self.contact_type_service.get_contact_types_by_name().await?;
+        for entry in contact_information.iter_mut() {
+            entry.url = contact_types
+                .get(&entry.type_field)
+                .and_then(|contact_type| contact_type.url(&entry.value));
+        }
+
+        Ok(contact_information)
     }
 
     /// Asynchronously retrieves public contact information associated with a user from the database.
@@ -80,17 +211,172 @@ impl ContactInformationService {
         limit: Option<i32>,
     ) -> Result<Vec<PublicContactInformationModel>, AppError> {
         let limit = limit.unwrap_or(-1);
-        sqlx::query_as::<_, PublicContactInformationModel>(
+        let mut contact_information = sqlx::query_as::<_, PublicContactInformationModel>(
             "SELECT
+                id,
                 type_field,
-                value
+                label,
+                value,
+                visibility
                 FROM contact_information
                 WHERE user_id = $1
                 ORDER BY created_at DESC
                 LIMIT $2",
         )
-        .bind(&user_id)
-        .bind(&limit)
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        let contact_types = self.contact_type_service.get_contact_types_by_name().await?;
+        for entry in contact_information.iter_mut() {
+            let contact_type = contact_types.get(&entry.type_field);
+
+            if entry.visibility == "public" {
+                entry.url = contact_type.and_then(|contact_type| contact_type.url(&entry.value));
+            } else {
+                entry.value = contact_type
+                    .map(|contact_type| contact_type.mask(&entry.value))
+                    .unwrap_or_default();
+                entry.masked = true;
+            }
+        }
+
+        Ok(contact_information)
+    }
+
+    /// Asynchronously retrieves a contact information entry of a user with its value, masked or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the entry.
+    /// * `contact_information_id` - The ID of the contact information entry.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the unmasked `PublicContactInformationModel`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the user has no contact information entry with the given ID.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_revealed_contact_information(
+        &self,
+        user_id: i32,
+        contact_information_id: i32,
+    ) -> Result<PublicContactInformationModel, AppError> {
+        let mut entry = sqlx::query_as::<_, PublicContactInformationModel>(
+            "SELECT
+                id,
+                type_field,
+                label,
+                value,
+                visibility
+                FROM contact_information
+                WHERE id = $1 AND user_id = $2",
+        )
+        .bind(contact_information_id)
+        .bind(user_id)
+        .fetch_optional(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?
+        .ok_or(AppError::NotFound {
+            error: "Contact information not found".to_string(),
+        })?;
+
+        entry.url = self
+            .contact_type_service
+            .get_contact_type(&entry.type_field)
+            .await
+            .ok()
+            .and_then(|contact_type| contact_type.url(&entry.value));
+
+        Ok(entry)
+    }
+
+    /// Asynchronously records that a viewer revealed a masked contact entry, unless the viewer reached the limit.
+    ///
+    /// The reveals of the last hour are counted in the same statement as the insert, so concurrent reveals cannot
+    /// exceed the limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `contact_information_id` - The ID of the revealed entry.
+    /// * `owner_id` - The ID of the user who owns the entry.
+    /// * `viewer_id` - The ID of the viewer, if logged in.
+    /// * `viewer_key` - The hashed key identifying the viewer.
+    /// * `reveals_per_hour` - How many entries the viewer may reveal per hour.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing `true` if the reveal was recorded, or `false` if the viewer reached the limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn record_contact_reveal(
+        &self,
+        contact_information_id: i32,
+        owner_id: i32,
+        viewer_id: Option<i32>,
+        viewer_key: &str,
+        reveals_per_hour: i64,
+    ) -> Result<bool, AppError> {
+        sqlx::query(
+            "INSERT INTO contact_reveals (contact_information_id, owner_id, viewer_id, viewer_key)
+              SELECT $1, $2, $3, $4
+              WHERE (
+                SELECT COUNT(*) FROM contact_reveals
+                WHERE viewer_key = $4 AND created_at > datetime('now', '-1 hour')
+              ) < $5",
+        )
+        .bind(contact_information_id)
+        .bind(owner_id)
+        .bind(viewer_id)
+        .bind(viewer_key)
+        .bind(reveals_per_hour)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|result| result.rows_affected() > 0)
+    }
+
+    /// Asynchronously retrieves the reveals of the masked contact entries of a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the entries.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `ContactRevealModel` instances, the most recent first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_contact_reveals(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<ContactRevealModel>, AppError> {
+        sqlx::query_as::<_, ContactRevealModel>(
+            "SELECT
+                contact_reveals.contact_information_id,
+                contact_information.type_field,
+                contact_information.label,
+                users.username AS viewer_username,
+                contact_reveals.created_at
+              FROM contact_reveals
+              JOIN contact_information ON contact_information.id = contact_reveals.contact_information_id
+              LEFT JOIN users ON users.id = contact_reveals.viewer_id
+              WHERE contact_reveals.owner_id = $1
+              ORDER BY contact_reveals.created_at DESC, contact_reveals.id DESC
+              LIMIT 500",
+        )
+        .bind(user_id)
         .fetch_all(&self.db_pool)
         .await
         .map_err(|_| AppError::InternalError)
@@ -188,10 +474,12 @@ impl ContactInformationService {
         payload: UpdateContactInformationPayload,
     ) -> Result<IdenoDBResult, AppError> {
         sqlx::query(
-            "UPDATE contact_information SET type_field = $1, value = $2 WHERE id = $3 AND user_id = $4",
+            "UPDATE contact_information SET type_field = $1, label = $2, value = $3, visibility = COALESCE($4, visibility) WHERE id = $5 AND user_id = $6",
         )
             .bind(payload.contact_type)
+            .bind(payload.label)
             .bind(payload.value)
+            .bind(payload.visibility)
             .bind(contact_information_id)
             .bind(user_id)
             .execute(&self.db_pool)
@@ -219,10 +507,12 @@ impl ContactInformationService {
         user_id: i32,
         payload: AddContactInformationPayload,
     ) -> Result<i64, AppError> {
-        sqlx::query_as::<_, (i64,)>("INSERT INTO contact_information (user_id, type_field, value) VALUES ($1, $2, $3) RETURNING id")
+        sqlx::query_as::<_, (i64,)>("INSERT INTO contact_information (user_id, type_field, label, value, visibility) VALUES ($1, $2, $3, $4, COALESCE($5, 'public')) RETURNING id")
             .bind(user_id)
             .bind(payload.contact_type)
+            .bind(payload.label)
             .bind(payload.value)
+            .bind(payload.visibility)
             .fetch_one(&self.db_pool)
             .await
             .map_err(|_| AppError::InternalError)
@@ -250,15 +540,16 @@ impl ContactInformationService {
         data: &AddContactInformationPayload,
     ) -> Result<bool, AppError> {
         sqlx::query_as::<_, (i64,)>(
-            "SELECT id FROM contact_information WHERE user_id = $1 AND type_field = $2 AND value = $3 LIMIT 1",
+            "SELECT id FROM contact_information WHERE user_id = $1 AND type_field = $2 AND value = $3 AND label IS $4 LIMIT 1",
         )
             .bind(user_id)
             .bind(&data.contact_type)
             .bind(&data.value)
+            .bind(&data.label)
             .fetch_all(&self.db_pool)
             .await
             .map_err(|_| AppError::InternalError)
-            .map(|count| count.len() > 0)
+            .map(|count| !count.is_empty())
     }
 
     /// Asynchronously retrieves the count of contact information entries associated with a user from the database.
@@ -302,13 +593,22 @@ impl ContactInformationService {
         &self,
         user_id: i32,
     ) -> Result<Vec<ContactInformationModel>, AppError> {
-        sqlx::query_as::<_, ContactInformationModel>(
+        let mut contact_information = sqlx::query_as::<_, ContactInformationModel>(
             "SELECT * FROM contact_information WHERE user_id = $1 ORDER BY created_at DESC",
         )
         .bind(user_id)
         .fetch_all(&self.db_pool)
         .await
-        .map_err(|_| AppError::InternalError)
+        .map_err(|_| AppError::InternalError)?;
+
+        let contact_types = self.contact_type_service.get_contact_types_by_name().await?;
+        for entry in contact_information.iter_mut() {
+            entry.url = contact_types
+                .get(&entry.type_field)
+                .and_then(|contact_type| contact_type.url(&entry.value));
+        }
+
+        Ok(contact_information)
     }
 
     /// Asynchronously deletes a contact information entry from the database foe administrative purposes.
@@ -373,3 +673,120 @@ impl ContactInformationService {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use axum::http::HeaderMap;
+
+    use super::{ContactInformationService, TrustedProxies};
+    use crate::services::contact_type_service::ContactTypeService;
+    use crate::test_utils::{create_test_user, test_pool};
+
+    fn ip(address: &str) -> IpAddr {
+        address.parse().unwrap()
+    }
+
+    fn forwarded_for(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", value.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_client_address() {
+        let proxies = TrustedProxies::parse("127.0.0.1, 172.16.0.0/12,::1")
+            .ok()
+            .unwrap();
+        let headers = forwarded_for("10.0.0.1, 203.0.113.7, 172.17.0.5");
+
+        // Trusted proxies are skipped from the right, spoofed entries left of the client are ignored
+        assert_eq!(
+            proxies.client_address(ip("127.0.0.1"), &headers),
+            ip("203.0.113.7")
+        );
+        assert_eq!(
+            proxies.client_address(ip("172.20.1.1"), &headers),
+            ip("203.0.113.7")
+        );
+        assert_eq!(
+            proxies.client_address(ip("::1"), &forwarded_for("2001:db8::1")),
+            ip("2001:db8::1")
+        );
+
+        // Test with a peer that is not a trusted proxy
+        assert_eq!(
+            proxies.client_address(ip("198.51.100.2"), &headers),
+            ip("198.51.100.2")
+        );
+        assert_eq!(
+            TrustedProxies::default().client_address(ip("127.0.0.1"), &headers),
+            ip("127.0.0.1")
+        );
+
+        // Test without a usable header
+        assert_eq!(
+            proxies.client_address(ip("127.0.0.1"), &HeaderMap::new()),
+            ip("127.0.0.1")
+        );
+        assert_eq!(
+            proxies.client_address(ip("127.0.0.1"), &forwarded_for("unknown")),
+            ip("127.0.0.1")
+        );
+
+        assert_eq!(TrustedProxies::parse("").ok().unwrap().networks.len(), 0);
+        assert_eq!(
+            TrustedProxies::parse("127.0.0.1,proxy").err(),
+            Some("proxy".to_string())
+        );
+        assert_eq!(
+            TrustedProxies::parse("10.0.0.0/33").err(),
+            Some("10.0.0.0/33".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_record_contact_reveal() {
+        let pool = test_pool().await;
+        let owner_id = create_test_user(&pool, "owner").await;
+        let service = ContactInformationService::new(
+            pool.clone(),
+            ContactTypeService::new(pool.clone()),
+            TrustedProxies::default(),
+        );
+
+        let entry_id = sqlx::query_as::<_, (i32,)>(
+            "INSERT INTO contact_information (user_id, type_field, value, visibility) VALUES ($1, 'email', 'owner@example.org', 'masked') RETURNING id",
+        )
+        .bind(owner_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap()
+        .0;
+
+        for _ in 0..2 {
+            assert!(service
+                .record_contact_reveal(entry_id, owner_id, None, "viewer", 2)
+                .await
+                .ok()
+                .unwrap());
+        }
+        assert!(!service
+            .record_contact_reveal(entry_id, owner_id, None, "viewer", 2)
+            .await
+            .ok()
+            .unwrap());
+        assert!(service
+            .record_contact_reveal(entry_id, owner_id, None, "other viewer", 2)
+            .await
+            .ok()
+            .unwrap());
+
+        let reveals = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM contact_reveals")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(reveals.0, 3);
+    }
+}
@@ -0,0 +1,356 @@
+use std::collections::HashMap;
+
+use crate::models::contact_type::{
+    full_match, normalize_domain, ContactTypeModel, ContactValueFormat,
+};
+use crate::response::error_handling::AppError;
+use crate::IdenoPool;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AddContactTypePayload {
+    pub name: String,
+    pub label: String,
+    pub value_format: String,
+    pub pattern: Option<String>,
+    pub hosts: Option<String>,
+    pub path_prefix: Option<String>,
+    pub url_template: Option<String>,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    #[serde(default)]
+    pub has_label: bool,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdateContactTypePayload {
+    pub label: String,
+    pub pattern: Option<String>,
+    pub hosts: Option<String>,
+    pub path_prefix: Option<String>,
+    pub url_template: Option<String>,
+    pub case_sensitive: bool,
+    pub is_enabled: bool,
+}
+
+#[derive(Clone)]
+pub struct ContactTypeService {
+    db_pool: IdenoPool,
+}
+
+impl ContactTypeService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        ContactTypeService { db_pool }
+    }
+
+    /// Asynchronously retrieves the contact types of the registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `include_disabled` - Whether to include the types that can no longer be chosen.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `ContactTypeModel` instances, in the order they were added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_contact_types(
+        &self,
+        include_disabled: bool,
+    ) -> Result<Vec<ContactTypeModel>, AppError> {
+        sqlx::query_as::<_, ContactTypeModel>(
+            "SELECT
+                name,
+                label,
+                value_format,
+                pattern,
+                hosts,
+                path_prefix,
+                url_template,
+                case_sensitive,
+                has_label,
+                is_enabled
+              FROM contact_types
+              WHERE is_enabled OR $1
+              ORDER BY rowid",
+        )
+        .bind(include_disabled)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously retrieves all contact types of the registry, keyed by name.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a map from type names to `ContactTypeModel` instances, including disabled types.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_contact_types_by_name(
+        &self,
+    ) -> Result<HashMap<String, ContactTypeModel>, AppError> {
+        self.get_contact_types(true).await.map(|contact_types| {
+            contact_types
+                .into_iter()
+                .map(|contact_type| (contact_type.name.clone(), contact_type))
+                .collect()
+        })
+    }
+
+    /// Asynchronously retrieves a contact type of the registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the contact type.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `ContactTypeModel`, which may be disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if there is no contact type with the given name.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_contact_type(&self, name: &str) -> Result<ContactTypeModel, AppError> {
+        sqlx::query_as::<_, ContactTypeModel>(
+            "SELECT
+                name,
+                label,
+                value_format,
+                pattern,
+                hosts,
+                path_prefix,
+                url_template,
+                case_sensitive,
+                has_label,
+                is_enabled
+              FROM contact_types
+              WHERE name = $1",
+        )
+        .bind(name)
+        .fetch_optional(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?
+        .ok_or(AppError::NotFound {
+            error: "Contact type not found".to_string(),
+        })
+    }
+
+    /// Asynchronously adds a contact type to the registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - An `AddContactTypePayload` containing the validated details of the contact type.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the contact type was added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::DataConflict` if a contact type with the same name already exists.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_contact_type(
+        &self,
+        payload: AddContactTypePayload,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "INSERT INTO contact_types (name, label, value_format, pattern, hosts, path_prefix, url_template, case_sensitive, has_label)
+              VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+              ON CONFLICT (name) DO NOTHING",
+        )
+        .bind(payload.name)
+        .bind(payload.label.trim())
+        .bind(payload.value_format)
+        .bind(payload.pattern)
+        .bind(payload.hosts)
+        .bind(payload.path_prefix)
+        .bind(payload.url_template)
+        .bind(payload.case_sensitive)
+        .bind(payload.has_label)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::DataConflict {
+                error: "Contact type already exists".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Asynchronously updates a contact type of the registry.
+    ///
+    /// The name and value format of a type cannot change, as existing entries are stored in the format of their type.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the contact type.
+    /// * `payload` - An `UpdateContactTypePayload` containing the validated details of the contact type.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the operation was successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn update_contact_type(
+        &self,
+        name: &str,
+        payload: UpdateContactTypePayload,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE contact_types SET label = $1, pattern = $2, hosts = $3, path_prefix = $4, url_template = $5, case_sensitive = $6, is_enabled = $7 WHERE name = $8",
+        )
+        .bind(payload.label.trim())
+        .bind(payload.pattern)
+        .bind(payload.hosts)
+        .bind(payload.path_prefix)
+        .bind(payload.url_template)
+        .bind(payload.case_sensitive)
+        .bind(payload.is_enabled)
+        .bind(name)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|_| ())
+    }
+
+    /// Validates the configurable rules of a contact type.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - The label shown for the type.
+    /// * `pattern` - An optional regular expression values must match.
+    /// * `hosts` - Optional comma-separated hosts whose profile links are accepted.
+    /// * `path_prefix` - An optional path segment profile links start with.
+    /// * `url_template` - An optional template for the links of values.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the rules are valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message describing the first invalid rule.
+    ///
+    pub fn validate_contact_type(
+        &self,
+        label: &str,
+        pattern: &Option<String>,
+        hosts: &Option<String>,
+        path_prefix: &Option<String>,
+        url_template: &Option<String>,
+    ) -> Result<bool, AppError> {
+        let error = if label.trim().is_empty() || label.trim().chars().count() > 50 {
+            Some("Label must be between 1 and 50 characters")
+        } else if pattern
+            .as_deref()
+            .is_some_and(|pattern| full_match(pattern).is_none())
+        {
+            Some("Invalid pattern")
+        } else if hosts.as_deref().is_some_and(|hosts| {
+            hosts
+                .split(',')
+                .any(|host| normalize_domain(host.trim()).as_deref() != Some(host.trim()))
+        }) {
+            Some("Hosts must be lowercase domain names separated by commas")
+        } else if path_prefix.as_deref().is_some_and(|path_prefix| {
+            path_prefix.is_empty()
+                || !path_prefix
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "_-".contains(c))
+        }) {
+            Some("Invalid path prefix")
+        } else if url_template
+            .as_deref()
+            .is_some_and(|url_template| !url_template.starts_with("https://"))
+        {
+            Some("URL template must start with https://")
+        } else {
+            None
+        };
+
+        match error {
+            Some(error) => Err(AppError::BadRequest {
+                error: Some(error.to_string()),
+            }),
+            None => Ok(true),
+        }
+    }
+
+    /// Asynchronously validates a contact information entry against its type and converts it to its canonical form.
+    ///
+    /// # Arguments
+    ///
+    /// * `contact_type` - The name of the contact type, e.g. `email` or `github`.
+    /// * `label` - The label given by the user, kept only for types with user-given labels.
+    /// * `value` - The value as entered by the user.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the canonical value and label to store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the type is unknown or disabled, the value is not
+    /// valid for the type, or the label is missing or too long.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn validate_contact_information(
+        &self,
+        contact_type: &str,
+        label: &Option<String>,
+        value: &str,
+    ) -> Result<(String, Option<String>), AppError> {
+        let contact_type = match self.get_contact_type(contact_type).await {
+            Ok(contact_type) if contact_type.is_enabled => contact_type,
+            Err(AppError::InternalError) => return Err(AppError::InternalError),
+            _ => {
+                return Err(AppError::BadRequest {
+                    error: Some("Invalid contact type".to_string()),
+                })
+            }
+        };
+
+        let value = contact_type.normalize(value).ok_or_else(|| {
+            let error = match ContactValueFormat::from_str(&contact_type.value_format) {
+                Some(ContactValueFormat::Email) => "Invalid email address".to_string(),
+                Some(ContactValueFormat::Phone) => {
+                    "Invalid phone number, use the international format starting with +".to_string()
+                }
+                Some(ContactValueFormat::Url) => {
+                    "Invalid URL, use an http or https link".to_string()
+                }
+                _ => format!("Invalid {} account", contact_type.label),
+            };
+
+            AppError::BadRequest { error: Some(error) }
+        })?;
+
+        if !contact_type.has_label {
+            return Ok((value, None));
+        }
+
+        match label.as_deref().map(str::trim) {
+            Some(label) if !label.is_empty() && label.chars().count() <= 50 => {
+                Ok((value, Some(label.to_string())))
+            }
+            _ => Err(AppError::BadRequest {
+                error: Some("Label must be between 1 and 50 characters".to_string()),
+            }),
+        }
+    }
+}
@@ -0,0 +1,780 @@
+use crate::models::custom_section::{
+    AuthCustomSectionEntryModel, AuthCustomSectionModel, PublicCustomSectionEntryModel,
+    PublicCustomSectionModel,
+};
+use crate::models::date::{deserialize_optional_date, ProfileDate};
+use crate::response::error_handling::AppError;
+use crate::routes::api::auth::profile::custom_section::SectionVisibility;
+use crate::services::project_service::is_http_url;
+use crate::{IdenoDBResult, IdenoPool};
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AddCustomSectionPayload {
+    pub title: String,
+    pub visibility: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdateCustomSectionPayload {
+    pub title: String,
+    pub visibility: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AddCustomSectionEntryPayload {
+    pub title: String,
+    pub subtitle: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub start_date: Option<ProfileDate>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub end_date: Option<ProfileDate>,
+    pub body: Option<String>,
+    pub link: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdateCustomSectionEntryPayload {
+    pub title: String,
+    pub subtitle: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub start_date: Option<ProfileDate>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub end_date: Option<ProfileDate>,
+    pub body: Option<String>,
+    pub link: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ReorderPayload {
+    pub ids: Vec<i32>,
+}
+
+#[derive(Clone)]
+pub struct CustomSectionService {
+    db_pool: IdenoPool,
+}
+
+impl CustomSectionService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        CustomSectionService { db_pool }
+    }
+
+    /// Asynchronously retrieves all custom sections of an authenticated user together with their entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the authenticated user whose custom sections are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `AuthCustomSectionModel` instances, including private sections,
+    /// ordered by their position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_authenticated_custom_sections(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<AuthCustomSectionModel>, AppError> {
+        let mut sections = sqlx::query_as::<_, AuthCustomSectionModel>(
+            "SELECT
+                id,
+                title,
+                position,
+                visibility
+              FROM custom_sections
+              WHERE user_id = ?
+              ORDER BY position, id",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        let entries = sqlx::query_as::<_, AuthCustomSectionEntryModel>(
+            "SELECT
+                custom_section_entries.id,
+                custom_section_entries.section_id,
+                custom_section_entries.title,
+                custom_section_entries.subtitle,
+                custom_section_entries.start_date,
+                custom_section_entries.end_date,
+                custom_section_entries.body,
+                custom_section_entries.link,
+                custom_section_entries.position
+              FROM custom_section_entries
+              JOIN custom_sections ON custom_sections.id = custom_section_entries.section_id
+              WHERE custom_sections.user_id = ?
+              ORDER BY custom_section_entries.position, custom_section_entries.id",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        for section in sections.iter_mut() {
+            section.entries = entries
+                .iter()
+                .filter(|entry| entry.section_id == section.id)
+                .cloned()
+                .collect();
+        }
+
+        Ok(sections)
+    }
+
+    /// Asynchronously retrieves the public custom sections of a user together with their entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose public custom sections are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `PublicCustomSectionModel` instances for all sections with public
+    /// visibility, ordered by their position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_public_custom_sections(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<PublicCustomSectionModel>, AppError> {
+        let mut sections = sqlx::query_as::<_, PublicCustomSectionModel>(
+            "SELECT
+                id,
+                title
+              FROM custom_sections
+              WHERE user_id = $1 AND visibility = 'public'
+              ORDER BY position, id",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        let entries = sqlx::query_as::<_, PublicCustomSectionEntryModel>(
+            "SELECT
+                custom_section_entries.section_id,
+                custom_section_entries.title,
+                custom_section_entries.subtitle,
+                custom_section_entries.start_date,
+                custom_section_entries.end_date,
+                custom_section_entries.body,
+                custom_section_entries.link
+              FROM custom_section_entries
+              JOIN custom_sections ON custom_sections.id = custom_section_entries.section_id
+              WHERE custom_sections.user_id = $1 AND custom_sections.visibility = 'public'
+              ORDER BY custom_section_entries.position, custom_section_entries.id",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        for section in sections.iter_mut() {
+            section.entries = entries
+                .iter()
+                .filter(|entry| entry.section_id == section.id)
+                .cloned()
+                .collect();
+        }
+
+        Ok(sections)
+    }
+
+    /// Asynchronously checks if a user owns a specific custom section.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user to check ownership.
+    /// * `section_id` - The ID of the custom section to check ownership against.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option<bool>`. If the user owns the custom section, it returns `Some(true)`,
+    /// if the user does not own the custom section, it returns `Some(false)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` with an error message if the custom section could not be checked.
+    ///
+    pub async fn user_owns_custom_section(
+        &self,
+        user_id: i32,
+        section_id: i32,
+    ) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM custom_sections WHERE id = $1 AND user_id = $2",
+        )
+        .bind(section_id)
+        .bind(user_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map_or_else(|_| None, |count| Some(count.0 > 0));
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Custom section not found".to_string(),
+            }),
+        }
+    }
+
+    /// Validates the title and visibility of a custom section.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The heading of the section.
+    /// * `visibility` - An optional reference to the visibility of the section.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the section is valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the title is empty or the visibility is invalid.
+    ///
+    pub fn validate_custom_section(
+        &self,
+        title: &str,
+        visibility: &Option<String>,
+    ) -> Result<bool, AppError> {
+        if title.trim().is_empty() {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid section title".to_string()),
+            });
+        }
+
+        if let Some(visibility) = visibility {
+            if SectionVisibility::from_str(visibility).is_none() {
+                return Err(AppError::BadRequest {
+                    error: Some("Invalid section visibility".to_string()),
+                });
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Validates the title and link of a custom section entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the entry.
+    /// * `link` - An optional reference to the link of the entry.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the entry is valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the title is empty or the link is not an http(s) URL.
+    ///
+    pub fn validate_custom_section_entry(
+        &self,
+        title: &str,
+        link: &Option<String>,
+    ) -> Result<bool, AppError> {
+        if title.trim().is_empty() {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid entry title".to_string()),
+            });
+        }
+
+        if let Some(link) = link {
+            if !link.is_empty() && !is_http_url(link) {
+                return Err(AppError::BadRequest {
+                    error: Some("Invalid entry link".to_string()),
+                });
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Asynchronously creates a new custom section for a user, placed after the existing sections.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the section is to be created.
+    /// * `payload` - An `AddCustomSectionPayload` containing the heading and visibility of the section.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created section if the operation is successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_custom_section(
+        &self,
+        user_id: i32,
+        payload: AddCustomSectionPayload,
+    ) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>(
+            "INSERT INTO custom_sections (user_id, title, visibility, position)
+              VALUES ($1, $2, $3, (SELECT COALESCE(MAX(position) + 1, 0) FROM custom_sections WHERE user_id = $1))
+              RETURNING id",
+        )
+        .bind(user_id)
+        .bind(payload.title)
+        .bind(payload.visibility.unwrap_or("public".to_string()))
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|id| id.0)
+    }
+
+    /// Asynchronously updates the heading and visibility of a custom section.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the section.
+    /// * `section_id` - The ID of the section to be updated.
+    /// * `payload` - An `UpdateCustomSectionPayload` containing the updated data for the section.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the update operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn update_custom_section(
+        &self,
+        user_id: i32,
+        section_id: i32,
+        payload: UpdateCustomSectionPayload,
+    ) -> Result<IdenoDBResult, AppError> {
+        sqlx::query(
+            "UPDATE custom_sections SET title = $1, visibility = COALESCE($2, visibility) WHERE id = $3 AND user_id = $4",
+        )
+        .bind(payload.title)
+        .bind(payload.visibility)
+        .bind(section_id)
+        .bind(user_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously deletes a custom section of a user together with all of its entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the section.
+    /// * `section_id` - The ID of the section to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the delete operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn delete_custom_section(
+        &self,
+        user_id: i32,
+        section_id: i32,
+    ) -> Result<IdenoDBResult, AppError> {
+        sqlx::query("DELETE FROM custom_sections WHERE id = $1 AND user_id = $2")
+            .bind(section_id)
+            .bind(user_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously changes the order of a user's custom sections.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the sections.
+    /// * `section_ids` - The IDs of all sections of the user in their new order.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the sections were reordered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` if the IDs do not match the sections of the user exactly.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn reorder_custom_sections(
+        &self,
+        user_id: i32,
+        section_ids: Vec<i32>,
+    ) -> Result<(), AppError> {
+        let mut existing_ids =
+            sqlx::query_as::<_, (i32,)>("SELECT id FROM custom_sections WHERE user_id = $1")
+                .bind(user_id)
+                .fetch_all(&self.db_pool)
+                .await
+                .map_err(|_| AppError::InternalError)?
+                .into_iter()
+                .map(|id| id.0)
+                .collect::<Vec<i32>>();
+
+        if !is_same_id_set(&mut existing_ids, &section_ids) {
+            return Err(AppError::BadRequest {
+                error: Some("Order must contain every section exactly once".to_string()),
+            });
+        }
+
+        let mut tx = self
+            .db_pool
+            .begin()
+            .await
+            .map_err(|_| AppError::InternalError)?;
+
+        for (position, section_id) in section_ids.into_iter().enumerate() {
+            sqlx::query("UPDATE custom_sections SET position = $1 WHERE id = $2 AND user_id = $3")
+                .bind(position as i32)
+                .bind(section_id)
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|_| AppError::InternalError)?;
+        }
+
+        tx.commit().await.map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously retrieves the count of custom sections associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose section count is to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the count of custom sections associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_custom_section_count(&self, user_id: i32) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM custom_sections WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|count| count.0)
+    }
+
+    /// Asynchronously retrieves the count of entries in a custom section.
+    ///
+    /// # Arguments
+    ///
+    /// * `section_id` - The ID of the section whose entry count is to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the count of entries in the section.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_custom_section_entry_count(&self, section_id: i32) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM custom_section_entries WHERE section_id = $1",
+        )
+        .bind(section_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|count| count.0)
+    }
+
+    /// Asynchronously creates a new entry in a custom section, placed after the existing entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the section.
+    /// * `section_id` - The ID of the section the entry is added to.
+    /// * `payload` - An `AddCustomSectionEntryPayload` containing the details of the entry.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created entry if the operation is successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the section does not belong to the user.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_custom_section_entry(
+        &self,
+        user_id: i32,
+        section_id: i32,
+        payload: AddCustomSectionEntryPayload,
+    ) -> Result<i64, AppError> {
+        let created = sqlx::query_as::<_, (i64,)>(
+            "INSERT INTO custom_section_entries (section_id, title, subtitle, start_date, end_date, body, link, position)
+              SELECT
+                id, $1, $2, $3, $4, $5, $6,
+                (SELECT COALESCE(MAX(position) + 1, 0) FROM custom_section_entries WHERE section_id = $7)
+              FROM custom_sections
+              WHERE id = $7 AND user_id = $8
+              RETURNING id",
+        )
+        .bind(payload.title)
+        .bind(payload.subtitle)
+        .bind(payload.start_date)
+        .bind(payload.end_date)
+        .bind(payload.body)
+        .bind(payload.link)
+        .bind(section_id)
+        .bind(user_id)
+        .fetch_optional(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        match created {
+            Some(id) => Ok(id.0),
+            None => Err(AppError::NotFound {
+                error: "Custom section not found".to_string(),
+            }),
+        }
+    }
+
+    /// Asynchronously updates an entry of a custom section.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the section.
+    /// * `section_id` - The ID of the section the entry belongs to.
+    /// * `entry_id` - The ID of the entry to be updated.
+    /// * `payload` - An `UpdateCustomSectionEntryPayload` containing the updated data for the entry.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the entry was updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the entry does not exist in a section of the user.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn update_custom_section_entry(
+        &self,
+        user_id: i32,
+        section_id: i32,
+        entry_id: i32,
+        payload: UpdateCustomSectionEntryPayload,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "UPDATE custom_section_entries
+              SET title = $1, subtitle = $2, start_date = $3, end_date = $4, body = $5, link = $6
+              WHERE id = $7
+                AND section_id = $8
+                AND section_id IN (SELECT id FROM custom_sections WHERE user_id = $9)",
+        )
+        .bind(payload.title)
+        .bind(payload.subtitle)
+        .bind(payload.start_date)
+        .bind(payload.end_date)
+        .bind(payload.body)
+        .bind(payload.link)
+        .bind(entry_id)
+        .bind(section_id)
+        .bind(user_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound {
+                error: "Custom section entry not found".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Asynchronously deletes an entry of a custom section.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the section.
+    /// * `section_id` - The ID of the section the entry belongs to.
+    /// * `entry_id` - The ID of the entry to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the entry was deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the entry does not exist in a section of the user.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn delete_custom_section_entry(
+        &self,
+        user_id: i32,
+        section_id: i32,
+        entry_id: i32,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "DELETE FROM custom_section_entries
+              WHERE id = $1
+                AND section_id = $2
+                AND section_id IN (SELECT id FROM custom_sections WHERE user_id = $3)",
+        )
+        .bind(entry_id)
+        .bind(section_id)
+        .bind(user_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound {
+                error: "Custom section entry not found".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Asynchronously changes the order of the entries in a custom section.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the section.
+    /// * `section_id` - The ID of the section whose entries are reordered.
+    /// * `entry_ids` - The IDs of all entries of the section in their new order.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the entries were reordered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` if the IDs do not match the entries of the section exactly.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn reorder_custom_section_entries(
+        &self,
+        user_id: i32,
+        section_id: i32,
+        entry_ids: Vec<i32>,
+    ) -> Result<(), AppError> {
+        let mut existing_ids = sqlx::query_as::<_, (i32,)>(
+            "SELECT custom_section_entries.id
+              FROM custom_section_entries
+              JOIN custom_sections ON custom_sections.id = custom_section_entries.section_id
+              WHERE custom_sections.id = $1 AND custom_sections.user_id = $2",
+        )
+        .bind(section_id)
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?
+        .into_iter()
+        .map(|id| id.0)
+        .collect::<Vec<i32>>();
+
+        if !is_same_id_set(&mut existing_ids, &entry_ids) {
+            return Err(AppError::BadRequest {
+                error: Some("Order must contain every entry exactly once".to_string()),
+            });
+        }
+
+        let mut tx = self
+            .db_pool
+            .begin()
+            .await
+            .map_err(|_| AppError::InternalError)?;
+
+        for (position, entry_id) in entry_ids.into_iter().enumerate() {
+            sqlx::query(
+                "UPDATE custom_section_entries SET position = $1 WHERE id = $2 AND section_id = $3",
+            )
+            .bind(position as i32)
+            .bind(entry_id)
+            .bind(section_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|_| AppError::InternalError)?;
+        }
+
+        tx.commit().await.map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously deletes a custom section from the database for administrative purposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `section_id` - The ID of the section to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), AppError>` indicating the outcome of the delete operation. If the section is successfully deleted, it returns `Ok(())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn admin_delete_custom_section(&self, section_id: i32) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM custom_sections WHERE id = $1")
+            .bind(section_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+
+    /// Asynchronously checks if a custom section exists in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `section_id` - The ID of the section to check for existence.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option<bool>`. If the section exists, it returns `Some(true)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` with an error message if the section could not be checked.
+    ///
+    pub async fn custom_section_exists(&self, section_id: i32) -> Result<Option<bool>, AppError> {
+        let flag =
+            sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM custom_sections WHERE id = $1")
+                .bind(section_id)
+                .fetch_one(&self.db_pool)
+                .await
+                .map_err(|_| AppError::InternalError)
+                .map_or_else(|_| None, |count| Some(count.0 > 0));
+
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Custom section not found".to_string(),
+            }),
+        }
+    }
+}
+
+/// Checks that a requested order contains every existing ID exactly once.
+fn is_same_id_set(existing_ids: &mut [i32], requested_ids: &[i32]) -> bool {
+    let mut requested_ids = requested_ids.to_vec();
+    existing_ids.sort_unstable();
+    requested_ids.sort_unstable();
+
+    existing_ids == requested_ids.as_slice()
+}
@@ -0,0 +1,742 @@
+use std::io::{Cursor, Write};
+
+use serde_json::{json, Map, Value};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Column, Row, TypeInfo, ValueRef};
+use zip::write::FileOptions;
+
+use crate::models::data_export::{DataExportModel, DataExportStatus};
+use crate::response::error_handling::AppError;
+use crate::IdenoPool;
+
+/// How long the download link of a finished export stays valid, as an SQLite date modifier.
+const DOWNLOAD_LINK_VALIDITY: &str = "+1 day";
+/// Exports are built by an in-memory task, so an export still pending after this long was lost, e.g. to a restart.
+const BUILD_TIMEOUT: &str = "-30 minutes";
+const MANIFEST_FILE: &str = "manifest.json";
+const MANIFEST_VERSION: i32 = 1;
+
+/// A file of the archive with the query selecting its records. The queries take the ID of the user as their only
+/// parameter. Files with a single record hold an object, the other files an array.
+struct ExportFile {
+    name: &'static str,
+    description: &'static str,
+    query: &'static str,
+    single: bool,
+}
+
+const EXPORT_FILES: &[ExportFile] = &[
+    ExportFile {
+        name: "account.json",
+        description: "The account, without the password hash",
+        query: "SELECT id, username, email, role, created_at FROM users WHERE id = $1",
+        single: true,
+    },
+    ExportFile {
+        name: "profile.json",
+        description: "The profile fields and the profile visibility",
+        query: "SELECT * FROM profiles WHERE user_id = $1",
+        single: true,
+    },
+    ExportFile {
+        name: "experiences.json",
+        description: "Work experiences",
+        query: "SELECT * FROM experiences WHERE user_id = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "experience_attestations.json",
+        description: "Attestations of experiences by their organizations",
+        query: "SELECT experience_attestations.*
+              FROM experience_attestations
+              JOIN experiences ON experiences.id = experience_attestations.experience_id
+              WHERE experiences.user_id = $1
+              ORDER BY experience_attestations.id",
+        single: false,
+    },
+    ExportFile {
+        name: "educations.json",
+        description: "Educations",
+        query: "SELECT * FROM educations WHERE user_id = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "certifications.json",
+        description: "Certifications, including imported badge assertions",
+        query: "SELECT * FROM certification WHERE user_id = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "contact_information.json",
+        description: "Contact entries, unmasked",
+        query: "SELECT * FROM contact_information WHERE user_id = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "contact_reveals.json",
+        description: "Reveals of masked contact entries by other viewers, anonymous viewers without a username",
+        query: "SELECT
+                contact_reveals.id,
+                contact_reveals.contact_information_id,
+                users.username AS viewer_username,
+                contact_reveals.created_at
+              FROM contact_reveals
+              LEFT JOIN users ON users.id = contact_reveals.viewer_id
+              WHERE contact_reveals.owner_id = $1
+              ORDER BY contact_reveals.id",
+        single: false,
+    },
+    ExportFile {
+        name: "skills.json",
+        description: "Skills with their proficiency",
+        query: "SELECT user_skills.id, skills.name, user_skills.proficiency, user_skills.created_at
+              FROM user_skills
+              JOIN skills ON skills.id = user_skills.skill_id
+              WHERE user_skills.user_id = $1
+              ORDER BY user_skills.id",
+        single: false,
+    },
+    ExportFile {
+        name: "skill_experiences.json",
+        description: "The experiences skills are linked to",
+        query: "SELECT user_skill_experiences.*
+              FROM user_skill_experiences
+              JOIN user_skills ON user_skills.id = user_skill_experiences.user_skill_id
+              WHERE user_skills.user_id = $1",
+        single: false,
+    },
+    ExportFile {
+        name: "projects.json",
+        description: "Projects",
+        query: "SELECT * FROM projects WHERE user_id = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "project_skills.json",
+        description: "The skills projects are linked to",
+        query: "SELECT project_skills.project_id, skills.name
+              FROM project_skills
+              JOIN projects ON projects.id = project_skills.project_id
+              JOIN skills ON skills.id = project_skills.skill_id
+              WHERE projects.user_id = $1",
+        single: false,
+    },
+    ExportFile {
+        name: "project_images.json",
+        description: "Links to the images of projects",
+        query: "SELECT project_images.*
+              FROM project_images
+              JOIN projects ON projects.id = project_images.project_id
+              WHERE projects.user_id = $1
+              ORDER BY project_images.id",
+        single: false,
+    },
+    ExportFile {
+        name: "languages.json",
+        description: "Languages",
+        query: "SELECT * FROM languages WHERE user_id = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "publications.json",
+        description: "Publications",
+        query: "SELECT * FROM publications WHERE user_id = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "patents.json",
+        description: "Patents",
+        query: "SELECT * FROM patents WHERE user_id = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "awards.json",
+        description: "Awards",
+        query: "SELECT * FROM awards WHERE user_id = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "custom_sections.json",
+        description: "Custom sections",
+        query: "SELECT * FROM custom_sections WHERE user_id = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "custom_section_entries.json",
+        description: "The entries of custom sections",
+        query: "SELECT custom_section_entries.*
+              FROM custom_section_entries
+              JOIN custom_sections ON custom_sections.id = custom_section_entries.section_id
+              WHERE custom_sections.user_id = $1
+              ORDER BY custom_section_entries.id",
+        single: false,
+    },
+    ExportFile {
+        name: "recommendations_written.json",
+        description: "Recommendations written for other users",
+        query: "SELECT recommendations.*, users.username AS recipient_username
+              FROM recommendations
+              JOIN users ON users.id = recommendations.recipient_id
+              WHERE recommendations.author_id = $1
+              ORDER BY recommendations.id",
+        single: false,
+    },
+    ExportFile {
+        name: "recommendations_received.json",
+        description: "Recommendations received from other users",
+        query: "SELECT recommendations.*, users.username AS author_username
+              FROM recommendations
+              JOIN users ON users.id = recommendations.author_id
+              WHERE recommendations.recipient_id = $1
+              ORDER BY recommendations.id",
+        single: false,
+    },
+    ExportFile {
+        name: "recommendation_requests.json",
+        description: "Recommendations requested from other users",
+        query: "SELECT recommendation_requests.id, users.username AS author_username, recommendation_requests.created_at
+              FROM recommendation_requests
+              JOIN users ON users.id = recommendation_requests.author_id
+              WHERE recommendation_requests.requester_id = $1
+              ORDER BY recommendation_requests.id",
+        single: false,
+    },
+    ExportFile {
+        name: "notifications.json",
+        description: "Notifications",
+        query: "SELECT * FROM notifications WHERE user_id = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "organizations_created.json",
+        description: "Organizations created by the user",
+        query: "SELECT * FROM organizations WHERE created_by = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "organization_roles.json",
+        description: "Roles in organizations",
+        query: "SELECT organization_roles.*, organizations.slug AS organization_slug
+              FROM organization_roles
+              JOIN organizations ON organizations.id = organization_roles.organization_id
+              WHERE organization_roles.user_id = $1
+              ORDER BY organization_roles.id",
+        single: false,
+    },
+    ExportFile {
+        name: "attestations_given.json",
+        description: "Attestations of other users' experiences signed by the user",
+        query: "SELECT * FROM experience_attestations WHERE attester_id = $1 ORDER BY id",
+        single: false,
+    },
+    ExportFile {
+        name: "data_exports.json",
+        description: "Requested data exports, without their archives and download links",
+        query: "SELECT id, status, created_at, completed_at, expires_at
+              FROM data_exports WHERE user_id = $1 ORDER BY id",
+        single: false,
+    },
+];
+
+/// Data Ideno does not store and that therefore is not part of the archive, with the reason given in the manifest.
+const NOT_INCLUDED: &[(&str, &str)] = &[
+    (
+        "sessions",
+        "Sessions are only kept in the memory of the server and are not stored.",
+    ),
+    (
+        "audit_events",
+        "Ideno keeps no audit log. The reveals of masked contact entries are in contact_reveals.json.",
+    ),
+    (
+        "uploaded_files",
+        "Ideno stores no uploaded files. Project images are links, see project_images.json.",
+    ),
+];
+
+/// Converts a database row into a JSON object, keeping the storage class of every column.
+fn row_to_json(row: &SqliteRow) -> Value {
+    let mut object = Map::new();
+
+    for (index, column) in row.columns().iter().enumerate() {
+        let value = match row.try_get_raw(index) {
+            Ok(raw) if raw.is_null() => Value::Null,
+            Ok(raw) => match raw.type_info().name() {
+                "INTEGER" => row
+                    .try_get_unchecked::<i64, _>(index)
+                    .map_or(Value::Null, Value::from),
+                "REAL" => row
+                    .try_get_unchecked::<f64, _>(index)
+                    .map_or(Value::Null, Value::from),
+                "BLOB" => row
+                    .try_get_unchecked::<Vec<u8>, _>(index)
+                    .map_or(Value::Null, |bytes| Value::from(hex::encode(bytes))),
+                _ => row
+                    .try_get_unchecked::<String, _>(index)
+                    .map_or(Value::Null, Value::from),
+            },
+            Err(_) => Value::Null,
+        };
+
+        object.insert(column.name().to_string(), value);
+    }
+
+    Value::Object(object)
+}
+
+/// Writes the files of a data export into a ZIP archive, preceded by a manifest listing them.
+///
+/// # Arguments
+///
+/// * `username` - The username of the user whose data is exported.
+/// * `generated_at` - The time the export was built.
+/// * `files` - The name, description and content of every file.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the ZIP archive.
+///
+/// # Errors
+///
+/// Returns an `AppError::InternalError` if the archive could not be written.
+///
+fn write_archive(
+    username: &str,
+    generated_at: &str,
+    files: &[(&str, &str, Value)],
+) -> Result<Vec<u8>, AppError> {
+    let manifest = json!({
+        "format": "ideno-data-export",
+        "version": MANIFEST_VERSION,
+        "username": username,
+        "generated_at": generated_at,
+        "files": files
+            .iter()
+            .map(|(name, description, content)| json!({
+                "name": name,
+                "description": description,
+                "records": match content {
+                    Value::Array(records) => records.len(),
+                    Value::Null => 0,
+                    _ => 1,
+                },
+            }))
+            .collect::<Vec<_>>(),
+        "not_included": NOT_INCLUDED
+            .iter()
+            .map(|(name, reason)| json!({ "name": name, "reason": reason }))
+            .collect::<Vec<_>>(),
+    });
+
+    let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    for (name, content) in std::iter::once((MANIFEST_FILE, &manifest))
+        .chain(files.iter().map(|(name, _, content)| (*name, content)))
+    {
+        let bytes = serde_json::to_vec_pretty(content).map_err(|_| AppError::InternalError)?;
+        archive
+            .start_file(name, options)
+            .and_then(|_| archive.write_all(&bytes).map_err(Into::into))
+            .map_err(|_| AppError::InternalError)?;
+    }
+
+    archive
+        .finish()
+        .map(Cursor::into_inner)
+        .map_err(|_| AppError::InternalError)
+}
+
+#[derive(Clone)]
+pub struct DataExportService {
+    db_pool: IdenoPool,
+}
+
+impl DataExportService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        DataExportService { db_pool }
+    }
+
+    /// Asynchronously records a new data export of a user, to be built in the background.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose data is exported.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the pending `DataExportModel` with the token of its download link.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::DataConflict` if an export of the user is still being built. Exports pending for longer
+    /// than the build timeout were lost and do not count.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_data_export(&self, user_id: i32) -> Result<DataExportModel, AppError> {
+        let pending = sqlx::query_as::<_, (i32,)>(
+            "SELECT COUNT(*) FROM data_exports WHERE user_id = $1 AND status = $2 AND created_at > datetime('now', $3)",
+        )
+        .bind(user_id)
+        .bind(DataExportStatus::Pending.as_str())
+        .bind(BUILD_TIMEOUT)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        if pending.0 > 0 {
+            return Err(AppError::DataConflict {
+                error: "A data export is already being prepared".to_string(),
+            });
+        }
+
+        let token = hex::encode(rand::random::<[u8; 32]>());
+
+        sqlx::query_as::<_, DataExportModel>(
+            "INSERT INTO data_exports (user_id, token, status) VALUES ($1, $2, $3)
+              RETURNING id, token, status, created_at, completed_at, expires_at",
+        )
+        .bind(user_id)
+        .bind(token)
+        .bind(DataExportStatus::Pending.as_str())
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously retrieves the number of data exports a user requested within the last day.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose exports are counted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of exports requested within the last day.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_recent_data_export_count(&self, user_id: i32) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM data_exports WHERE user_id = $1 AND created_at > datetime('now', '-1 day')",
+        )
+        .bind(user_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|count| count.0)
+    }
+
+    /// Asynchronously retrieves the data exports of a user whose download link has not expired, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose exports are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `DataExportModel`s of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_data_exports(&self, user_id: i32) -> Result<Vec<DataExportModel>, AppError> {
+        sqlx::query_as::<_, DataExportModel>(
+            "SELECT id, token, status, created_at, completed_at, expires_at
+              FROM data_exports
+              WHERE user_id = $1 AND (expires_at IS NULL OR expires_at > datetime('now'))
+              ORDER BY id DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously collects everything stored about a user into a ZIP archive of JSON files with a manifest.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose data is exported.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ZIP archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::UserNotFound` if the user does not exist.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database or writing the
+    /// archive.
+    ///
+    pub async fn build_archive(&self, user_id: i32) -> Result<Vec<u8>, AppError> {
+        let mut files = Vec::with_capacity(EXPORT_FILES.len());
+
+        for file in EXPORT_FILES.iter() {
+            let rows = sqlx::query(file.query)
+                .bind(user_id)
+                .fetch_all(&self.db_pool)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Error exporting {}: {}", file.name, e);
+                    AppError::InternalError
+                })?;
+
+            let content = match file.single {
+                true => rows.first().map_or(Value::Null, row_to_json),
+                false => Value::Array(rows.iter().map(row_to_json).collect()),
+            };
+            files.push((file.name, file.description, content));
+        }
+
+        let username = files[0].2["username"]
+            .as_str()
+            .ok_or(AppError::UserNotFound)?
+            .to_string();
+        let generated_at = sqlx::query_as::<_, (String,)>("SELECT datetime('now')")
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)?
+            .0;
+
+        write_archive(&username, &generated_at, &files)
+    }
+
+    /// Asynchronously stores the archive of a data export and starts the validity of its download link.
+    ///
+    /// # Arguments
+    ///
+    /// * `export_id` - The ID of the export.
+    /// * `archive` - The ZIP archive.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the archive was stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn complete_data_export(
+        &self,
+        export_id: i32,
+        archive: Vec<u8>,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE data_exports
+              SET status = $1, archive = $2, completed_at = datetime('now'), expires_at = datetime('now', $3)
+              WHERE id = $4",
+        )
+        .bind(DataExportStatus::Ready.as_str())
+        .bind(archive)
+        .bind(DOWNLOAD_LINK_VALIDITY)
+        .bind(export_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|_| ())
+    }
+
+    /// Asynchronously marks a data export as failed. Failed exports expire like finished ones, so they are
+    /// cleaned up and do not block new exports.
+    ///
+    /// # Arguments
+    ///
+    /// * `export_id` - The ID of the export.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the export was updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn fail_data_export(&self, export_id: i32) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE data_exports
+              SET status = $1, completed_at = datetime('now'), expires_at = datetime('now', $2)
+              WHERE id = $3",
+        )
+        .bind(DataExportStatus::Failed.as_str())
+        .bind(DOWNLOAD_LINK_VALIDITY)
+        .bind(export_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|_| ())
+    }
+
+    /// Asynchronously retrieves the archive of a finished data export by the token of its download link.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The token of the download link.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the username of the exported user and the ZIP archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if there is no finished export with the token or its link has expired.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_data_export_archive(
+        &self,
+        token: &str,
+    ) -> Result<(String, Vec<u8>), AppError> {
+        sqlx::query_as::<_, (String, Vec<u8>)>(
+            "SELECT users.username, data_exports.archive
+              FROM data_exports
+              JOIN users ON users.id = data_exports.user_id
+              WHERE data_exports.token = $1
+                AND data_exports.status = $2
+                AND data_exports.expires_at > datetime('now')",
+        )
+        .bind(token)
+        .bind(DataExportStatus::Ready.as_str())
+        .fetch_optional(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?
+        .ok_or(AppError::NotFound {
+            error: "Data export not found or expired".to_string(),
+        })
+    }
+
+    /// Asynchronously marks the data exports that are pending for longer than the build timeout as failed, so they
+    /// expire and are cleaned up like any other failed export.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of exports marked as failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn fail_stale_data_exports(&self) -> Result<u64, AppError> {
+        sqlx::query(
+            "UPDATE data_exports
+              SET status = $1, completed_at = datetime('now'), expires_at = datetime('now', $2)
+              WHERE status = $3 AND created_at <= datetime('now', $4)",
+        )
+        .bind(DataExportStatus::Failed.as_str())
+        .bind(DOWNLOAD_LINK_VALIDITY)
+        .bind(DataExportStatus::Pending.as_str())
+        .bind(BUILD_TIMEOUT)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|result| result.rows_affected())
+    }
+
+    /// Asynchronously deletes the data exports whose download link has expired, together with their archives.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of deleted exports.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn delete_expired_data_exports(&self) -> Result<u64, AppError> {
+        sqlx::query("DELETE FROM data_exports WHERE expires_at <= datetime('now')")
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|result| result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+    use crate::test_utils::{create_test_user, test_pool};
+
+    #[test]
+    fn test_write_archive() {
+        let files = [
+            ("account.json", "The account", json!({ "username": "ada" })),
+            (
+                "experiences.json",
+                "Work experiences",
+                json!([{ "id": 1 }, { "id": 2 }]),
+            ),
+            ("profile.json", "The profile", Value::Null),
+        ];
+
+        let archive = write_archive("ada", "2026-10-19 12:00:00", &files)
+            .ok()
+            .unwrap();
+        let mut archive = zip::ZipArchive::new(Cursor::new(archive)).unwrap();
+        let mut read = |name: &str| {
+            let mut content = String::new();
+            archive
+                .by_name(name)
+                .unwrap()
+                .read_to_string(&mut content)
+                .unwrap();
+            serde_json::from_str::<Value>(&content).unwrap()
+        };
+
+        let manifest = read(MANIFEST_FILE);
+        assert_eq!(manifest["username"], "ada");
+        assert_eq!(manifest["files"][1]["name"], "experiences.json");
+        assert_eq!(manifest["files"][0]["records"], 1);
+        assert_eq!(manifest["files"][1]["records"], 2);
+        assert_eq!(manifest["files"][2]["records"], 0);
+        assert_eq!(
+            manifest["not_included"].as_array().unwrap().len(),
+            NOT_INCLUDED.len()
+        );
+        assert_eq!(read("experiences.json")[1]["id"], 2);
+        assert_eq!(archive.len(), files.len() + 1);
+    }
+
+    #[test]
+    fn test_export_files() {
+        // Every file is named once and the account comes first, as its username names the archive
+        assert_eq!(EXPORT_FILES[0].name, "account.json");
+        assert!(!EXPORT_FILES[0].query.contains("password"));
+        for (index, file) in EXPORT_FILES.iter().enumerate() {
+            assert!(file.name.ends_with(".json"));
+            assert_ne!(file.name, MANIFEST_FILE);
+            assert!(EXPORT_FILES[..index]
+                .iter()
+                .all(|other| other.name != file.name));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stale_data_export() {
+        let pool = test_pool().await;
+        let user_id = create_test_user(&pool, "ada").await;
+        let service = DataExportService::new(pool.clone());
+
+        let export = service.create_data_export(user_id).await.ok().unwrap();
+        assert!(service.create_data_export(user_id).await.is_err());
+        assert_eq!(service.fail_stale_data_exports().await.ok().unwrap(), 0);
+
+        // An export whose build was lost no longer blocks new exports and is failed by the cleanup
+        sqlx::query("UPDATE data_exports SET created_at = datetime('now', '-1 hour') WHERE id = $1")
+            .bind(export.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(service.fail_stale_data_exports().await.ok().unwrap(), 1);
+        service.create_data_export(user_id).await.ok().unwrap();
+
+        let exports = service.get_data_exports(user_id).await.ok().unwrap();
+        assert_eq!(exports.len(), 2);
+        assert_eq!(exports[1].status, DataExportStatus::Failed.as_str());
+        assert!(exports[1].expires_at.is_some());
+        assert_eq!(service.get_recent_data_export_count(user_id).await.ok().unwrap(), 2);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::models::date::{deserialize_optional_date, ProfileDate};
 use crate::models::education::{AuthEducationModel, EducationModel, PublicEducationModel};
 use crate::response::error_handling::AppError;
 use crate::{IdenoDBResult, IdenoPool};
@@ -5,19 +6,25 @@ use crate::{IdenoDBResult, IdenoPool};
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct AddEducationPayload {
     pub school: String,
+    pub organization_id: Option<i32>,
     pub degree: Option<String>,
     pub field: Option<String>,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub start_date: Option<ProfileDate>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub end_date: Option<ProfileDate>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct UpdateEducationPayload {
     pub school: String,
+    pub organization_id: Option<i32>,
     pub degree: Option<String>,
     pub field: Option<String>,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub start_date: Option<ProfileDate>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub end_date: Option<ProfileDate>,
 }
 
 #[derive(Clone)]
@@ -50,15 +57,20 @@ impl EducationService {
     ) -> Result<Vec<AuthEducationModel>, AppError> {
         sqlx::query_as::<_, AuthEducationModel>(
             "SELECT
-                id,
+                educations.id,
                 school,
+                organization_id,
+                organizations.slug AS organization_slug,
                 degree,
                 field,
                 start_date,
-                end_date
+                end_date,
+                start_date_legacy,
+                end_date_legacy
               FROM educations
+              LEFT JOIN organizations ON organizations.id = educations.organization_id
               WHERE user_id = ?
-              ORDER BY created_at DESC",
+              ORDER BY educations.created_at DESC",
         )
         .bind(user_id)
         .fetch_all(&self.db_pool)
@@ -90,13 +102,15 @@ impl EducationService {
         sqlx::query_as::<_, PublicEducationModel>(
             "SELECT
                 school,
+                organizations.slug AS organization_slug,
                 degree,
                 field,
                 start_date,
                 end_date
               FROM educations
+              LEFT JOIN organizations ON organizations.id = educations.organization_id
               WHERE user_id = $1
-              ORDER BY created_at DESC
+              ORDER BY educations.created_at DESC
               LIMIT $2",
         )
         .bind(user_id)
@@ -197,12 +211,13 @@ impl EducationService {
         education_id: i32,
         payload: UpdateEducationPayload,
     ) -> Result<IdenoDBResult, AppError> {
-        sqlx::query("UPDATE educations SET school = $1, degree = $2, field = $3, start_date = $4, end_date = $5 WHERE id = $6 AND user_id = $7")
+        sqlx::query("UPDATE educations SET school = $1, degree = $2, field = $3, start_date = $4, end_date = $5, start_date_legacy = CASE WHEN $4 IS NULL THEN start_date_legacy END, end_date_legacy = CASE WHEN $5 IS NULL THEN end_date_legacy END, organization_id = $6 WHERE id = $7 AND user_id = $8")
             .bind(payload.school)
             .bind(payload.degree)
             .bind(payload.field)
             .bind(payload.start_date)
             .bind(payload.end_date)
+            .bind(payload.organization_id)
             .bind(education_id)
             .bind(user_id)
             .execute(&self.db_pool)
@@ -230,19 +245,49 @@ impl EducationService {
         user_id: i32,
         payload: AddEducationPayload,
     ) -> Result<i64, AppError> {
-        sqlx::query_as::<_, (i64,)>("INSERT INTO educations (user_id, school, degree, field, start_date, end_date) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id")
+        sqlx::query_as::<_, (i64,)>("INSERT INTO educations (user_id, school, degree, field, start_date, end_date, organization_id) VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING id")
             .bind(user_id)
             .bind(payload.school)
             .bind(payload.degree)
             .bind(payload.field)
             .bind(payload.start_date)
             .bind(payload.end_date)
+            .bind(payload.organization_id)
             .fetch_one(&self.db_pool)
             .await
             .map_err(|_| AppError::InternalError)
             .map(|id| id.0)
     }
 
+    /// Validates that the end date of an education does not come before its start date.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` - An optional reference to the start date.
+    /// * `end_date` - An optional reference to the end date.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the dates are valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the end date is before the start date.
+    ///
+    pub fn validate_education_dates(
+        &self,
+        start_date: &Option<ProfileDate>,
+        end_date: &Option<ProfileDate>,
+    ) -> Result<bool, AppError> {
+        if !ProfileDate::is_valid_range(start_date, end_date) {
+            return Err(AppError::BadRequest {
+                error: Some("End date must not be before start date".to_string()),
+            });
+        }
+
+        Ok(true)
+    }
+
     /// Asynchronously retrieves the count of educations associated with a user from the database.
     ///
     /// # Arguments
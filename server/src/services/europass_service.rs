@@ -0,0 +1,892 @@
+use std::collections::HashMap;
+use std::io::Write;
+
+use quick_xml::events::{BytesDecl, BytesText, Event};
+use quick_xml::Writer;
+
+use crate::models::contact_type::{ContactTypeModel, ContactValueFormat};
+use crate::models::date::ProfileDate;
+use crate::models::resume::ProfileExportModel;
+use crate::response::error_handling::AppError;
+
+const EUROPASS_NAMESPACE: &str = "http://europass.cedefop.europa.eu/Europass";
+const EUROPASS_XSD_VERSION: &str = "V3.4";
+
+type XmlResult = Result<(), quick_xml::Error>;
+
+fn non_empty(value: Option<&String>) -> Option<&str> {
+    value
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+fn text_element<W: Write>(writer: &mut Writer<W>, name: &str, text: &str) -> XmlResult {
+    writer
+        .create_element(name)
+        .write_text_content(BytesText::new(text))?;
+
+    Ok(())
+}
+
+/// Writes an element holding a `Code` and an optional `Label`, the way Europass writes values from its vocabularies.
+fn code_element<W: Write>(
+    writer: &mut Writer<W>,
+    name: &str,
+    code: Option<&str>,
+    label: Option<&str>,
+) -> XmlResult {
+    writer.create_element(name).write_inner_content(|writer| {
+        if let Some(code) = code {
+            text_element(writer, "Code", code)?;
+        }
+        if let Some(label) = label {
+            text_element(writer, "Label", label)?;
+        }
+
+        Ok::<(), quick_xml::Error>(())
+    })?;
+
+    Ok(())
+}
+
+/// Writes a date as a Europass date element, with the month and day in the `--MM` and `---DD` formats of XML
+/// Schema.
+fn date_element<W: Write>(writer: &mut Writer<W>, name: &str, date: &ProfileDate) -> XmlResult {
+    let year = date.year().to_string();
+    let month = date.month().map(|month| format!("--{:02}", month));
+    let day = date.day().map(|day| format!("---{:02}", day));
+
+    let mut element = writer
+        .create_element(name)
+        .with_attribute(("year", year.as_str()));
+    if let Some(month) = month.as_deref() {
+        element = element.with_attribute(("month", month));
+    }
+    if let Some(day) = day.as_deref() {
+        element = element.with_attribute(("day", day));
+    }
+    element.write_empty()?;
+
+    Ok(())
+}
+
+fn period_element<W: Write>(
+    writer: &mut Writer<W>,
+    start: Option<ProfileDate>,
+    end: Option<ProfileDate>,
+    is_current: bool,
+) -> XmlResult {
+    if start.is_none() && end.is_none() && !is_current {
+        return Ok(());
+    }
+
+    writer
+        .create_element("Period")
+        .write_inner_content(|writer| {
+            if let Some(start) = start {
+                date_element(writer, "From", &start)?;
+            }
+            match (end, is_current) {
+                (_, true) => text_element(writer, "Current", "true")?,
+                (Some(end), false) => date_element(writer, "To", &end)?,
+                (None, false) => {}
+            }
+
+            Ok::<(), quick_xml::Error>(())
+        })?;
+
+    Ok(())
+}
+
+fn write_contact_info<W: Write>(
+    writer: &mut Writer<W>,
+    export: &ProfileExportModel,
+    contact_types: &HashMap<String, ContactTypeModel>,
+) -> XmlResult {
+    let profile = &export.profile;
+    let city = non_empty(profile.city.as_ref());
+    let country = non_empty(profile.country.as_ref());
+
+    let mut email = None;
+    let mut telephones = Vec::new();
+    let mut websites = Vec::new();
+    let mut instant_messaging = Vec::new();
+    for entry in export
+        .contact_information
+        .iter()
+        .filter(|entry| !entry.masked)
+    {
+        let contact_type = contact_types.get(&entry.type_field);
+        let label = non_empty(entry.label.as_ref())
+            .map(str::to_string)
+            .or_else(|| contact_type.map(|contact_type| contact_type.label.clone()));
+
+        match contact_type
+            .and_then(|contact_type| ContactValueFormat::from_str(&contact_type.value_format))
+        {
+            Some(ContactValueFormat::Email) => {
+                email.get_or_insert(entry.value.as_str());
+            }
+            Some(ContactValueFormat::Phone) => telephones.push(entry.value.as_str()),
+            Some(ContactValueFormat::Matrix) | Some(ContactValueFormat::Fediverse) => {
+                instant_messaging.push((entry.value.as_str(), label))
+            }
+            _ => {
+                if let Some(url) = entry.url.as_deref() {
+                    websites.push(url);
+                }
+            }
+        }
+    }
+
+    if city.is_none()
+        && country.is_none()
+        && email.is_none()
+        && telephones.is_empty()
+        && websites.is_empty()
+        && instant_messaging.is_empty()
+    {
+        return Ok(());
+    }
+
+    writer
+        .create_element("ContactInfo")
+        .write_inner_content(|writer| {
+            if city.is_some() || country.is_some() {
+                writer
+                    .create_element("Address")
+                    .write_inner_content(|writer| {
+                        writer
+                            .create_element("Contact")
+                            .write_inner_content(|writer| {
+                                if let Some(city) = city {
+                                    text_element(writer, "Municipality", city)?;
+                                }
+                                if let Some(country) = country {
+                                    match country.len() == 2
+                                        && country.chars().all(|c| c.is_ascii_alphabetic())
+                                    {
+                                        true => code_element(
+                                            writer,
+                                            "Country",
+                                            Some(&country.to_ascii_uppercase()),
+                                            None,
+                                        )?,
+                                        false => {
+                                            code_element(writer, "Country", None, Some(country))?
+                                        }
+                                    }
+                                }
+
+                                Ok::<(), quick_xml::Error>(())
+                            })?;
+
+                        Ok::<(), quick_xml::Error>(())
+                    })?;
+            }
+            if let Some(email) = email {
+                writer
+                    .create_element("Email")
+                    .write_inner_content(|writer| text_element(writer, "Contact", email))?;
+            }
+            if !telephones.is_empty() {
+                writer
+                    .create_element("TelephoneList")
+                    .write_inner_content(|writer| {
+                        for telephone in telephones.iter() {
+                            writer
+                                .create_element("Telephone")
+                                .write_inner_content(|writer| {
+                                    text_element(writer, "Contact", telephone)
+                                })?;
+                        }
+
+                        Ok::<(), quick_xml::Error>(())
+                    })?;
+            }
+            if !websites.is_empty() {
+                writer
+                    .create_element("WebsiteList")
+                    .write_inner_content(|writer| {
+                        for website in websites.iter() {
+                            writer
+                                .create_element("Website")
+                                .write_inner_content(|writer| {
+                                    text_element(writer, "Contact", website)
+                                })?;
+                        }
+
+                        Ok::<(), quick_xml::Error>(())
+                    })?;
+            }
+            if !instant_messaging.is_empty() {
+                writer
+                    .create_element("InstantMessagingList")
+                    .write_inner_content(|writer| {
+                        for (contact, label) in instant_messaging.iter() {
+                            writer
+                                .create_element("InstantMessaging")
+                                .write_inner_content(|writer| {
+                                    text_element(writer, "Contact", contact)?;
+                                    if let Some(label) = label {
+                                        code_element(writer, "Use", None, Some(label))?;
+                                    }
+
+                                    Ok::<(), quick_xml::Error>(())
+                                })?;
+                        }
+
+                        Ok::<(), quick_xml::Error>(())
+                    })?;
+            }
+
+            Ok::<(), quick_xml::Error>(())
+        })?;
+
+    Ok(())
+}
+
+fn write_work_experiences<W: Write>(
+    writer: &mut Writer<W>,
+    export: &ProfileExportModel,
+) -> XmlResult {
+    if export.experiences.is_empty() {
+        return Ok(());
+    }
+
+    writer
+        .create_element("WorkExperienceList")
+        .write_inner_content(|writer| {
+            for experience in export.experiences.iter() {
+                writer
+                    .create_element("WorkExperience")
+                    .write_inner_content(|writer| {
+                        period_element(
+                            writer,
+                            experience.start_date,
+                            experience.end_date,
+                            experience.is_current,
+                        )?;
+                        code_element(writer, "Position", None, Some(&experience.title))?;
+                        if let Some(description) = non_empty(experience.description.as_ref()) {
+                            text_element(writer, "Activities", description)?;
+                        }
+                        writer
+                            .create_element("Employer")
+                            .write_inner_content(|writer| {
+                                text_element(writer, "Name", &experience.company)
+                            })?;
+
+                        Ok::<(), quick_xml::Error>(())
+                    })?;
+            }
+
+            Ok::<(), quick_xml::Error>(())
+        })?;
+
+    Ok(())
+}
+
+fn write_educations<W: Write>(writer: &mut Writer<W>, export: &ProfileExportModel) -> XmlResult {
+    if export.educations.is_empty() {
+        return Ok(());
+    }
+
+    writer
+        .create_element("EducationList")
+        .write_inner_content(|writer| {
+            for education in export.educations.iter() {
+                let start = education.start_date;
+                let end = education.end_date;
+                let degree = non_empty(education.degree.as_ref());
+                let field = non_empty(education.field.as_ref());
+
+                writer
+                    .create_element("Education")
+                    .write_inner_content(|writer| {
+                        period_element(writer, start, end, false)?;
+                        if let Some(title) = degree.or(field) {
+                            text_element(writer, "Title", title)?;
+                        }
+                        if let (Some(_), Some(field)) = (degree, field) {
+                            text_element(writer, "Skills", field)?;
+                        }
+                        writer
+                            .create_element("Organisation")
+                            .write_inner_content(|writer| {
+                                text_element(writer, "Name", &education.school)
+                            })?;
+
+                        Ok::<(), quick_xml::Error>(())
+                    })?;
+            }
+
+            Ok::<(), quick_xml::Error>(())
+        })?;
+
+    Ok(())
+}
+
+/// Writes the languages of a profile, with native languages as mother tongues and the CEFR level of the other
+/// languages for each of the five skills of the Europass language passport.
+fn write_languages<W: Write>(writer: &mut Writer<W>, export: &ProfileExportModel) -> XmlResult {
+    if export.languages.is_empty() {
+        return Ok(());
+    }
+
+    let (mother_tongues, foreign_languages): (Vec<_>, Vec<_>) = export
+        .languages
+        .iter()
+        .partition(|language| language.level == "Native");
+
+    writer
+        .create_element("Skills")
+        .write_inner_content(|writer| {
+            writer
+                .create_element("Linguistic")
+                .write_inner_content(|writer| {
+                    if !mother_tongues.is_empty() {
+                        writer
+                            .create_element("MotherTongueList")
+                            .write_inner_content(|writer| {
+                                for language in mother_tongues.iter() {
+                                    writer.create_element("MotherTongue").write_inner_content(
+                                        |writer| {
+                                            let code = language.language_code.to_lowercase();
+                                            code_element(writer, "Description", Some(&code), None)
+                                        },
+                                    )?;
+                                }
+
+                                Ok::<(), quick_xml::Error>(())
+                            })?;
+                    }
+                    if !foreign_languages.is_empty() {
+                        writer
+                            .create_element("ForeignLanguageList")
+                            .write_inner_content(|writer| {
+                                for language in foreign_languages.iter() {
+                                    writer
+                                        .create_element("ForeignLanguage")
+                                        .write_inner_content(|writer| {
+                                            let code = language.language_code.to_lowercase();
+                                            code_element(writer, "Description", Some(&code), None)?;
+                                            writer
+                                                .create_element("ProficiencyLevel")
+                                                .write_inner_content(|writer| {
+                                                    for skill in [
+                                                        "Listening",
+                                                        "Reading",
+                                                        "SpokenInteraction",
+                                                        "SpokenProduction",
+                                                        "Writing",
+                                                    ] {
+                                                        text_element(
+                                                            writer,
+                                                            skill,
+                                                            &language.level,
+                                                        )?;
+                                                    }
+
+                                                    Ok::<(), quick_xml::Error>(())
+                                                })?;
+
+                                            Ok::<(), quick_xml::Error>(())
+                                        })?;
+                                }
+
+                                Ok::<(), quick_xml::Error>(())
+                            })?;
+                    }
+
+                    Ok::<(), quick_xml::Error>(())
+                })?;
+
+            Ok::<(), quick_xml::Error>(())
+        })?;
+
+    Ok(())
+}
+
+/// Returns the achievements of a profile as their title code, title label and description.
+///
+/// Certifications and projects use the matching codes of the Europass achievement vocabulary, the summary and the
+/// entries of custom sections only a label.
+///
+fn achievements(export: &ProfileExportModel) -> Vec<(Option<&'static str>, String, String)> {
+    let mut achievements = Vec::new();
+
+    if let Some(bio) = non_empty(export.profile.bio.as_ref()) {
+        achievements.push((None, "Summary".to_string(), bio.to_string()));
+    }
+
+    for certification in export.certifications.iter() {
+        let mut lines = vec![format!(
+            "{}, {}",
+            certification.name, certification.organization
+        )];
+        if let Some(issue_date) = certification.issue_date {
+            lines.push(format!("Issued {}", issue_date));
+        }
+        if let Some(expiration_date) = certification.expiration_date {
+            lines.push(format!("Expires {}", expiration_date));
+        }
+        lines.extend(non_empty(certification.credential_url.as_ref()).map(str::to_string));
+
+        achievements.push((
+            Some("certifications"),
+            "Certifications".to_string(),
+            lines.join("\n"),
+        ));
+    }
+
+    for project in export.projects.iter() {
+        let lines: Vec<&str> = [
+            Some(project.name.as_str()),
+            non_empty(project.role.as_ref()),
+            non_empty(project.description.as_ref()),
+            non_empty(project.url.as_ref()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        achievements.push((Some("projects"), "Projects".to_string(), lines.join("\n")));
+    }
+
+    for section in export.custom_sections.iter() {
+        for entry in section.entries.iter() {
+            let lines: Vec<&str> = [
+                Some(entry.title.as_str()),
+                non_empty(entry.subtitle.as_ref()),
+                non_empty(entry.body.as_ref()),
+                non_empty(entry.link.as_ref()),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+
+            achievements.push((None, section.title.clone(), lines.join("\n")));
+        }
+    }
+
+    achievements
+}
+
+fn write_achievements<W: Write>(writer: &mut Writer<W>, export: &ProfileExportModel) -> XmlResult {
+    let achievements = achievements(export);
+    if achievements.is_empty() {
+        return Ok(());
+    }
+
+    writer
+        .create_element("AchievementList")
+        .write_inner_content(|writer| {
+            for (code, label, description) in achievements.iter() {
+                writer
+                    .create_element("Achievement")
+                    .write_inner_content(|writer| {
+                        code_element(writer, "Title", *code, Some(label))?;
+                        text_element(writer, "Description", description)
+                    })?;
+            }
+
+            Ok::<(), quick_xml::Error>(())
+        })?;
+
+    Ok(())
+}
+
+fn write_skills_passport<W: Write>(
+    writer: &mut Writer<W>,
+    export: &ProfileExportModel,
+    contact_types: &HashMap<String, ContactTypeModel>,
+) -> XmlResult {
+    let profile = &export.profile;
+    let created_at = format!("{}T00:00:00.000Z", ProfileDate::today());
+
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    writer
+        .create_element("SkillsPassport")
+        .with_attribute(("xmlns", EUROPASS_NAMESPACE))
+        .with_attribute(("locale", "en"))
+        .write_inner_content(|writer| {
+            writer
+                .create_element("DocumentInfo")
+                .write_inner_content(|writer| {
+                    text_element(writer, "DocumentType", "ECV")?;
+                    text_element(writer, "CreationDate", &created_at)?;
+                    text_element(writer, "LastUpdateDate", &created_at)?;
+                    text_element(writer, "XSDVersion", EUROPASS_XSD_VERSION)?;
+                    text_element(writer, "Generator", "Ideno")
+                })?;
+
+            writer
+                .create_element("LearnerInfo")
+                .write_inner_content(|writer| {
+                    writer
+                        .create_element("Identification")
+                        .write_inner_content(|writer| {
+                            writer
+                                .create_element("PersonName")
+                                .write_inner_content(|writer| {
+                                    if let Some(first_name) = non_empty(profile.first_name.as_ref())
+                                    {
+                                        text_element(writer, "FirstName", first_name)?;
+                                    }
+                                    if let Some(last_name) = non_empty(profile.last_name.as_ref()) {
+                                        text_element(writer, "Surname", last_name)?;
+                                    }
+
+                                    Ok::<(), quick_xml::Error>(())
+                                })?;
+
+                            write_contact_info(writer, export, contact_types)
+                        })?;
+
+                    if let Some(headline) = non_empty(profile.headline.as_ref()) {
+                        writer
+                            .create_element("Headline")
+                            .write_inner_content(|writer| {
+                                code_element(writer, "Type", Some("position"), Some("Position"))?;
+                                code_element(writer, "Description", None, Some(headline))
+                            })?;
+                    }
+
+                    write_work_experiences(writer, export)?;
+                    write_educations(writer, export)?;
+                    write_languages(writer, export)?;
+                    write_achievements(writer, export)
+                })?;
+
+            Ok::<(), quick_xml::Error>(())
+        })?;
+
+    Ok(())
+}
+
+/// Exports a profile as a Europass CV in the Europass XML format, see
+/// <https://europass.cedefop.europa.eu/europass/about/europass-xml>.
+///
+/// Work experiences, educations and languages map onto their Europass sections. Certifications, projects, the
+/// summary and custom sections become achievements. Masked contact entries are left out.
+///
+/// # Arguments
+///
+/// * `export` - The parts of the profile visible to the viewer.
+/// * `contact_types` - The contact types by name.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the Europass XML document.
+///
+/// # Errors
+///
+/// Returns an `AppError::InternalError` if the document could not be written.
+///
+pub fn export_europass(
+    export: &ProfileExportModel,
+    contact_types: &HashMap<String, ContactTypeModel>,
+) -> Result<String, AppError> {
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+
+    write_skills_passport(&mut writer, export, contact_types)
+        .map_err(|_| AppError::InternalError)?;
+
+    String::from_utf8(writer.into_inner()).map_err(|_| AppError::InternalError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::certification::{CertificationStatus, PublicCertificationModel};
+    use crate::models::contact_information::PublicContactInformationModel;
+    use crate::models::education::PublicEducationModel;
+    use crate::models::experience::PublicExperienceModel;
+    use crate::models::language::PublicLanguageModel;
+    use crate::models::profile::PublicProfileModel;
+
+    /// The child elements Europass XML allows for the elements that are written, in the order of the XML schema.
+    const CONTENT_MODELS: &[(&str, &[&str])] = &[
+        ("SkillsPassport", &["DocumentInfo", "LearnerInfo"]),
+        (
+            "DocumentInfo",
+            &[
+                "DocumentType",
+                "CreationDate",
+                "LastUpdateDate",
+                "XSDVersion",
+                "Generator",
+                "Comment",
+            ],
+        ),
+        (
+            "LearnerInfo",
+            &[
+                "Identification",
+                "Headline",
+                "WorkExperienceList",
+                "EducationList",
+                "Skills",
+                "AchievementList",
+                "Documentation",
+            ],
+        ),
+        (
+            "Identification",
+            &["PersonName", "ContactInfo", "Demographics", "Photo"],
+        ),
+        ("PersonName", &["Title", "FirstName", "Surname"]),
+        (
+            "ContactInfo",
+            &[
+                "Address",
+                "Email",
+                "TelephoneList",
+                "WebsiteList",
+                "InstantMessagingList",
+            ],
+        ),
+        ("Address", &["Contact"]),
+        (
+            "Contact",
+            &[
+                "AddressLine",
+                "AddressLine2",
+                "PostalCode",
+                "Municipality",
+                "Country",
+            ],
+        ),
+        ("Country", &["Code", "Label"]),
+        ("Email", &["Contact"]),
+        ("TelephoneList", &["Telephone"]),
+        ("Telephone", &["Contact", "Use"]),
+        ("WebsiteList", &["Website"]),
+        ("Website", &["Contact", "Use"]),
+        ("InstantMessagingList", &["InstantMessaging"]),
+        ("InstantMessaging", &["Contact", "Use"]),
+        ("Use", &["Code", "Label"]),
+        ("Headline", &["Type", "Description"]),
+        ("Type", &["Code", "Label"]),
+        ("WorkExperienceList", &["WorkExperience"]),
+        (
+            "WorkExperience",
+            &["Period", "Position", "Activities", "Employer"],
+        ),
+        ("Period", &["From", "To", "Current"]),
+        ("Position", &["Code", "Label"]),
+        ("Employer", &["Name", "ContactInfo", "Sector"]),
+        ("EducationList", &["Education"]),
+        (
+            "Education",
+            &[
+                "Period",
+                "Title",
+                "Skills",
+                "Organisation",
+                "Level",
+                "Field",
+            ],
+        ),
+        ("Organisation", &["Name", "ContactInfo"]),
+        ("Skills", &["Linguistic"]),
+        ("Linguistic", &["MotherTongueList", "ForeignLanguageList"]),
+        ("MotherTongueList", &["MotherTongue"]),
+        ("MotherTongue", &["Description"]),
+        ("ForeignLanguageList", &["ForeignLanguage"]),
+        ("ForeignLanguage", &["Description", "ProficiencyLevel"]),
+        ("Description", &["Code", "Label"]),
+        (
+            "ProficiencyLevel",
+            &[
+                "Listening",
+                "Reading",
+                "SpokenInteraction",
+                "SpokenProduction",
+                "Writing",
+            ],
+        ),
+        ("AchievementList", &["Achievement"]),
+        ("Achievement", &["Title", "Description"]),
+        ("Title", &["Code", "Label"]),
+    ];
+
+    /// Checks that the children of an element are allowed by its content model and in the order of the model,
+    /// recursing into elements with children. Elements with the same name but a different content model, like the
+    /// text `Description` of an achievement, are told apart by having no child elements.
+    fn check_structure(node: roxmltree::Node) {
+        let name = node.tag_name().name();
+        let children: Vec<&str> = node
+            .children()
+            .filter(|child| child.is_element())
+            .map(|child| child.tag_name().name())
+            .collect();
+        if children.is_empty() {
+            return;
+        }
+
+        let model = CONTENT_MODELS
+            .iter()
+            .find(|(element, _)| *element == name)
+            .map(|(_, model)| *model)
+            .unwrap_or_else(|| panic!("{} may not have child elements", name));
+        let positions: Vec<usize> = children
+            .iter()
+            .map(|child| {
+                model
+                    .iter()
+                    .position(|allowed| allowed == child)
+                    .unwrap_or_else(|| panic!("{} may not contain {}", name, child))
+            })
+            .collect();
+        assert!(
+            positions.windows(2).all(|pair| pair[0] <= pair[1]),
+            "children of {} are out of order: {:?}",
+            name,
+            children
+        );
+
+        for child in node.children().filter(|child| child.is_element()) {
+            assert_eq!(child.tag_name().namespace(), Some(EUROPASS_NAMESPACE));
+            check_structure(child);
+        }
+    }
+
+    fn export() -> ProfileExportModel {
+        ProfileExportModel {
+            profile: PublicProfileModel {
+                first_name: Some("Ada".to_string()),
+                last_name: Some("Lovelace".to_string()),
+                pronouns: None,
+                headline: Some("Analyst & programmer".to_string()),
+                country: Some("gb".to_string()),
+                city: Some("London".to_string()),
+                bio: Some("Poetical science".to_string()),
+            },
+            experiences: vec![PublicExperienceModel {
+                id: 1,
+                company: "Analytical Engines".to_string(),
+                organization_slug: None,
+                title: "Programmer".to_string(),
+                start_date: ProfileDate::parse("1842-09-14"),
+                end_date: None,
+                is_current: true,
+                duration_months: None,
+                exp_type: None,
+                description: Some("Wrote <the> first program".to_string()),
+                verified: false,
+            }],
+            educations: vec![PublicEducationModel {
+                school: "Home schooling".to_string(),
+                organization_slug: None,
+                degree: Some("Private tuition".to_string()),
+                field: Some("Mathematics".to_string()),
+                start_date: ProfileDate::parse("1820"),
+                end_date: None,
+            }],
+            certifications: vec![PublicCertificationModel {
+                name: "Difference Engine Operator".to_string(),
+                organization: "Babbage & Co".to_string(),
+                issue_date: ProfileDate::parse("1833-06"),
+                expiration_date: None,
+                status: CertificationStatus::Valid,
+                credential_id: None,
+                credential_url: None,
+                credential_url_status: None,
+                badge_verification: None,
+            }],
+            contact_information: vec![
+                PublicContactInformationModel {
+                    id: 1,
+                    type_field: "email".to_string(),
+                    label: None,
+                    value: "ada@example.org".to_string(),
+                    url: Some("mailto:ada@example.org".to_string()),
+                    visibility: "public".to_string(),
+                    masked: false,
+                },
+                PublicContactInformationModel {
+                    id: 2,
+                    type_field: "phone".to_string(),
+                    label: None,
+                    value: "+44******90".to_string(),
+                    url: None,
+                    visibility: "masked".to_string(),
+                    masked: true,
+                },
+            ],
+            projects: vec![],
+            languages: vec![
+                PublicLanguageModel {
+                    language_code: "en".to_string(),
+                    level: "Native".to_string(),
+                },
+                PublicLanguageModel {
+                    language_code: "FR".to_string(),
+                    level: "B2".to_string(),
+                },
+            ],
+            custom_sections: vec![],
+        }
+    }
+
+    #[test]
+    fn test_export_europass() {
+        let contact_types = HashMap::from([(
+            "email".to_string(),
+            ContactTypeModel {
+                name: "email".to_string(),
+                label: "Email".to_string(),
+                value_format: "email".to_string(),
+                pattern: None,
+                hosts: None,
+                path_prefix: None,
+                url_template: None,
+                case_sensitive: false,
+                has_label: false,
+                is_enabled: true,
+            },
+        )]);
+
+        let xml = export_europass(&export(), &contact_types).ok().unwrap();
+        let document = roxmltree::Document::parse(&xml).unwrap();
+        let root = document.root_element();
+
+        assert_eq!(root.tag_name().name(), "SkillsPassport");
+        assert_eq!(root.tag_name().namespace(), Some(EUROPASS_NAMESPACE));
+        check_structure(root);
+
+        let find = |name: &str| {
+            document
+                .descendants()
+                .filter(|node| node.tag_name().name() == name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(find("Surname")[0].text(), Some("Lovelace"));
+        assert_eq!(
+            find("Country")[0]
+                .children()
+                .find(|node| node.is_element())
+                .unwrap()
+                .text(),
+            Some("GB")
+        );
+        assert_eq!(find("Email").len(), 1);
+        // Masked entries are left out
+        assert!(find("TelephoneList").is_empty());
+        let from = find("From")[0];
+        assert_eq!(from.attribute("year"), Some("1842"));
+        assert_eq!(from.attribute("month"), Some("--09"));
+        assert_eq!(from.attribute("day"), Some("---14"));
+        assert_eq!(find("Current")[0].text(), Some("true"));
+        assert_eq!(
+            find("Activities")[0].text(),
+            Some("Wrote <the> first program")
+        );
+        assert_eq!(find("MotherTongue").len(), 1);
+        assert_eq!(find("ForeignLanguage").len(), 1);
+        assert_eq!(find("Writing")[0].text(), Some("B2"));
+        assert_eq!(find("Achievement").len(), 2);
+    }
+}
@@ -1,14 +1,25 @@
-use crate::models::experience::{AuthExperienceModel, ExperienceModel, PublicExperienceModel};
+use crate::models::date::{deserialize_optional_date, ProfileDate};
+use crate::models::experience::{
+    AttestedExperienceModel, AuthExperienceModel, ExperienceGapModel, ExperienceGroupModel,
+    ExperienceMetricsModel, ExperienceModel, ExperienceSpanModel, PublicExperienceModel,
+};
 use crate::response::error_handling::AppError;
 use crate::routes::api::auth::profile::experience::ExperienceType;
+use crate::services::attestation_service::AttestationKey;
 use crate::{IdenoDBResult, IdenoPool};
+use std::cmp::Reverse;
+use std::collections::HashSet;
 
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct AddExperiencePayload {
     pub company: String,
+    pub organization_id: Option<i32>,
     pub title: String,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub start_date: Option<ProfileDate>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub end_date: Option<ProfileDate>,
+    pub is_current: Option<bool>,
     pub exp_type: Option<String>,
     pub description: Option<String>,
 }
@@ -16,21 +27,40 @@ pub struct AddExperiencePayload {
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct UpdateExperiencePayload {
     pub company: String,
+    pub organization_id: Option<i32>,
     pub title: String,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub start_date: Option<ProfileDate>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub end_date: Option<ProfileDate>,
+    pub is_current: Option<bool>,
     pub exp_type: Option<String>,
     pub description: Option<String>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ExperienceListQuery {
+    pub grouped: Option<bool>,
+}
+
+/// Legal forms that are ignored when comparing company names.
+const COMPANY_LEGAL_FORMS: [&str; 14] = [
+    "ag", "co", "corp", "corporation", "gmbh", "inc", "incorporated", "kg", "limited", "llc", "ltd",
+    "plc", "sa", "se",
+];
+
 #[derive(Clone)]
 pub struct ExperienceService {
     db_pool: IdenoPool,
+    attestation_key: AttestationKey,
 }
 
 impl ExperienceService {
-    pub fn new(db_pool: IdenoPool) -> Self {
-        ExperienceService { db_pool }
+    pub fn new(db_pool: IdenoPool, attestation_key: AttestationKey) -> Self {
+        ExperienceService {
+            db_pool,
+            attestation_key,
+        }
     }
 
     /// Asynchronously retrieves experiences associated with an authenticated user from the database.
@@ -51,23 +81,43 @@ impl ExperienceService {
         &self,
         user_id: i32,
     ) -> Result<Vec<AuthExperienceModel>, AppError> {
-        sqlx::query_as::<_, AuthExperienceModel>(
+        let mut experiences = sqlx::query_as::<_, AuthExperienceModel>(
             "SELECT
-                id,
+                experiences.id,
                 company,
+                organization_id,
+                organizations.slug AS organization_slug,
                 title,
                 start_date,
                 end_date,
+                start_date_legacy,
+                end_date_legacy,
+                is_current,
                 exp_type,
                 description
               FROM experiences
+              LEFT JOIN organizations ON organizations.id = experiences.organization_id
               WHERE user_id = ?
-              ORDER BY created_at DESC",
+              ORDER BY experiences.created_at DESC",
         )
         .bind(user_id)
         .fetch_all(&self.db_pool)
         .await
-        .map_err(|_| AppError::InternalError)
+        .map_err(|_| AppError::InternalError)?;
+
+        let today = ProfileDate::today();
+        let verified_ids = self.get_verified_experience_ids(user_id).await?;
+        for experience in experiences.iter_mut() {
+            experience.duration_months = experience_duration_months(
+                &experience.start_date,
+                &experience.end_date,
+                experience.is_current,
+                today,
+            );
+            experience.verified = verified_ids.contains(&experience.id);
+        }
+
+        Ok(experiences)
     }
 
     /// Asynchronously retrieves public experiences associated with a user from the database.
@@ -91,24 +141,42 @@ impl ExperienceService {
         limit: Option<i32>,
     ) -> Result<Vec<PublicExperienceModel>, AppError> {
         let limit = limit.unwrap_or(-1);
-        sqlx::query_as::<_, PublicExperienceModel>(
+        let mut experiences = sqlx::query_as::<_, PublicExperienceModel>(
             "SELECT
+                experiences.id,
                 company,
+                organizations.slug AS organization_slug,
                 title,
                 start_date,
                 end_date,
+                is_current,
                 exp_type,
                 description
               FROM experiences
+              LEFT JOIN organizations ON organizations.id = experiences.organization_id
               WHERE user_id = $1
-              ORDER BY created_at DESC
+              ORDER BY experiences.created_at DESC
               LIMIT $2",
         )
         .bind(user_id)
         .bind(limit)
         .fetch_all(&self.db_pool)
         .await
-        .map_err(|_| AppError::InternalError)
+        .map_err(|_| AppError::InternalError)?;
+
+        let today = ProfileDate::today();
+        let verified_ids = self.get_verified_experience_ids(user_id).await?;
+        for experience in experiences.iter_mut() {
+            experience.duration_months = experience_duration_months(
+                &experience.start_date,
+                &experience.end_date,
+                experience.is_current,
+                today,
+            );
+            experience.verified = verified_ids.contains(&experience.id);
+        }
+
+        Ok(experiences)
     }
 
     /// Asynchronously checks if a user owns a specific experience.
@@ -181,6 +249,8 @@ impl ExperienceService {
 
     /// Asynchronously updates an experience associated with a user in the database.
     ///
+    /// The attestation of the experience is voided if the company, title, dates, current flag or organization change.
+    ///
     /// # Arguments
     ///
     /// * `user_id` - The ID of the user who owns the experience to be updated.
@@ -202,15 +272,47 @@ impl ExperienceService {
         experience_id: i32,
         payload: UpdateExperiencePayload,
     ) -> Result<IdenoDBResult, AppError> {
+        let is_current = payload.is_current.unwrap_or(false);
+
+        // Editing a verified field voids the attestation, even if the field is changed back later
         sqlx::query(
-            "UPDATE experiences SET company = $1, title = $2, start_date = $3, end_date = $4, exp_type = $5, description = $6 WHERE id = $7 AND user_id = $8",
+            "DELETE FROM experience_attestations
+              WHERE experience_id = $1
+                AND EXISTS (
+                  SELECT 1 FROM experiences
+                  WHERE id = $1
+                    AND user_id = $2
+                    AND (company IS NOT $3
+                      OR title IS NOT $4
+                      OR start_date IS NOT $5
+                      OR end_date IS NOT $6
+                      OR is_current IS NOT $7
+                      OR organization_id IS NOT $8)
+                )",
+        )
+        .bind(experience_id)
+        .bind(user_id)
+        .bind(&payload.company)
+        .bind(&payload.title)
+        .bind(payload.start_date)
+        .bind(payload.end_date)
+        .bind(is_current)
+        .bind(payload.organization_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        sqlx::query(
+            "UPDATE experiences SET company = $1, title = $2, start_date = $3, end_date = $4, start_date_legacy = CASE WHEN $3 IS NULL THEN start_date_legacy END, end_date_legacy = CASE WHEN $4 IS NULL THEN end_date_legacy END, is_current = $5, exp_type = $6, description = $7, organization_id = $8 WHERE id = $9 AND user_id = $10",
         )
             .bind(payload.company)
             .bind(payload.title)
             .bind(payload.start_date)
             .bind(payload.end_date)
+            .bind(is_current)
             .bind(payload.exp_type)
             .bind(payload.description)
+            .bind(payload.organization_id)
             .bind(experience_id)
             .bind(user_id)
             .execute(&self.db_pool)
@@ -239,14 +341,16 @@ impl ExperienceService {
         payload: AddExperiencePayload,
     ) -> Result<i64, AppError> {
         sqlx::query_as::<_, (i64,)>(
-            "INSERT INTO experiences (company, title, start_date, end_date, exp_type, description, user_id) VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING id",
+            "INSERT INTO experiences (company, title, start_date, end_date, is_current, exp_type, description, organization_id, user_id) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) RETURNING id",
         )
             .bind(payload.company)
             .bind(payload.title)
             .bind(payload.start_date)
             .bind(payload.end_date)
+            .bind(payload.is_current.unwrap_or(false))
             .bind(payload.exp_type)
             .bind(payload.description)
+            .bind(payload.organization_id)
             .bind(user_id)
             .fetch_one(&self.db_pool)
             .await
@@ -273,7 +377,7 @@ impl ExperienceService {
         experience_type: &Option<String>,
     ) -> Result<bool, AppError> {
         if let Some(exp_type) = experience_type {
-            if exp_type.len() == 0 {
+            if exp_type.is_empty() {
                 return Ok(true);
             };
 
@@ -288,6 +392,129 @@ impl ExperienceService {
         Ok(true)
     }
 
+    /// Validates the dates of an experience.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_date` - An optional reference to the start date.
+    /// * `end_date` - An optional reference to the end date.
+    /// * `is_current` - Whether the user currently holds the position.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the dates are valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the end date is before the start date or a current
+    /// experience has an end date.
+    ///
+    pub fn validate_experience_dates(
+        &self,
+        start_date: &Option<ProfileDate>,
+        end_date: &Option<ProfileDate>,
+        is_current: Option<bool>,
+    ) -> Result<bool, AppError> {
+        if is_current.unwrap_or(false) && end_date.is_some() {
+            return Err(AppError::BadRequest {
+                error: Some("Current experiences cannot have an end date".to_string()),
+            });
+        }
+
+        if !ProfileDate::is_valid_range(start_date, end_date) {
+            return Err(AppError::BadRequest {
+                error: Some("End date must not be before start date".to_string()),
+            });
+        }
+
+        Ok(true)
+    }
+
+    /// Asynchronously retrieves the experiences of an authenticated user grouped by company.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the authenticated user whose experiences are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `ExperienceGroupModel` instances, each holding consecutive
+    /// `AuthExperienceModel` positions at the same company.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_grouped_authenticated_experiences(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<ExperienceGroupModel<AuthExperienceModel>>, AppError> {
+        let experiences = self.get_authenticated_experiences(user_id).await?;
+
+        Ok(group_experiences(experiences, ProfileDate::today()))
+    }
+
+    /// Asynchronously retrieves the public experiences of a user grouped by company.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose public experiences are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `ExperienceGroupModel` instances, each holding consecutive
+    /// `PublicExperienceModel` positions at the same company.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_grouped_public_experiences(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<ExperienceGroupModel<PublicExperienceModel>>, AppError> {
+        let experiences = self.get_public_experiences(user_id, None).await?;
+
+        Ok(group_experiences(experiences, ProfileDate::today()))
+    }
+
+    /// Asynchronously derives the experience metrics of a user from all of their experiences.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose experience metrics are to be derived.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `ExperienceMetricsModel` with the total experience, the gaps between
+    /// experiences and the current position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_experience_metrics(
+        &self,
+        user_id: i32,
+    ) -> Result<ExperienceMetricsModel, AppError> {
+        let spans = sqlx::query_as::<_, ExperienceSpanModel>(
+            "SELECT
+                company,
+                title,
+                start_date,
+                end_date,
+                is_current
+              FROM experiences
+              WHERE user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        Ok(compute_experience_metrics(&spans, ProfileDate::today()))
+    }
+
     /// Asynchronously retrieves the count of experiences associated with a user from the database.
     ///
     /// # Arguments
@@ -329,13 +556,64 @@ impl ExperienceService {
         &self,
         user_id: i32,
     ) -> Result<Vec<ExperienceModel>, AppError> {
-        sqlx::query_as::<_, ExperienceModel>(
+        let mut experiences = sqlx::query_as::<_, ExperienceModel>(
             "SELECT * FROM experiences WHERE user_id = $1 ORDER BY created_at DESC",
         )
         .bind(user_id)
         .fetch_all(&self.db_pool)
         .await
-        .map_err(|_| AppError::InternalError)
+        .map_err(|_| AppError::InternalError)?;
+
+        let verified_ids = self.get_verified_experience_ids(user_id).await?;
+        for experience in experiences.iter_mut() {
+            experience.verified = verified_ids.contains(&experience.id);
+        }
+
+        Ok(experiences)
+    }
+
+    /// Asynchronously retrieves the IDs of the experiences of a user that carry a valid attestation.
+    ///
+    /// An attestation is valid while its signature matches the verified fields of the experience as they are stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose verified experiences are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the set of verified experience IDs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    async fn get_verified_experience_ids(&self, user_id: i32) -> Result<HashSet<i32>, AppError> {
+        let experiences = sqlx::query_as::<_, AttestedExperienceModel>(
+            "SELECT
+                experiences.id,
+                experiences.user_id,
+                experiences.organization_id,
+                experiences.company,
+                experiences.title,
+                experiences.start_date,
+                experiences.end_date,
+                experiences.is_current,
+                experience_attestations.signature
+              FROM experience_attestations
+              JOIN experiences ON experiences.id = experience_attestations.experience_id
+              WHERE experiences.user_id = $1",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        Ok(experiences
+            .iter()
+            .filter(|experience| self.attestation_key.verify(experience))
+            .map(|experience| experience.id)
+            .collect())
     }
 
     /// Asynchronously deletes an experience from the database for administrative purposes.
@@ -393,3 +671,293 @@ impl ExperienceService {
         }
     }
 }
+
+/// Returns the number of months an experience lasted, counting the first and the last month.
+///
+/// A current experience lasts until today. Experiences without a start date, and past experiences without an end
+/// date, have no known duration.
+///
+pub(crate) fn experience_duration_months(
+    start_date: &Option<ProfileDate>,
+    end_date: &Option<ProfileDate>,
+    is_current: bool,
+    today: ProfileDate,
+) -> Option<i32> {
+    let (first, last) = experience_month_range(start_date, end_date, is_current, today)?;
+
+    Some(last - first + 1)
+}
+
+fn experience_month_range(
+    start_date: &Option<ProfileDate>,
+    end_date: &Option<ProfileDate>,
+    is_current: bool,
+    today: ProfileDate,
+) -> Option<(i32, i32)> {
+    let first = start_date.as_ref()?.first_month();
+    let last = if is_current {
+        today.last_month()
+    } else {
+        end_date.as_ref()?.last_month()
+    };
+
+    (last >= first).then_some((first, last))
+}
+
+/// Derives the experience metrics from the experiences of a user.
+///
+/// Overlapping and adjacent experiences are merged before the total is summed up, so holding two positions at the same
+/// time is not counted twice. Every month between two merged ranges that is not covered by an experience is reported
+/// as part of a gap. Experiences without a known duration are ignored, except for the current position.
+///
+pub(crate) fn compute_experience_metrics(
+    spans: &[ExperienceSpanModel],
+    today: ProfileDate,
+) -> ExperienceMetricsModel {
+    let mut ranges = spans
+        .iter()
+        .filter_map(|span| {
+            experience_month_range(&span.start_date, &span.end_date, span.is_current, today)
+        })
+        .collect::<Vec<(i32, i32)>>();
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(i32, i32)> = Vec::new();
+    for (first, last) in ranges {
+        match merged.last_mut() {
+            Some(previous) if first <= previous.1 + 1 => previous.1 = previous.1.max(last),
+            _ => merged.push((first, last)),
+        }
+    }
+
+    let total_months = merged
+        .iter()
+        .map(|(first, last)| last - first + 1)
+        .sum::<i32>();
+    let gaps = merged
+        .windows(2)
+        .map(|pair| ExperienceGapModel {
+            start: ProfileDate::from_month_index(pair[0].1 + 1),
+            end: ProfileDate::from_month_index(pair[1].0 - 1),
+            months: pair[1].0 - pair[0].1 - 1,
+        })
+        .collect();
+
+    let current = spans
+        .iter()
+        .filter(|span| span.is_current)
+        .max_by_key(|span| span.start_date.map(|date| date.first_month()));
+
+    ExperienceMetricsModel {
+        total_months,
+        total_years: (f64::from(total_months) / 12.0 * 10.0).round() / 10.0,
+        gaps,
+        current_title: current.map(|span| span.title.clone()),
+        current_company: current.map(|span| span.company.clone()),
+    }
+}
+
+/// Normalizes a company name so that spelling variants of the same company compare equal.
+///
+/// The name is lowercased, punctuation is dropped, whitespace is collapsed and trailing legal forms such as
+/// "Inc." or "GmbH" are removed.
+///
+pub(crate) fn normalize_company_name(name: &str) -> String {
+    let cleaned = name
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>();
+    let mut words = cleaned.split_whitespace().collect::<Vec<&str>>();
+
+    while words.len() > 1 && COMPANY_LEGAL_FORMS.contains(words.last().unwrap()) {
+        words.pop();
+    }
+
+    words.join(" ")
+}
+
+/// Groups consecutive positions at the same company, after ordering them from the most recent to the oldest.
+///
+/// The tenure of a group counts every month covered by at least one of its positions.
+///
+pub(crate) fn group_experiences<T>(
+    mut experiences: Vec<T>,
+    today: ProfileDate,
+) -> Vec<ExperienceGroupModel<T>>
+where
+    for<'a> ExperienceSpanModel: From<&'a T>,
+{
+    experiences.sort_by_key(|experience| {
+        let span = ExperienceSpanModel::from(experience);
+        Reverse((
+            span.is_current,
+            span.start_date.map(|date| date.first_month()),
+        ))
+    });
+
+    let mut groups: Vec<(String, Vec<T>)> = Vec::new();
+    for experience in experiences {
+        let key = normalize_company_name(&ExperienceSpanModel::from(&experience).company);
+        match groups.last_mut() {
+            Some((group_key, positions)) if *group_key == key => positions.push(experience),
+            _ => groups.push((key, vec![experience])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(_, positions)| {
+            let spans = positions
+                .iter()
+                .map(ExperienceSpanModel::from)
+                .collect::<Vec<ExperienceSpanModel>>();
+            let is_current = spans.iter().any(|span| span.is_current);
+            let tenure_months = compute_experience_metrics(&spans, today).total_months;
+
+            ExperienceGroupModel {
+                company: spans[0].company.clone(),
+                start_date: spans
+                    .iter()
+                    .filter_map(|span| span.start_date)
+                    .min_by_key(|date| date.first_month()),
+                end_date: if is_current {
+                    None
+                } else {
+                    spans
+                        .iter()
+                        .filter_map(|span| span.end_date)
+                        .max_by_key(|date| date.last_month())
+                },
+                is_current,
+                tenure_months: (tenure_months > 0).then_some(tenure_months),
+                positions,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        compute_experience_metrics, experience_duration_months, group_experiences,
+        normalize_company_name,
+    };
+    use crate::models::date::ProfileDate;
+    use crate::models::experience::{ExperienceSpanModel, PublicExperienceModel};
+
+    fn span(start: &str, end: Option<&str>, is_current: bool) -> ExperienceSpanModel {
+        ExperienceSpanModel {
+            company: format!("Company {}", start),
+            title: format!("Title {}", start),
+            start_date: ProfileDate::parse(start),
+            end_date: end.and_then(ProfileDate::parse),
+            is_current,
+        }
+    }
+
+    #[test]
+    fn test_experience_duration_months() {
+        let today = ProfileDate::parse("2024-06-15").unwrap();
+        let date = |value: &str| ProfileDate::parse(value);
+
+        assert_eq!(
+            experience_duration_months(&date("2020-01"), &date("2020-12"), false, today),
+            Some(12)
+        );
+        assert_eq!(
+            experience_duration_months(&date("2020"), &date("2021"), false, today),
+            Some(24)
+        );
+        assert_eq!(
+            experience_duration_months(&date("2024-01"), &None, true, today),
+            Some(6)
+        );
+
+        // Past experiences without an end date have no known duration
+        assert_eq!(
+            experience_duration_months(&date("2020-01"), &None, false, today),
+            None
+        );
+        assert_eq!(
+            experience_duration_months(&None, &date("2020-01"), false, today),
+            None
+        );
+    }
+
+    #[test]
+    fn test_compute_experience_metrics() {
+        let today = ProfileDate::parse("2024-06-15").unwrap();
+        let spans = vec![
+            span("2018-01", Some("2019-06"), false),
+            span("2019-01", Some("2019-12"), false),
+            span("2020-01", Some("2020-03"), false),
+            span("2021-01", None, true),
+            span("2015-01", None, false),
+        ];
+
+        let metrics = compute_experience_metrics(&spans, today);
+
+        // 2018-01..2020-03 merged (27 months) and 2021-01..2024-06 (42 months)
+        assert_eq!(metrics.total_months, 69);
+        assert_eq!(metrics.total_years, 5.8);
+        assert_eq!(metrics.gaps.len(), 1);
+        assert_eq!(metrics.gaps[0].start.to_string(), "2020-04");
+        assert_eq!(metrics.gaps[0].end.to_string(), "2020-12");
+        assert_eq!(metrics.gaps[0].months, 9);
+        assert_eq!(metrics.current_title.as_deref(), Some("Title 2021-01"));
+        assert_eq!(metrics.current_company.as_deref(), Some("Company 2021-01"));
+    }
+
+    #[test]
+    fn test_normalize_company_name() {
+        assert_eq!(normalize_company_name("Acme"), "acme");
+        assert_eq!(normalize_company_name("  ACME, Inc. "), "acme");
+        assert_eq!(normalize_company_name("Acme GmbH & Co. KG"), "acme");
+        assert_eq!(normalize_company_name("Foo-Bar  Ltd"), "foo bar");
+
+        // A legal form on its own is kept
+        assert_eq!(normalize_company_name("Inc."), "inc");
+    }
+
+    #[test]
+    fn test_group_experiences() {
+        let today = ProfileDate::parse("2024-06-15").unwrap();
+        let position = |company: &str, start: &str, end: Option<&str>, is_current: bool| {
+            PublicExperienceModel {
+                id: 0,
+                company: company.to_string(),
+                organization_slug: None,
+                title: format!("Title {}", start),
+                start_date: ProfileDate::parse(start),
+                end_date: end.and_then(ProfileDate::parse),
+                is_current,
+                duration_months: None,
+                exp_type: None,
+                description: None,
+                verified: false,
+            }
+        };
+
+        let groups = group_experiences(
+            vec![
+                position("Acme", "2016-01", Some("2017-12"), false),
+                position("ACME", "2020-01", Some("2021-12"), false),
+                position("Beta", "2018-01", Some("2019-12"), false),
+                position("Acme Inc.", "2022-01", None, true),
+            ],
+            today,
+        );
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].company, "Acme Inc.");
+        assert_eq!(groups[0].positions.len(), 2);
+        assert!(groups[0].is_current);
+        assert_eq!(groups[0].start_date.unwrap().to_string(), "2020-01");
+        assert_eq!(groups[0].end_date, None);
+        assert_eq!(groups[0].tenure_months, Some(54));
+        assert_eq!(groups[1].company, "Beta");
+        assert_eq!(groups[2].company, "Acme");
+        assert_eq!(groups[2].tenure_months, Some(24));
+    }
+}
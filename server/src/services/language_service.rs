@@ -0,0 +1,394 @@
+use crate::models::language::{AuthLanguageModel, LanguageModel, PublicLanguageModel};
+use crate::response::error_handling::AppError;
+use crate::routes::api::auth::profile::language::{is_language_code, LanguageLevel};
+use crate::{IdenoDBResult, IdenoPool};
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AddLanguagePayload {
+    pub language_code: String,
+    pub level: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdateLanguagePayload {
+    pub language_code: String,
+    pub level: String,
+}
+
+#[derive(Clone)]
+pub struct LanguageService {
+    db_pool: IdenoPool,
+}
+
+impl LanguageService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        LanguageService { db_pool }
+    }
+
+    /// Asynchronously retrieves authenticated languages associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the authenticated user whose languages are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `AuthLanguageModel` instances representing the languages associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_authenticated_languages(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<AuthLanguageModel>, AppError> {
+        sqlx::query_as::<_, AuthLanguageModel>(
+            "SELECT
+                id,
+                language_code,
+                level
+              FROM languages
+              WHERE user_id = ?
+              ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously retrieves public languages associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose public languages are to be retrieved.
+    /// * `limit` - An optional limit on the number of languages to retrieve. If not provided, retrieves all languages.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `PublicLanguageModel` instances representing the public languages associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_public_languages(
+        &self,
+        user_id: i32,
+        limit: Option<i32>,
+    ) -> Result<Vec<PublicLanguageModel>, AppError> {
+        let limit = limit.unwrap_or(-1);
+        sqlx::query_as::<_, PublicLanguageModel>(
+            "SELECT
+                language_code,
+                level
+              FROM languages
+              WHERE user_id = $1
+              ORDER BY created_at DESC
+              LIMIT $2",
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously checks if a user owns a specific language entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user to check ownership.
+    /// * `language_id` - The ID of the language entry to check ownership against.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option<bool>`. If the user owns the language entry, it returns `Some(true)`,
+    /// if the user does not own the language entry, it returns `Some(false)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` with an error message if the language entry is not found.
+    ///
+    pub async fn user_owns_language(
+        &self,
+        user_id: i32,
+        language_id: i32,
+    ) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM languages WHERE id = $1 AND user_id = $2",
+        )
+        .bind(language_id)
+        .bind(user_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map_or_else(|_| None, |count| Some(count.0 > 0));
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Language not found".to_string(),
+            }),
+        }
+    }
+
+    /// Validates the language code and CEFR level.
+    ///
+    /// # Arguments
+    ///
+    /// * `language_code` - The ISO 639-1 code of the language.
+    /// * `level` - The CEFR level or `Native`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the language is valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the language code or level is invalid.
+    ///
+    pub fn validate_language(&self, language_code: &str, level: &str) -> Result<bool, AppError> {
+        if !is_language_code(language_code) {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid language code".to_string()),
+            });
+        }
+
+        if LanguageLevel::from_str(level).is_none() {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid language level".to_string()),
+            });
+        }
+
+        Ok(true)
+    }
+
+    /// Asynchronously checks if a user already has an entry for the given language.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user to check.
+    /// * `language_code` - The ISO 639-1 code of the language.
+    /// * `exclude_id` - An optional language entry ID to ignore, used when updating an existing entry.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing `true` if the user already has an entry for the language, otherwise `false`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_existing_language(
+        &self,
+        user_id: i32,
+        language_code: &str,
+        exclude_id: Option<i32>,
+    ) -> Result<bool, AppError> {
+        sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM languages WHERE user_id = $1 AND language_code = $2 AND id != $3",
+        )
+        .bind(user_id)
+        .bind(language_code.to_lowercase())
+        .bind(exclude_id.unwrap_or(-1))
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|count| count.0 > 0)
+    }
+
+    /// Asynchronously deletes a language entry associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the language entry to be deleted.
+    /// * `language_id` - The ID of the language entry to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the delete operation. If the language entry is successfully deleted,
+    /// it returns `Ok(IdenoDBResult)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn delete_language(
+        &self,
+        user_id: i32,
+        language_id: i32,
+    ) -> Result<IdenoDBResult, AppError> {
+        sqlx::query("DELETE FROM languages WHERE id = $1 AND user_id = $2")
+            .bind(language_id)
+            .bind(user_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously updates a language entry associated with a user in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the language entry to be updated.
+    /// * `language_id` - The ID of the language entry to be updated.
+    /// * `payload` - An `UpdateLanguagePayload` containing the updated data for the language.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the update operation. If the language entry is successfully updated,
+    /// it returns `Ok(IdenoDBResult)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn update_language(
+        &self,
+        user_id: i32,
+        language_id: i32,
+        payload: UpdateLanguagePayload,
+    ) -> Result<IdenoDBResult, AppError> {
+        sqlx::query("UPDATE languages SET language_code = $1, level = $2 WHERE id = $3 AND user_id = $4")
+            .bind(payload.language_code.to_lowercase())
+            .bind(payload.level)
+            .bind(language_id)
+            .bind(user_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously creates a new language entry for a user in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the language entry is to be created.
+    /// * `payload` - An `AddLanguagePayload` containing the details of the language to be created.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created language entry if the operation is successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_language(
+        &self,
+        user_id: i32,
+        payload: AddLanguagePayload,
+    ) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>(
+            "INSERT INTO languages (language_code, level, user_id) VALUES ($1, $2, $3) RETURNING id",
+        )
+        .bind(payload.language_code.to_lowercase())
+        .bind(payload.level)
+        .bind(user_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|id| id.0)
+    }
+
+    /// Asynchronously retrieves the count of language entries associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose language count is to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the count of language entries associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_language_count(&self, user_id: i32) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM languages WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|count| count.0)
+    }
+
+    /// Asynchronously retrieves all language entries associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose language entries are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `LanguageModel` instances representing all language entries associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_all_languages(&self, user_id: i32) -> Result<Vec<LanguageModel>, AppError> {
+        sqlx::query_as::<_, LanguageModel>(
+            "SELECT * FROM languages WHERE user_id = $1 ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously deletes a language entry from the database for administrative purposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `language_id` - The ID of the language entry to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), AppError>` indicating the outcome of the delete operation. If the language entry is successfully deleted, it returns `Ok(())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn admin_delete_language(&self, language_id: i32) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM languages WHERE id = $1")
+            .bind(language_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+
+    /// Asynchronously checks if a language entry exists in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `language_id` - The ID of the language entry to check for existence.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option<bool>`. If the language entry exists, it returns `Some(true)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` with an error message if the language entry is not found.
+    ///
+    pub async fn language_exists(&self, language_id: i32) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM languages WHERE id = $1")
+            .bind(language_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map_or_else(|_| None, |count| Some(count.0 > 0));
+
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Language not found".to_string(),
+            }),
+        }
+    }
+}
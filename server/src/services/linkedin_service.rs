@@ -0,0 +1,479 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+
+use crate::models::date::ProfileDate;
+use crate::models::profile::PublicProfileModel;
+use crate::response::error_handling::AppError;
+use crate::services::certification_service::AddCertificationPayload;
+use crate::services::contact_information_service::AddContactInformationPayload;
+use crate::services::education_service::AddEducationPayload;
+use crate::services::experience_service::AddExperiencePayload;
+use crate::services::resume_service::{ResumeImport, ResumeImportItem};
+
+/// The files of a LinkedIn data export that are imported.
+const PROFILE_FILE: &str = "Profile.csv";
+const POSITIONS_FILE: &str = "Positions.csv";
+const EDUCATION_FILE: &str = "Education.csv";
+const CERTIFICATIONS_FILE: &str = "Certifications.csv";
+const EMAIL_ADDRESSES_FILE: &str = "Email Addresses.csv";
+
+/// The maximum size of an imported file after decompression.
+const MAX_FILE_BYTES: u64 = 5 * 1024 * 1024;
+
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// A CSV file of the archive, as rows of values keyed by column name.
+type CsvRows = Vec<HashMap<String, String>>;
+
+/// Reads the profile fields and entries of a LinkedIn data export.
+///
+/// The archive is the ZIP file from LinkedIn's "Get a copy of your data". The profile is read from `Profile.csv`,
+/// experiences from `Positions.csv`, educations from `Education.csv`, certifications from `Certifications.csv`
+/// and contact information from the websites and Twitter handles of `Profile.csv` and from `Email Addresses.csv`.
+/// Missing files are skipped. Rows missing required columns or with unreadable dates are reported as problems.
+///
+/// # Arguments
+///
+/// * `content` - The uploaded archive.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the `ResumeImport` read from the archive, not yet validated against the rules of
+/// each entry type.
+///
+/// # Errors
+///
+/// Returns an `AppError::BadRequest` if the upload is not a ZIP archive or contains none of the imported files.
+///
+pub fn read_linkedin_archive(content: &[u8]) -> Result<ResumeImport, AppError> {
+    let invalid_archive = || AppError::BadRequest {
+        error: Some("Invalid LinkedIn data export archive".to_string()),
+    };
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(content)).map_err(|_| invalid_archive())?;
+    let mut files: HashMap<&str, CsvRows> = HashMap::new();
+
+    for index in 0..archive.len() {
+        let file = archive.by_index(index).map_err(|_| invalid_archive())?;
+        let file_name = file
+            .name()
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+
+        let Some(imported_file) = [
+            PROFILE_FILE,
+            POSITIONS_FILE,
+            EDUCATION_FILE,
+            CERTIFICATIONS_FILE,
+            EMAIL_ADDRESSES_FILE,
+        ]
+        .into_iter()
+        .find(|imported_file| imported_file.eq_ignore_ascii_case(&file_name)) else {
+            continue;
+        };
+
+        let mut data = Vec::new();
+        file.take(MAX_FILE_BYTES + 1)
+            .read_to_end(&mut data)
+            .map_err(|_| invalid_archive())?;
+
+        if data.len() as u64 > MAX_FILE_BYTES {
+            return Err(AppError::BadRequest {
+                error: Some(format!("{} is too large", imported_file)),
+            });
+        }
+
+        files.insert(
+            imported_file,
+            read_csv(&data).map_err(|_| invalid_archive())?,
+        );
+    }
+
+    if files.is_empty() {
+        return Err(invalid_archive());
+    }
+
+    let mut import = ResumeImport::default();
+    let mut take = |name: &str| files.remove(name).unwrap_or_default();
+
+    let profile_rows = take(PROFILE_FILE);
+    if let Some(row) = profile_rows.first() {
+        read_profile(row, &mut import);
+    }
+
+    for (index, row) in take(POSITIONS_FILE).iter().enumerate() {
+        let (Some(company), Some(title)) = (column(row, "Company Name"), column(row, "Title"))
+        else {
+            import.add_problem(
+                POSITIONS_FILE,
+                Some(index),
+                "Company name and title are required",
+            );
+            continue;
+        };
+
+        let (Some(start_date), Some(end_date)) = (
+            parse_linkedin_date(column(row, "Started On")),
+            parse_linkedin_date(column(row, "Finished On")),
+        ) else {
+            import.add_problem(POSITIONS_FILE, Some(index), "Invalid date");
+            continue;
+        };
+
+        import.experiences.push(ResumeImportItem::new(
+            POSITIONS_FILE,
+            Some(index),
+            AddExperiencePayload {
+                company,
+                organization_id: None,
+                title,
+                start_date,
+                end_date,
+                is_current: Some(start_date.is_some() && end_date.is_none()),
+                exp_type: None,
+                description: column(row, "Description"),
+            },
+        ));
+    }
+
+    for (index, row) in take(EDUCATION_FILE).iter().enumerate() {
+        let Some(school) = column(row, "School Name") else {
+            import.add_problem(EDUCATION_FILE, Some(index), "School name is required");
+            continue;
+        };
+
+        let (Some(start_date), Some(end_date)) = (
+            parse_linkedin_date(column(row, "Start Date")),
+            parse_linkedin_date(column(row, "End Date")),
+        ) else {
+            import.add_problem(EDUCATION_FILE, Some(index), "Invalid date");
+            continue;
+        };
+
+        import.educations.push(ResumeImportItem::new(
+            EDUCATION_FILE,
+            Some(index),
+            AddEducationPayload {
+                school,
+                organization_id: None,
+                degree: column(row, "Degree Name"),
+                field: None,
+                start_date,
+                end_date,
+            },
+        ));
+    }
+
+    for (index, row) in take(CERTIFICATIONS_FILE).iter().enumerate() {
+        let (Some(name), Some(organization)) = (column(row, "Name"), column(row, "Authority"))
+        else {
+            import.add_problem(
+                CERTIFICATIONS_FILE,
+                Some(index),
+                "Name and authority are required",
+            );
+            continue;
+        };
+
+        let (Some(issue_date), Some(expiration_date)) = (
+            parse_linkedin_date(column(row, "Started On")),
+            parse_linkedin_date(column(row, "Finished On")),
+        ) else {
+            import.add_problem(CERTIFICATIONS_FILE, Some(index), "Invalid date");
+            continue;
+        };
+
+        import.certifications.push(ResumeImportItem::new(
+            CERTIFICATIONS_FILE,
+            Some(index),
+            AddCertificationPayload {
+                name,
+                organization,
+                issue_date,
+                expiration_date,
+                credential_id: column(row, "License Number"),
+                credential_url: column(row, "Url"),
+            },
+        ));
+    }
+
+    let mut email_addresses = take(EMAIL_ADDRESSES_FILE)
+        .into_iter()
+        .enumerate()
+        .collect::<Vec<_>>();
+    // The primary address comes first
+    email_addresses.sort_by_key(|(_, row)| column(row, "Primary").as_deref() != Some("Yes"));
+
+    for (index, row) in email_addresses {
+        if let Some(email) = column(&row, "Email Address") {
+            import.contact_information.push(contact_item(
+                EMAIL_ADDRESSES_FILE,
+                Some(index),
+                "email",
+                None,
+                email,
+            ));
+        }
+    }
+
+    Ok(import)
+}
+
+/// Reads the profile fields, websites and Twitter handles of the row of `Profile.csv`.
+fn read_profile(row: &HashMap<String, String>, import: &mut ResumeImport) {
+    // Geo locations are given like "Berlin, Germany" or "Greater Munich Metropolitan Area"
+    let (city, country) = match column(row, "Geo Location") {
+        Some(location) => match location.rsplit_once(',') {
+            Some((city, country)) => (
+                Some(city.trim().to_string()),
+                Some(country.trim().to_string()),
+            ),
+            None => (Some(location), None),
+        },
+        None => (None, None),
+    };
+
+    let profile = PublicProfileModel {
+        first_name: column(row, "First Name"),
+        last_name: column(row, "Last Name"),
+        pronouns: None,
+        headline: column(row, "Headline"),
+        country,
+        city,
+        bio: column(row, "Summary"),
+    };
+
+    if [
+        &profile.first_name,
+        &profile.last_name,
+        &profile.headline,
+        &profile.city,
+        &profile.bio,
+    ]
+    .iter()
+    .any(|field| field.is_some())
+    {
+        import.profile = Some(profile);
+    }
+
+    // Websites are given like "[PERSONAL:https://example.org],[BLOG:https://blog.example.org]"
+    for (index, website) in list_column(row, "Websites").into_iter().enumerate() {
+        let (kind, url) = match website.split_once(':') {
+            Some((kind, url))
+                if !kind.is_empty() && kind.chars().all(|c| c.is_ascii_uppercase()) =>
+            {
+                (Some(kind), url)
+            }
+            _ => (None, website.as_str()),
+        };
+
+        let item = match (index, kind) {
+            (0, _) | (_, None) => {
+                contact_item(PROFILE_FILE, Some(index), "website", None, url.to_string())
+            }
+            (_, Some(kind)) => {
+                let label = kind[..1].to_string() + &kind[1..].to_ascii_lowercase();
+                contact_item(
+                    PROFILE_FILE,
+                    Some(index),
+                    "custom",
+                    Some(label),
+                    url.to_string(),
+                )
+            }
+        };
+
+        import.contact_information.push(item);
+    }
+
+    for handle in list_column(row, "Twitter Handles") {
+        import
+            .contact_information
+            .push(contact_item(PROFILE_FILE, None, "twitter", None, handle));
+    }
+}
+
+fn contact_item(
+    section: &str,
+    index: Option<usize>,
+    contact_type: &str,
+    label: Option<String>,
+    value: String,
+) -> ResumeImportItem<AddContactInformationPayload> {
+    ResumeImportItem::new(
+        section,
+        index,
+        AddContactInformationPayload {
+            contact_type: contact_type.to_string(),
+            label,
+            value,
+            visibility: None,
+        },
+    )
+}
+
+/// Reads a CSV file with a header row into rows keyed by column name.
+fn read_csv(data: &[u8]) -> Result<CsvRows, csv::Error> {
+    let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+    let mut reader = csv::ReaderBuilder::new().flexible(true).from_reader(data);
+    let headers = reader.headers()?.clone();
+
+    reader
+        .records()
+        .map(|record| {
+            record.map(|record| {
+                headers
+                    .iter()
+                    .zip(record.iter())
+                    .map(|(header, value)| (header.trim().to_string(), value.to_string()))
+                    .collect()
+            })
+        })
+        .collect()
+}
+
+/// Returns the trimmed value of a column, or `None` if it is missing or empty.
+fn column(row: &HashMap<String, String>, name: &str) -> Option<String> {
+    row.get(name)
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Returns the values of a column holding a list like "[first],[second]".
+fn list_column(row: &HashMap<String, String>, name: &str) -> Vec<String> {
+    column(row, name)
+        .map(|value| {
+            value
+                .split("],")
+                .map(|item| {
+                    item.trim()
+                        .trim_start_matches('[')
+                        .trim_end_matches(']')
+                        .trim()
+                })
+                .filter(|item| !item.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parses a date of a LinkedIn export, where `Some(None)` stands for a missing date and `None` for an
+/// unreadable one.
+///
+/// LinkedIn exports dates like `Jan 2021` or `2021`, dates in the profile date formats are accepted as well.
+///
+fn parse_linkedin_date(value: Option<String>) -> Option<Option<ProfileDate>> {
+    let Some(value) = value else {
+        return Some(None);
+    };
+
+    if let Some((month, year)) = value.split_once(' ') {
+        let month = MONTHS
+            .iter()
+            .position(|name| month.to_ascii_lowercase().starts_with(name))?;
+
+        return ProfileDate::parse(&format!("{}-{:02}", year.trim(), month + 1)).map(Some);
+    }
+
+    ProfileDate::parse(&value).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use zip::write::FileOptions;
+
+    use crate::models::date::ProfileDate;
+
+    use super::{parse_linkedin_date, read_linkedin_archive};
+
+    #[test]
+    fn test_parse_linkedin_date() {
+        assert_eq!(
+            parse_linkedin_date(Some("Jan 2021".to_string())),
+            Some(ProfileDate::parse("2021-01"))
+        );
+        assert_eq!(
+            parse_linkedin_date(Some("2019".to_string())),
+            Some(ProfileDate::parse("2019"))
+        );
+        assert_eq!(parse_linkedin_date(None), Some(None));
+
+        // Test with dates that cannot be read
+        assert_eq!(parse_linkedin_date(Some("Spring 2021".to_string())), None);
+        assert_eq!(parse_linkedin_date(Some("Jan 21".to_string())), None);
+    }
+
+    #[test]
+    fn test_read_linkedin_archive() {
+        let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let files = [
+            (
+                "Profile.csv",
+                "\u{feff}First Name,Last Name,Maiden Name,Address,Birth Date,Headline,Summary,Industry,Zip Code,Geo Location,Twitter Handles,Websites,Instant Messengers\n\
+                 Ada,Lovelace,,,,Analyst,\"First line\nSecond line\",,,\"London, United Kingdom\",[ada],\"[PERSONAL:https://ada.example.org],[BLOG:https://blog.example.org]\",\n",
+            ),
+            (
+                "Positions.csv",
+                "Company Name,Title,Description,Location,Started On,Finished On\n\
+                 Analytical Engines,Programmer,,London,Sep 1842,\n\
+                 Analytical Engines,,,,1840,1841\n",
+            ),
+            ("Email Addresses.csv", "Email Address,Confirmed,Primary,Updated On\nold@example.org,Yes,No,\nada@example.org,Yes,Yes,\n"),
+            ("Connections.csv", "First Name,Last Name\nCharles,Babbage\n"),
+        ];
+
+        for (name, content) in files {
+            archive.start_file(name, FileOptions::default()).unwrap();
+            archive.write_all(content.as_bytes()).unwrap();
+        }
+
+        let content = archive.finish().unwrap().into_inner();
+        let import = read_linkedin_archive(&content).ok().unwrap();
+
+        let profile = import.profile.unwrap();
+        assert_eq!(profile.first_name.as_deref(), Some("Ada"));
+        assert_eq!(profile.city.as_deref(), Some("London"));
+        assert_eq!(profile.country.as_deref(), Some("United Kingdom"));
+        assert_eq!(profile.bio.as_deref(), Some("First line\nSecond line"));
+
+        assert_eq!(import.experiences.len(), 1);
+        assert_eq!(
+            import.experiences[0].payload.start_date,
+            ProfileDate::parse("1842-09")
+        );
+        assert_eq!(import.experiences[0].payload.is_current, Some(true));
+        assert_eq!(import.problems.len(), 1);
+
+        let contacts = import
+            .contact_information
+            .iter()
+            .map(|item| {
+                (
+                    item.payload.contact_type.as_str(),
+                    item.payload.value.as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            contacts,
+            vec![
+                ("website", "https://ada.example.org"),
+                ("custom", "https://blog.example.org"),
+                ("twitter", "ada"),
+                ("email", "ada@example.org"),
+                ("email", "old@example.org"),
+            ]
+        );
+
+        // Test with an upload that is not an archive
+        assert!(read_linkedin_archive(b"First Name,Last Name").is_err());
+    }
+}
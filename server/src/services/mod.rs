@@ -1,8 +1,30 @@
 pub mod account_service;
+pub mod attestation_service;
+pub mod award_service;
+pub mod badge_service;
 pub mod certification_service;
 pub mod contact_information_service;
+pub mod contact_type_service;
+pub mod custom_section_service;
+pub mod data_export_service;
 pub mod education_service;
+pub mod europass_service;
 pub mod experience_service;
+pub mod language_service;
+pub mod linkedin_service;
+pub mod notification_service;
+pub mod og_image_service;
+pub mod organization_service;
+pub mod patent_service;
+pub mod pdf_service;
+pub mod profile_page_service;
 pub mod profile_service;
+pub mod project_service;
+pub mod publication_service;
+pub mod qr_service;
+pub mod recommendation_service;
+pub mod resume_service;
 pub mod session_service;
+pub mod skill_service;
 pub mod user_service;
+pub mod vcard_service;
@@ -0,0 +1,153 @@
+use crate::models::notification::{NotificationKind, NotificationModel};
+use crate::response::error_handling::AppError;
+use crate::IdenoPool;
+
+#[derive(Clone)]
+pub struct NotificationService {
+    db_pool: IdenoPool,
+}
+
+impl NotificationService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        NotificationService { db_pool }
+    }
+
+    /// Asynchronously creates a notification for a user.
+    ///
+    /// This is the hook other features call when something happens that the user should be told about.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user to be notified.
+    /// * `kind` - The `NotificationKind` describing the event.
+    /// * `message` - A human readable message describing the event.
+    /// * `reference_id` - An optional ID of the record the notification refers to.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the notification was stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn notify(
+        &self,
+        user_id: i32,
+        kind: NotificationKind,
+        message: String,
+        reference_id: Option<i32>,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            "INSERT INTO notifications (user_id, kind, message, reference_id) VALUES ($1, $2, $3, $4)",
+        )
+        .bind(user_id)
+        .bind(kind.as_str())
+        .bind(message)
+        .bind(reference_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|_| ())
+    }
+
+    /// Asynchronously retrieves the notifications of a user, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose notifications are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `NotificationModel` instances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_notifications(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<NotificationModel>, AppError> {
+        sqlx::query_as::<_, NotificationModel>(
+            "SELECT
+                id,
+                kind,
+                message,
+                reference_id,
+                read_at,
+                created_at
+              FROM notifications
+              WHERE user_id = $1
+              ORDER BY created_at DESC, id DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously marks a single notification of a user as read.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the notification.
+    /// * `notification_id` - The ID of the notification to be marked as read.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the notification was marked as read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the notification does not belong to the user.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn mark_notification_read(
+        &self,
+        user_id: i32,
+        notification_id: i32,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "UPDATE notifications SET read_at = COALESCE(read_at, CURRENT_TIMESTAMP) WHERE id = $1 AND user_id = $2",
+        )
+        .bind(notification_id)
+        .bind(user_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound {
+                error: "Notification not found".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Asynchronously marks all unread notifications of a user as read.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose notifications are to be marked as read.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the operation was successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn mark_all_notifications_read(&self, user_id: i32) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE notifications SET read_at = CURRENT_TIMESTAMP WHERE user_id = $1 AND read_at IS NULL",
+        )
+        .bind(user_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|_| ())
+    }
+}
@@ -0,0 +1,571 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use sha2::{Digest, Sha256};
+use tiny_skia::{Color, FillRule, Paint, PathBuilder, Pixmap, Rect, Transform};
+use ttf_parser::{Face, OutlineBuilder};
+
+use crate::models::experience::PublicExperienceModel;
+use crate::models::profile::PublicProfileModel;
+use crate::response::error_handling::AppError;
+use crate::services::pdf_service::{full_name, BOLD_FONT, REGULAR_FONT};
+
+pub const OG_IMAGE_WIDTH: u32 = 1200;
+pub const OG_IMAGE_HEIGHT: u32 = 630;
+/// The most profile cards kept in memory. The cache is emptied when it is full.
+const MAX_CACHED_IMAGES: usize = 256;
+/// Changes with the layout of the card, so cards rendered by an earlier layout are not served from the cache.
+const CARD_LAYOUT_VERSION: &str = "1";
+
+const BACKGROUND_COLOR: [u8; 3] = [0xf5, 0xf7, 0xfa];
+const ACCENT_COLOR: [u8; 3] = [0x1f, 0x54, 0x8c];
+const TEXT_COLOR: [u8; 3] = [0x1f, 0x29, 0x33];
+const MUTED_COLOR: [u8; 3] = [0x61, 0x6e, 0x7c];
+const AVATAR_TEXT_COLOR: [u8; 3] = [0xff, 0xff, 0xff];
+
+const MARGIN: f32 = 80.0;
+const ACCENT_BAR_WIDTH: f32 = 24.0;
+const AVATAR_RADIUS: f32 = 110.0;
+const AVATAR_CENTER_X: f32 = MARGIN + ACCENT_BAR_WIDTH + AVATAR_RADIUS;
+const AVATAR_CENTER_Y: f32 = 250.0;
+const TEXT_LEFT: f32 = AVATAR_CENTER_X + AVATAR_RADIUS + 60.0;
+const TEXT_WIDTH: f32 = OG_IMAGE_WIDTH as f32 - TEXT_LEFT - MARGIN;
+
+/// The content of the preview card of a profile.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ProfileCard {
+    pub username: String,
+    pub name: String,
+    pub initials: String,
+    pub headline: Option<String>,
+    pub position: Option<String>,
+}
+
+impl ProfileCard {
+    /// Builds the card of a profile, with the first current experience as the position.
+    pub fn new(
+        username: &str,
+        profile: &PublicProfileModel,
+        experiences: &[PublicExperienceModel],
+    ) -> ProfileCard {
+        let name = full_name(profile).unwrap_or_else(|| username.to_string());
+        let initials: String = name
+            .split_whitespace()
+            .filter_map(|part| part.chars().next())
+            .take(2)
+            .flat_map(char::to_uppercase)
+            .collect();
+        let position = experiences
+            .iter()
+            .find(|experience| experience.is_current)
+            .map(|experience| format!("{} at {}", experience.title, experience.company));
+
+        ProfileCard {
+            username: username.to_string(),
+            name,
+            initials,
+            headline: profile
+                .headline
+                .as_ref()
+                .map(|headline| headline.trim().to_string())
+                .filter(|headline| !headline.is_empty()),
+            position,
+        }
+    }
+
+    /// Returns a hash of the content of the card, which identifies the rendered image.
+    pub fn key(&self) -> String {
+        let mut hasher = Sha256::new();
+
+        for part in [
+            CARD_LAYOUT_VERSION,
+            &self.username,
+            &self.name,
+            &self.initials,
+            self.headline.as_deref().unwrap_or_default(),
+            self.position.as_deref().unwrap_or_default(),
+        ] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+
+        hex::encode(&hasher.finalize()[..16])
+    }
+}
+
+/// Converts the outline of a glyph from font units into a path on the card.
+struct GlyphPath<'a> {
+    builder: &'a mut PathBuilder,
+    x: f32,
+    baseline: f32,
+    scale: f32,
+}
+
+impl GlyphPath<'_> {
+    fn point(&self, x: f32, y: f32) -> (f32, f32) {
+        (self.x + x * self.scale, self.baseline - y * self.scale)
+    }
+}
+
+impl OutlineBuilder for GlyphPath<'_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.builder.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let (x, y) = self.point(x, y);
+        self.builder.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let (x1, y1) = self.point(x1, y1);
+        let (x, y) = self.point(x, y);
+        self.builder.quad_to(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let (x1, y1) = self.point(x1, y1);
+        let (x2, y2) = self.point(x2, y2);
+        let (x, y) = self.point(x, y);
+        self.builder.cubic_to(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.builder.close();
+    }
+}
+
+/// Returns the glyph of a character, falling back to a question mark for characters the font does not cover.
+fn glyph(face: &Face, c: char) -> ttf_parser::GlyphId {
+    face.glyph_index(c)
+        .or_else(|| face.glyph_index('?'))
+        .unwrap_or_default()
+}
+
+fn text_width(face: &Face, text: &str, size: f32) -> f32 {
+    let advances: f32 = text
+        .chars()
+        .map(|c| f32::from(face.glyph_hor_advance(glyph(face, c)).unwrap_or(0)))
+        .sum();
+
+    advances * size / f32::from(face.units_per_em())
+}
+
+/// Shortens a line with an ellipsis until it fits into a width.
+fn ellipsize(face: &Face, line: &str, size: f32, width: f32) -> String {
+    let mut line = line.trim_end().to_string();
+
+    while !line.is_empty() && text_width(face, &format!("{}…", line), size) > width {
+        line.pop();
+        line = line.trim_end().to_string();
+    }
+
+    format!("{}…", line)
+}
+
+/// Wraps a text at word boundaries into at most `max_lines` lines, ending cut texts with an ellipsis.
+fn wrap(face: &Face, text: &str, size: f32, width: f32, max_lines: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut truncated = false;
+
+    for word in text.split_whitespace() {
+        let candidate = match current.is_empty() {
+            true => word.to_string(),
+            false => format!("{} {}", current, word),
+        };
+
+        if current.is_empty() || text_width(face, &candidate, size) <= width {
+            current = candidate;
+            continue;
+        }
+
+        if lines.len() + 1 == max_lines {
+            truncated = true;
+            break;
+        }
+        lines.push(std::mem::replace(&mut current, word.to_string()));
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    if let Some(last) = lines.last_mut() {
+        if truncated || text_width(face, last, size) > width {
+            *last = ellipsize(face, last, size, width);
+        }
+    }
+
+    lines
+}
+
+fn paint(color: [u8; 3]) -> Paint<'static> {
+    let mut paint = Paint {
+        anti_alias: true,
+        ..Paint::default()
+    };
+    paint.set_color_rgba8(color[0], color[1], color[2], 0xff);
+    paint
+}
+
+/// Draws a line of text with its baseline at `y`.
+fn draw_text(
+    pixmap: &mut Pixmap,
+    face: &Face,
+    text: &str,
+    x: f32,
+    y: f32,
+    size: f32,
+    color: [u8; 3],
+) {
+    let scale = size / f32::from(face.units_per_em());
+    let mut builder = PathBuilder::new();
+    let mut pen = x;
+
+    for c in text.chars() {
+        let glyph = glyph(face, c);
+        face.outline_glyph(
+            glyph,
+            &mut GlyphPath {
+                builder: &mut builder,
+                x: pen,
+                baseline: y,
+                scale,
+            },
+        );
+        pen += f32::from(face.glyph_hor_advance(glyph).unwrap_or(0)) * scale;
+    }
+
+    if let Some(path) = builder.finish() {
+        pixmap.fill_path(
+            &path,
+            &paint(color),
+            FillRule::Winding,
+            Transform::identity(),
+            None,
+        );
+    }
+}
+
+/// Renders the preview card of a profile as a 1200×630 PNG, the size link previews expect.
+///
+/// The card shows the name, headline and current position next to an avatar, set in the embedded DejaVu Sans fonts.
+/// Ideno has no profile pictures, so the avatar is a monogram of the initials of the name.
+///
+/// # Arguments
+///
+/// * `card` - The content of the card.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the PNG image.
+///
+/// # Errors
+///
+/// Returns an `AppError::InternalError` if the embedded fonts could not be read or the image could not be encoded.
+///
+pub fn render_og_image(card: &ProfileCard) -> Result<Vec<u8>, AppError> {
+    let regular = Face::parse(REGULAR_FONT, 0).map_err(|_| AppError::InternalError)?;
+    let bold = Face::parse(BOLD_FONT, 0).map_err(|_| AppError::InternalError)?;
+    let mut pixmap = Pixmap::new(OG_IMAGE_WIDTH, OG_IMAGE_HEIGHT).ok_or(AppError::InternalError)?;
+
+    let [r, g, b] = BACKGROUND_COLOR;
+    pixmap.fill(Color::from_rgba8(r, g, b, 0xff));
+    if let Some(bar) = Rect::from_xywh(0.0, 0.0, ACCENT_BAR_WIDTH, OG_IMAGE_HEIGHT as f32) {
+        pixmap.fill_rect(bar, &paint(ACCENT_COLOR), Transform::identity(), None);
+    }
+
+    if let Some(circle) = PathBuilder::from_circle(AVATAR_CENTER_X, AVATAR_CENTER_Y, AVATAR_RADIUS)
+    {
+        pixmap.fill_path(
+            &circle,
+            &paint(ACCENT_COLOR),
+            FillRule::Winding,
+            Transform::identity(),
+            None,
+        );
+    }
+    let initials_size = 88.0;
+    let initials_width = text_width(&bold, &card.initials, initials_size);
+    draw_text(
+        &mut pixmap,
+        &bold,
+        &card.initials,
+        AVATAR_CENTER_X - initials_width / 2.0,
+        AVATAR_CENTER_Y + initials_size * 0.36,
+        initials_size,
+        AVATAR_TEXT_COLOR,
+    );
+
+    let mut y = 150.0;
+    for line in wrap(&bold, &card.name, 64.0, TEXT_WIDTH, 2) {
+        y += 64.0;
+        draw_text(&mut pixmap, &bold, &line, TEXT_LEFT, y, 64.0, TEXT_COLOR);
+        y += 12.0;
+    }
+    if let Some(headline) = card.headline.as_deref() {
+        y += 12.0;
+        for line in wrap(&regular, headline, 36.0, TEXT_WIDTH, 2) {
+            y += 36.0;
+            draw_text(
+                &mut pixmap,
+                &regular,
+                &line,
+                TEXT_LEFT,
+                y,
+                36.0,
+                MUTED_COLOR,
+            );
+            y += 10.0;
+        }
+    }
+    if let Some(position) = card.position.as_deref() {
+        y += 20.0;
+        for line in wrap(&regular, position, 32.0, TEXT_WIDTH, 1) {
+            y += 32.0;
+            draw_text(
+                &mut pixmap,
+                &regular,
+                &line,
+                TEXT_LEFT,
+                y,
+                32.0,
+                ACCENT_COLOR,
+            );
+        }
+    }
+
+    let footer_y = OG_IMAGE_HEIGHT as f32 - 60.0;
+    draw_text(
+        &mut pixmap,
+        &bold,
+        "Ideno",
+        TEXT_LEFT,
+        footer_y,
+        30.0,
+        ACCENT_COLOR,
+    );
+    let handle = format!("@{}", card.username);
+    let handle = wrap(&regular, &handle, 30.0, TEXT_WIDTH - 160.0, 1).concat();
+    draw_text(
+        &mut pixmap,
+        &regular,
+        &handle,
+        OG_IMAGE_WIDTH as f32 - MARGIN - text_width(&regular, &handle, 30.0),
+        footer_y,
+        30.0,
+        MUTED_COLOR,
+    );
+
+    // The card is opaque, so its premultiplied pixels are the RGBA values to encode
+    let mut image = Vec::new();
+    let mut encoder = png::Encoder::new(&mut image, OG_IMAGE_WIDTH, OG_IMAGE_HEIGHT);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(pixmap.data()))
+        .map_err(|_| AppError::InternalError)?;
+
+    Ok(image)
+}
+
+struct CachedImage {
+    key: String,
+    image: Arc<Vec<u8>>,
+}
+
+#[derive(Clone)]
+pub struct OgImageService {
+    public_origin: Option<String>,
+    cache: Arc<Mutex<HashMap<i32, CachedImage>>>,
+}
+
+impl OgImageService {
+    pub fn new(public_origin: Option<&str>) -> Self {
+        OgImageService {
+            public_origin: public_origin.map(|origin| origin.trim_end_matches('/').to_string()),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Builds the absolute link to the preview image of a profile, which link previews require.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - The username of the user.
+    ///
+    /// # Returns
+    ///
+    /// Returns the URL of the image, or `None` if the public origin of the server is not configured.
+    ///
+    pub fn image_url(&self, username: &str) -> Option<String> {
+        self.public_origin
+            .as_ref()
+            .map(|origin| format!("{}/api/v1/profile/{}/og-image", origin, username))
+    }
+
+    /// Returns the preview image of a profile, rendering it unless the cached image shows the same card.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose card is rendered.
+    /// * `card` - The content of the card.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the key of the card and the PNG image.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if the image could not be rendered.
+    ///
+    pub fn get_og_image(
+        &self,
+        user_id: i32,
+        card: &ProfileCard,
+    ) -> Result<(String, Arc<Vec<u8>>), AppError> {
+        let key = card.key();
+
+        if let Some(cached) = self.cache.lock().unwrap().get(&user_id) {
+            if cached.key == key {
+                return Ok((key, cached.image.clone()));
+            }
+        }
+
+        let image = Arc::new(render_og_image(card)?);
+
+        let mut cache = self.cache.lock().unwrap();
+        if cache.len() >= MAX_CACHED_IMAGES {
+            cache.clear();
+        }
+        cache.insert(
+            user_id,
+            CachedImage {
+                key: key.clone(),
+                image: image.clone(),
+            },
+        );
+
+        Ok((key, image))
+    }
+
+    /// Drops the cached preview image of a profile, after changes to the profile or its experiences.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose profile changed.
+    ///
+    pub fn invalidate(&self, user_id: i32) {
+        self.cache.lock().unwrap().remove(&user_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::date::ProfileDate;
+
+    fn profile(first_name: &str, last_name: &str, headline: Option<&str>) -> PublicProfileModel {
+        PublicProfileModel {
+            first_name: Some(first_name.to_string()),
+            last_name: Some(last_name.to_string()),
+            pronouns: None,
+            headline: headline.map(str::to_string),
+            country: None,
+            city: None,
+            bio: None,
+        }
+    }
+
+    #[test]
+    fn test_profile_card() {
+        let experiences = vec![
+            PublicExperienceModel {
+                id: 2,
+                company: "Royal Society".to_string(),
+                organization_slug: None,
+                title: "Translator".to_string(),
+                start_date: ProfileDate::parse("1840"),
+                end_date: ProfileDate::parse("1842"),
+                is_current: false,
+                duration_months: None,
+                exp_type: None,
+                description: None,
+                verified: false,
+            },
+            PublicExperienceModel {
+                id: 1,
+                company: "Analytical Engines".to_string(),
+                organization_slug: None,
+                title: "Programmer".to_string(),
+                start_date: ProfileDate::parse("1842-09"),
+                end_date: None,
+                is_current: true,
+                duration_months: None,
+                exp_type: None,
+                description: None,
+                verified: false,
+            },
+        ];
+
+        let card = ProfileCard::new("ada", &profile("ada", "Lovelace", Some(" ")), &experiences);
+        assert_eq!(card.name, "ada Lovelace");
+        assert_eq!(card.initials, "AL");
+        assert_eq!(card.headline, None);
+        assert_eq!(
+            card.position.as_deref(),
+            Some("Programmer at Analytical Engines")
+        );
+
+        let changed = ProfileCard::new("ada", &profile("Ada", "Lovelace", None), &experiences);
+        assert_ne!(card.key(), changed.key());
+        assert_eq!(card.key(), card.clone().key());
+    }
+
+    #[test]
+    fn test_render_og_image() {
+        let card = ProfileCard {
+            username: "ada".to_string(),
+            name: "Augusta Ada King, Countess of Lovelace and Analyst of the Engine".to_string(),
+            initials: "AK".to_string(),
+            headline: Some("Mathematician, writer and the first programmer ".repeat(4)),
+            position: Some("Programmer at Analytical Engines".to_string()),
+        };
+
+        let image = render_og_image(&card).ok().unwrap();
+
+        let decoder = png::Decoder::new(image.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (OG_IMAGE_WIDTH, OG_IMAGE_HEIGHT));
+        // The accent bar, the background and the avatar
+        let pixel = |x: usize, y: usize| {
+            let index = (y * OG_IMAGE_WIDTH as usize + x) * 4;
+            [pixels[index], pixels[index + 1], pixels[index + 2]]
+        };
+        assert_eq!(pixel(4, 4), ACCENT_COLOR);
+        assert_eq!(pixel(OG_IMAGE_WIDTH as usize - 4, 4), BACKGROUND_COLOR);
+        assert_eq!(
+            pixel(AVATAR_CENTER_X as usize, AVATAR_CENTER_Y as usize - 80),
+            ACCENT_COLOR
+        );
+        // The name is drawn in the text column
+        let text_rows = 150..240;
+        assert!(text_rows
+            .flat_map(|y| (TEXT_LEFT as usize..OG_IMAGE_WIDTH as usize).map(move |x| (x, y)))
+            .any(|(x, y)| pixel(x, y) == TEXT_COLOR));
+
+        let wrapped = wrap(
+            &Face::parse(REGULAR_FONT, 0).unwrap(),
+            card.headline.as_deref().unwrap(),
+            36.0,
+            TEXT_WIDTH,
+            2,
+        );
+        assert_eq!(wrapped.len(), 2);
+        assert!(wrapped[1].ends_with('…'));
+    }
+}
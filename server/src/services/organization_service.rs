@@ -0,0 +1,727 @@
+use crate::models::organization::{
+    OrganizationMemberModel, OrganizationModel, OrganizationRoleModel, PublicOrganizationModel,
+};
+use crate::models::user::UserModel;
+use crate::response::error_handling::AppError;
+use crate::services::experience_service::normalize_company_name;
+use crate::services::project_service::is_http_url;
+use crate::IdenoPool;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AddOrganizationPayload {
+    pub name: String,
+    pub website: Option<String>,
+    pub logo_url: Option<String>,
+    pub org_type: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdateOrganizationPayload {
+    pub name: String,
+    pub website: Option<String>,
+    pub logo_url: Option<String>,
+    pub org_type: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct OrganizationSearchQuery {
+    pub q: String,
+    pub org_type: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GrantOrganizationRolePayload {
+    pub username: String,
+    pub role: String,
+}
+
+const ORGANIZATION_SEARCH_LIMIT: i32 = 10;
+
+#[derive(Clone)]
+pub struct OrganizationService {
+    db_pool: IdenoPool,
+}
+
+impl OrganizationService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        OrganizationService { db_pool }
+    }
+
+    /// Asynchronously searches organizations by name for autocompletion.
+    ///
+    /// Organizations whose name starts with the query are listed before those that only contain it.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The text typed by the user.
+    /// * `org_type` - An optional organization type to restrict the search to.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing at most ten `PublicOrganizationModel` instances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn search_organizations(
+        &self,
+        query: &str,
+        org_type: Option<String>,
+    ) -> Result<Vec<PublicOrganizationModel>, AppError> {
+        let pattern = escape_like_pattern(query.trim());
+
+        sqlx::query_as::<_, PublicOrganizationModel>(
+            "SELECT
+                id,
+                name,
+                slug,
+                website,
+                logo_url,
+                org_type
+              FROM organizations
+              WHERE name LIKE '%' || $1 || '%' ESCAPE '\\'
+                AND ($2 IS NULL OR org_type = $2)
+              ORDER BY name LIKE $1 || '%' ESCAPE '\\' DESC, name
+              LIMIT $3",
+        )
+        .bind(pattern)
+        .bind(org_type)
+        .bind(ORGANIZATION_SEARCH_LIMIT)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously retrieves an organization by its slug.
+    ///
+    /// # Arguments
+    ///
+    /// * `slug` - The slug of the organization to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `PublicOrganizationModel` of the organization.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if no organization has the given slug.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_organization_by_slug(
+        &self,
+        slug: &str,
+    ) -> Result<PublicOrganizationModel, AppError> {
+        sqlx::query_as::<_, PublicOrganizationModel>(
+            "SELECT
+                id,
+                name,
+                slug,
+                website,
+                logo_url,
+                org_type
+              FROM organizations
+              WHERE slug = $1",
+        )
+        .bind(slug)
+        .fetch_optional(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?
+        .ok_or(AppError::NotFound {
+            error: "Organization not found".to_string(),
+        })
+    }
+
+    /// Asynchronously retrieves an organization by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` - The ID of the organization to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `OrganizationModel` of the organization.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the organization does not exist.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_organization(
+        &self,
+        organization_id: i32,
+    ) -> Result<OrganizationModel, AppError> {
+        sqlx::query_as::<_, OrganizationModel>("SELECT * FROM organizations WHERE id = $1")
+            .bind(organization_id)
+            .fetch_optional(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)?
+            .ok_or(AppError::NotFound {
+                error: "Organization not found".to_string(),
+            })
+    }
+
+    /// Asynchronously retrieves the members of an organization whose profiles the viewer may see.
+    ///
+    /// A member is a user with at least one experience or education linked to the organization.
+    /// Each member is listed once, with the role of their current entry if they have one.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` - The ID of the organization.
+    /// * `viewer` - The logged-in user viewing the page, if any. Admins see every member.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `OrganizationMemberModel` instances, current members first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_organization_members(
+        &self,
+        organization_id: i32,
+        viewer: &Option<UserModel>,
+    ) -> Result<Vec<OrganizationMemberModel>, AppError> {
+        let viewer_id = viewer.as_ref().map(|viewer| viewer.id);
+        let is_admin = matches!(viewer, Some(viewer) if viewer.role == "admin");
+
+        sqlx::query_as::<_, OrganizationMemberModel>(
+            "SELECT
+                users.username,
+                profiles.first_name,
+                profiles.last_name,
+                profiles.headline,
+                entries.role,
+                MAX(entries.is_current) AS is_current
+              FROM (
+                SELECT user_id, title AS role, is_current FROM experiences WHERE organization_id = $1
+                UNION ALL
+                SELECT user_id, degree AS role, FALSE AS is_current FROM educations WHERE organization_id = $1
+              ) AS entries
+              JOIN users ON users.id = entries.user_id
+              JOIN profiles ON profiles.user_id = entries.user_id
+              WHERE profiles.visibility = 'public'
+                OR (profiles.visibility = 'members' AND $2 IS NOT NULL)
+                OR profiles.user_id = $2
+                OR $3
+              GROUP BY users.id
+              ORDER BY is_current DESC, users.username",
+        )
+        .bind(organization_id)
+        .bind(viewer_id)
+        .bind(is_admin)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Validates the name and links of an organization.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A reference to the name of the organization.
+    /// * `website` - An optional reference to the website of the organization.
+    /// * `logo_url` - An optional reference to the logo URL of the organization.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the organization is valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the name is empty or too long,
+    /// or if the website or logo URL is not an http(s) URL.
+    ///
+    pub fn validate_organization(
+        &self,
+        name: &str,
+        website: &Option<String>,
+        logo_url: &Option<String>,
+    ) -> Result<bool, AppError> {
+        if organization_slug(name).is_empty() {
+            return Err(AppError::BadRequest {
+                error: Some("Organization name must contain letters or digits".to_string()),
+            });
+        }
+
+        if name.chars().count() > 255 {
+            return Err(AppError::BadRequest {
+                error: Some("Organization name must not exceed 255 characters".to_string()),
+            });
+        }
+
+        if let Some(website) = website {
+            if !is_http_url(website) {
+                return Err(AppError::BadRequest {
+                    error: Some("Website must be an http(s) URL".to_string()),
+                });
+            }
+        }
+
+        if let Some(logo_url) = logo_url {
+            if !is_http_url(logo_url) {
+                return Err(AppError::BadRequest {
+                    error: Some("Logo URL must be an http(s) URL".to_string()),
+                });
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Asynchronously checks that an entry may be linked to an organization.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` - The optional ID of the organization the entry links to.
+    /// * `org_type` - The organization type the entry requires, `company` for experiences and `school` for educations.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(true)` if no organization is linked or the organization has the required type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the organization does not exist.
+    /// Returns an `AppError::BadRequest` if the organization has another type.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn validate_organization_link(
+        &self,
+        organization_id: Option<i32>,
+        org_type: &str,
+    ) -> Result<bool, AppError> {
+        let Some(organization_id) = organization_id else {
+            return Ok(true);
+        };
+
+        let organization = self.get_organization(organization_id).await?;
+
+        if organization.org_type != org_type {
+            return Err(AppError::BadRequest {
+                error: Some(format!("Organization must be a {}", org_type)),
+            });
+        }
+
+        Ok(true)
+    }
+
+    /// Asynchronously creates a new organization.
+    ///
+    /// The slug is derived from the normalized name, so "Acme Inc." and "ACME" end up as the same organization.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user creating the organization.
+    /// * `payload` - An `AddOrganizationPayload` containing the details of the organization.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created organization.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::DataConflict` if an organization with the same slug already exists.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_organization(
+        &self,
+        user_id: i32,
+        payload: AddOrganizationPayload,
+    ) -> Result<i64, AppError> {
+        let slug = organization_slug(&payload.name);
+        self.check_slug_available(&slug, None).await?;
+
+        sqlx::query_as::<_, (i64,)>(
+            "INSERT INTO organizations (name, slug, website, logo_url, org_type, created_by) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id",
+        )
+        .bind(payload.name.trim())
+        .bind(slug)
+        .bind(payload.website)
+        .bind(payload.logo_url)
+        .bind(payload.org_type)
+        .bind(user_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|id| id.0)
+    }
+
+    /// Asynchronously updates an organization for administrative purposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` - The ID of the organization to be updated.
+    /// * `payload` - An `UpdateOrganizationPayload` containing the updated data for the organization.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the organization was updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::DataConflict` if another organization already uses the slug of the new name.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn update_organization(
+        &self,
+        organization_id: i32,
+        payload: UpdateOrganizationPayload,
+    ) -> Result<(), AppError> {
+        let slug = organization_slug(&payload.name);
+        self.check_slug_available(&slug, Some(organization_id))
+            .await?;
+
+        sqlx::query(
+            "UPDATE organizations SET name = $1, slug = $2, website = $3, logo_url = $4, org_type = $5 WHERE id = $6",
+        )
+        .bind(payload.name.trim())
+        .bind(slug)
+        .bind(payload.website)
+        .bind(payload.logo_url)
+        .bind(payload.org_type)
+        .bind(organization_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|_| ())
+    }
+
+    /// Asynchronously checks that no other organization uses a slug.
+    async fn check_slug_available(
+        &self,
+        slug: &str,
+        organization_id: Option<i32>,
+    ) -> Result<(), AppError> {
+        let count = sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM organizations WHERE slug = $1 AND ($2 IS NULL OR id != $2)",
+        )
+        .bind(slug)
+        .bind(organization_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        if count.0 > 0 {
+            return Err(AppError::DataConflict {
+                error: "Organization already exists".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Asynchronously retrieves the users holding a role on an organization.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` - The ID of the organization.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `OrganizationRoleModel` instances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_organization_roles(
+        &self,
+        organization_id: i32,
+    ) -> Result<Vec<OrganizationRoleModel>, AppError> {
+        sqlx::query_as::<_, OrganizationRoleModel>(
+            "SELECT
+                organization_roles.user_id,
+                users.username,
+                organization_roles.role,
+                organization_roles.created_at
+              FROM organization_roles
+              JOIN users ON users.id = organization_roles.user_id
+              WHERE organization_roles.organization_id = $1
+              ORDER BY organization_roles.role, users.username",
+        )
+        .bind(organization_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously checks that a user holds one of the given roles on an organization.
+    ///
+    /// Site admins pass the check for every organization, which is how the first organization admin gets appointed.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` - The ID of the organization.
+    /// * `user` - The user to check.
+    /// * `roles` - The roles that are sufficient.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the user holds one of the roles or is a site admin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::Forbidden` if the user holds none of the roles.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn check_organization_role(
+        &self,
+        organization_id: i32,
+        user: &UserModel,
+        roles: &[&str],
+    ) -> Result<(), AppError> {
+        if user.role == "admin" {
+            return Ok(());
+        }
+
+        let role = sqlx::query_as::<_, (String,)>(
+            "SELECT role FROM organization_roles WHERE organization_id = $1 AND user_id = $2",
+        )
+        .bind(organization_id)
+        .bind(user.id)
+        .fetch_optional(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        match role {
+            Some((role,)) if roles.contains(&role.as_str()) => Ok(()),
+            _ => Err(AppError::Forbidden { error: None }),
+        }
+    }
+
+    /// Asynchronously grants a user a role on an organization, replacing the role the user held before.
+    ///
+    /// The last admin of an organization cannot be demoted, so the organization stays manageable by its members.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` - The ID of the organization.
+    /// * `user_id` - The ID of the user to be granted the role.
+    /// * `role` - The role to be granted.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the role was granted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::DataConflict` if the user is the last admin of the organization and would be demoted.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn grant_organization_role(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+        role: &str,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "INSERT INTO organization_roles (organization_id, user_id, role) VALUES ($1, $2, $3)
+              ON CONFLICT (organization_id, user_id) DO UPDATE SET role = excluded.role
+              WHERE organization_roles.role != 'admin'
+                OR excluded.role = 'admin'
+                OR (SELECT COUNT(*) FROM organization_roles WHERE organization_id = $1 AND role = 'admin') > 1",
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .bind(role)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        if result.rows_affected() == 0 {
+            return Err(last_admin_error());
+        }
+
+        Ok(())
+    }
+
+    /// Asynchronously revokes the role a user holds on an organization.
+    ///
+    /// The last admin of an organization cannot be removed, so the organization stays manageable by its members.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` - The ID of the organization.
+    /// * `user_id` - The ID of the user whose role is to be revoked.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the role was revoked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the user holds no role on the organization.
+    /// Returns an `AppError::DataConflict` if the user is the last admin of the organization.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn revoke_organization_role(
+        &self,
+        organization_id: i32,
+        user_id: i32,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "DELETE FROM organization_roles
+              WHERE organization_id = $1
+                AND user_id = $2
+                AND (role != 'admin'
+                  OR (SELECT COUNT(*) FROM organization_roles WHERE organization_id = $1 AND role = 'admin') > 1)",
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        if result.rows_affected() > 0 {
+            return Ok(());
+        }
+
+        let has_role = sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM organization_roles WHERE organization_id = $1 AND user_id = $2",
+        )
+        .bind(organization_id)
+        .bind(user_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        match has_role.0 > 0 {
+            true => Err(last_admin_error()),
+            false => Err(AppError::NotFound {
+                error: "Organization role not found".to_string(),
+            }),
+        }
+    }
+
+    /// Asynchronously deletes an organization from the database for administrative purposes.
+    ///
+    /// Linked experiences and educations keep their free-text company or school.
+    ///
+    /// # Arguments
+    ///
+    /// * `organization_id` - The ID of the organization to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), AppError>` indicating the outcome of the delete operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn admin_delete_organization(&self, organization_id: i32) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM organizations WHERE id = $1")
+            .bind(organization_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+}
+
+/// Derives the URL slug of an organization from its name.
+///
+/// The name is normalized like company names are for grouping, then its words are joined with dashes.
+///
+pub(crate) fn organization_slug(name: &str) -> String {
+    normalize_company_name(name).replace(' ', "-")
+}
+
+/// Escapes the wildcards of a `LIKE` pattern, using `\` as the escape character.
+fn escape_like_pattern(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+fn last_admin_error() -> AppError {
+    AppError::DataConflict {
+        error: "An organization must keep at least one admin".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        escape_like_pattern, organization_slug, AddOrganizationPayload, OrganizationService,
+    };
+    use crate::test_utils::{create_test_user, test_pool};
+
+    #[test]
+    fn test_organization_slug() {
+        assert_eq!(organization_slug("Acme"), "acme");
+        assert_eq!(organization_slug("ACME, Inc."), "acme");
+        assert_eq!(organization_slug("  Big   Data GmbH "), "big-data");
+        assert_eq!(organization_slug("Universität Wien"), "universität-wien");
+        assert_eq!(organization_slug("AT&T"), "at-t");
+        assert_eq!(organization_slug("--"), "");
+    }
+
+    #[test]
+    fn test_escape_like_pattern() {
+        assert_eq!(escape_like_pattern("acme"), "acme");
+        assert_eq!(escape_like_pattern("100%"), "100\\%");
+        assert_eq!(escape_like_pattern("a_b\\c"), "a\\_b\\\\c");
+    }
+
+    #[tokio::test]
+    async fn test_last_organization_admin() {
+        let pool = test_pool().await;
+        let admin_id = create_test_user(&pool, "admin").await;
+        let member_id = create_test_user(&pool, "member").await;
+        let service = OrganizationService::new(pool);
+
+        let organization_id = service
+            .create_organization(
+                admin_id,
+                AddOrganizationPayload {
+                    name: "Acme".to_string(),
+                    website: None,
+                    logo_url: None,
+                    org_type: "company".to_string(),
+                },
+            )
+            .await
+            .ok()
+            .unwrap() as i32;
+        service
+            .grant_organization_role(organization_id, admin_id, "admin")
+            .await
+            .ok()
+            .unwrap();
+
+        // The only admin can neither leave nor be demoted
+        assert!(service
+            .revoke_organization_role(organization_id, admin_id)
+            .await
+            .is_err());
+        assert!(service
+            .grant_organization_role(organization_id, admin_id, "verifier")
+            .await
+            .is_err());
+
+        // Once there is a second admin, the first one can leave
+        service
+            .grant_organization_role(organization_id, member_id, "admin")
+            .await
+            .ok()
+            .unwrap();
+        service
+            .revoke_organization_role(organization_id, admin_id)
+            .await
+            .ok()
+            .unwrap();
+
+        let roles = service.get_organization_roles(organization_id).await.ok().unwrap();
+        assert_eq!(roles.len(), 1);
+        assert_eq!(roles[0].user_id, member_id);
+        assert!(service
+            .revoke_organization_role(organization_id, admin_id)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,390 @@
+use crate::models::patent::{AuthPatentModel, PatentModel, PublicPatentModel};
+use crate::response::error_handling::AppError;
+use crate::routes::api::auth::profile::patent::PatentStatus;
+use crate::{IdenoDBResult, IdenoPool};
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AddPatentPayload {
+    pub title: String,
+    pub patent_number: String,
+    pub office: String,
+    pub status: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdatePatentPayload {
+    pub title: String,
+    pub patent_number: String,
+    pub office: String,
+    pub status: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct PatentService {
+    db_pool: IdenoPool,
+}
+
+impl PatentService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        PatentService { db_pool }
+    }
+
+    /// Asynchronously retrieves authenticated patents associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the authenticated patents are being retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `AuthPatentModel` representing authenticated patents associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_authenticated_patents(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<AuthPatentModel>, AppError> {
+        sqlx::query_as::<_, AuthPatentModel>(
+            "SELECT
+                id,
+                title,
+                patent_number,
+                office,
+                status,
+                url
+              FROM patents
+              WHERE user_id = ?
+              ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously retrieves public patents associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the public patents are being retrieved.
+    /// * `limit` - An optional limit on the number of patents to retrieve. If not provided, all patents are fetched.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `PublicPatentModel` representing public patents associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_public_patents(
+        &self,
+        user_id: i32,
+        limit: Option<i32>,
+    ) -> Result<Vec<PublicPatentModel>, AppError> {
+        let limit = limit.unwrap_or(-1);
+        sqlx::query_as::<_, PublicPatentModel>(
+            "SELECT
+                title,
+                patent_number,
+                office,
+                status,
+                url
+              FROM patents
+              WHERE user_id = $1
+              ORDER BY created_at DESC
+              LIMIT $2",
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously checks if a user owns a specific patent.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose ownership of the patent is being checked.
+    /// * `patent_id` - The ID of the patent being checked for ownership.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an optional boolean value indicating whether the user owns the patent.
+    /// - `Some(true)` if the user owns the patent.
+    /// - `Some(false)` if the user does not own the patent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the patent with the provided ID could not be checked.
+    ///
+    pub async fn user_owns_patent(
+        &self,
+        user_id: i32,
+        patent_id: i32,
+    ) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM patents WHERE id = $1 AND user_id = $2",
+        )
+        .bind(patent_id)
+        .bind(user_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map_or_else(|_| None, |count| Some(count.0 > 0));
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Patent not found".to_string(),
+            }),
+        }
+    }
+
+    /// Asynchronously deletes a patent entry associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the patent entry to be deleted.
+    /// * `patent_id` - The ID of the patent entry to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the delete operation. If the patent entry is successfully deleted,
+    /// it returns `Ok(IdenoDBResult)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn delete_patent(
+        &self,
+        user_id: i32,
+        patent_id: i32,
+    ) -> Result<IdenoDBResult, AppError> {
+        sqlx::query("DELETE FROM patents WHERE id = $1 AND user_id = $2")
+            .bind(patent_id)
+            .bind(user_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously updates a patent entry associated with a user in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the patent entry to be updated.
+    /// * `patent_id` - The ID of the patent entry to be updated.
+    /// * `payload` - An `UpdatePatentPayload` containing the updated patent details.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the update operation. If the patent entry is successfully updated,
+    /// it returns `Ok(IdenoDBResult)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn update_patent(
+        &self,
+        user_id: i32,
+        patent_id: i32,
+        payload: UpdatePatentPayload,
+    ) -> Result<IdenoDBResult, AppError> {
+        sqlx::query("UPDATE patents SET title = $1, patent_number = $2, office = $3, status = $4, url = $5 WHERE id = $6 AND user_id = $7")
+            .bind(payload.title)
+            .bind(payload.patent_number)
+            .bind(payload.office)
+            .bind(payload.status)
+            .bind(payload.url)
+            .bind(patent_id)
+            .bind(user_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously creates a new patent entry associated with a user in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the patent is being created.
+    /// * `payload` - An `AddPatentPayload` containing the details of the patent to be created.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created patent entry if successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_patent(
+        &self,
+        user_id: i32,
+        payload: AddPatentPayload,
+    ) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>("INSERT INTO patents (user_id, title, patent_number, office, status, url) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id")
+            .bind(user_id)
+            .bind(payload.title)
+            .bind(payload.patent_number)
+            .bind(payload.office)
+            .bind(payload.status)
+            .bind(payload.url)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|id| id.0)
+    }
+
+    /// Validates the patent title, number, office and status.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the patent.
+    /// * `patent_number` - The number the patent was filed or granted under.
+    /// * `office` - The patent office, e.g. `EPO` or `USPTO`.
+    /// * `status` - An optional reference to the status of the patent.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the patent is valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if a required field is empty or the status is invalid.
+    ///
+    pub fn validate_patent(
+        &self,
+        title: &str,
+        patent_number: &str,
+        office: &str,
+        status: &Option<String>,
+    ) -> Result<bool, AppError> {
+        if title.trim().is_empty() || patent_number.trim().is_empty() || office.trim().is_empty() {
+            return Err(AppError::BadRequest {
+                error: Some("Title, patent number and office are required".to_string()),
+            });
+        }
+
+        if let Some(status) = status {
+            if status.is_empty() {
+                return Ok(true);
+            };
+
+            if PatentStatus::from_str(status).is_none() {
+                return Err(AppError::BadRequest {
+                    error: Some("Invalid patent status".to_string()),
+                });
+            };
+        }
+
+        Ok(true)
+    }
+
+    /// Asynchronously retrieves the count of patent entries associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the count of patent entries is being retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the count of patent entries associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_patent_count(&self, user_id: i32) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM patents WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|count| count.0)
+    }
+
+    /// Asynchronously retrieves all patent entries associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the patent entries are being retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `PatentModel` representing all patent entries associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_all_patents(&self, user_id: i32) -> Result<Vec<PatentModel>, AppError> {
+        sqlx::query_as::<_, PatentModel>("SELECT * FROM patents WHERE user_id = $1 ORDER BY created_at DESC")
+            .bind(user_id)
+            .fetch_all(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously deletes a patent entry from the database for administrative purposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `patent_id` - The ID of the patent entry to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the delete operation. If the operation is successful, it returns `Ok(())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn admin_delete_patent(&self, patent_id: i32) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM patents WHERE id = $1")
+            .bind(patent_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+
+    /// Asynchronously checks if a patent entry exists in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `patent_id` - The ID of the patent entry to be checked.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an optional boolean value.
+    /// If the patent entry exists, it returns `Some(true)`, otherwise `Some(false)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` with an error message if the patent entry could not be checked.
+    ///
+    pub async fn patent_exists(&self, patent_id: i32) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM patents WHERE id = $1")
+            .bind(patent_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map_or_else(|_| None, |count| Some(count.0 > 0));
+
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Patent not found".to_string(),
+            }),
+        }
+    }
+}
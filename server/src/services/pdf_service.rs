@@ -0,0 +1,989 @@
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use pdf_writer::types::{CidFontType, FontFlags, SystemInfo, UnicodeCmap};
+use pdf_writer::{Content, Filter, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+use serde::Deserialize;
+use ttf_parser::{Face, GlyphId};
+
+use crate::models::certification::PublicCertificationModel;
+use crate::models::contact_type::ContactTypeModel;
+use crate::models::custom_section::PublicCustomSectionModel;
+use crate::models::date::ProfileDate;
+use crate::models::education::PublicEducationModel;
+use crate::models::experience::PublicExperienceModel;
+use crate::models::language::PublicLanguageModel;
+use crate::models::profile::PublicProfileModel;
+use crate::models::project::PublicProjectModel;
+use crate::models::resume::ProfileExportModel;
+use crate::response::error_handling::AppError;
+
+pub(crate) const REGULAR_FONT: &[u8] = include_bytes!("../../fonts/DejaVuSans.ttf");
+pub(crate) const BOLD_FONT: &[u8] = include_bytes!("../../fonts/DejaVuSans-Bold.ttf");
+
+/// A4 in PostScript points.
+const PAGE_WIDTH: f32 = 595.28;
+const PAGE_HEIGHT: f32 = 841.89;
+const MARGIN: f32 = 48.0;
+const CONTENT_TOP: f32 = PAGE_HEIGHT - MARGIN;
+/// The lowest point content may reach, leaving room for the page numbers.
+const CONTENT_BOTTOM: f32 = MARGIN + 12.0;
+
+const SIDEBAR_WIDTH: f32 = 178.0;
+const SIDEBAR_PADDING: f32 = 22.0;
+const SIDEBAR_GAP: f32 = 26.0;
+
+const LINE_SPACING: f32 = 1.4;
+const COLUMN_GAP: f32 = 12.0;
+
+const TEXT_COLOR: [f32; 3] = [0.13, 0.13, 0.15];
+const MUTED_COLOR: [f32; 3] = [0.42, 0.42, 0.46];
+const RULE_COLOR: [f32; 3] = [0.78, 0.78, 0.8];
+const SIDEBAR_COLOR: [f32; 3] = [0.93, 0.95, 0.97];
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+#[derive(Deserialize)]
+pub struct ResumePdfQuery {
+    pub template: Option<String>,
+}
+
+/// The layouts a PDF resume can be rendered with.
+///
+/// * `Classic` - a single column below a centered header.
+/// * `Sidebar` - a tinted sidebar with contact details, languages and certifications next to the main column.
+///
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PdfTemplate {
+    Classic,
+    Sidebar,
+}
+
+impl PdfTemplate {
+    pub(crate) fn from_str(s: &str) -> Option<PdfTemplate> {
+        match s {
+            "classic" => Some(PdfTemplate::Classic),
+            "sidebar" => Some(PdfTemplate::Sidebar),
+            _ => None,
+        }
+    }
+
+    fn accent(&self) -> [f32; 3] {
+        match self {
+            PdfTemplate::Classic => TEXT_COLOR,
+            PdfTemplate::Sidebar => [0.12, 0.33, 0.55],
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct TextStyle {
+    bold: bool,
+    size: f32,
+    color: [f32; 3],
+}
+
+impl TextStyle {
+    fn line_height(&self) -> f32 {
+        self.size * LINE_SPACING
+    }
+
+    fn with_color(self, color: [f32; 3]) -> TextStyle {
+        TextStyle { color, ..self }
+    }
+}
+
+const NAME_STYLE: TextStyle = TextStyle {
+    bold: true,
+    size: 22.0,
+    color: TEXT_COLOR,
+};
+const HEADLINE_STYLE: TextStyle = TextStyle {
+    bold: false,
+    size: 11.5,
+    color: MUTED_COLOR,
+};
+const HEADING_STYLE: TextStyle = TextStyle {
+    bold: true,
+    size: 10.5,
+    color: TEXT_COLOR,
+};
+const TITLE_STYLE: TextStyle = TextStyle {
+    bold: true,
+    size: 10.0,
+    color: TEXT_COLOR,
+};
+const BODY_STYLE: TextStyle = TextStyle {
+    bold: false,
+    size: 9.5,
+    color: TEXT_COLOR,
+};
+const META_STYLE: TextStyle = TextStyle {
+    bold: false,
+    size: 9.0,
+    color: MUTED_COLOR,
+};
+const SMALL_STYLE: TextStyle = TextStyle {
+    bold: false,
+    size: 8.0,
+    color: MUTED_COLOR,
+};
+
+#[derive(Clone, Copy)]
+enum Align {
+    Left,
+    Center,
+}
+
+/// A TrueType font embedded into the PDF, which keeps track of the glyphs in use so that only those are embedded.
+struct EmbeddedFont {
+    data: &'static [u8],
+    face: Face<'static>,
+    base_font: &'static str,
+    resource: &'static [u8],
+    used: BTreeMap<u16, char>,
+}
+
+impl EmbeddedFont {
+    fn new(
+        data: &'static [u8],
+        base_font: &'static str,
+        resource: &'static [u8],
+    ) -> Result<Self, AppError> {
+        let face = Face::parse(data, 0).map_err(|_| AppError::InternalError)?;
+
+        Ok(EmbeddedFont {
+            data,
+            face,
+            base_font,
+            resource,
+            used: BTreeMap::new(),
+        })
+    }
+
+    /// Returns the glyph of a character, falling back to a question mark for characters the font does not cover.
+    fn glyph(&self, c: char) -> u16 {
+        self.face
+            .glyph_index(c)
+            .or_else(|| self.face.glyph_index('?'))
+            .map_or(0, |glyph| glyph.0)
+    }
+
+    /// Returns the advance of a glyph in thousandths of the font size, the unit of PDF glyph widths.
+    fn advance(&self, glyph: u16) -> f32 {
+        let advance = self.face.glyph_hor_advance(GlyphId(glyph)).unwrap_or(0);
+
+        f32::from(advance) * 1000.0 / f32::from(self.face.units_per_em())
+    }
+
+    fn width(&self, text: &str, size: f32) -> f32 {
+        let advances: f32 = text.chars().map(|c| self.advance(self.glyph(c))).sum();
+
+        advances * size / 1000.0
+    }
+
+    /// Encodes a text as the two-byte glyph IDs of the `Identity-H` encoding.
+    fn encode(&mut self, text: &str) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(text.len() * 2);
+
+        for c in text.chars() {
+            let glyph = self.glyph(c);
+            self.used.entry(glyph).or_insert(c);
+            encoded.extend_from_slice(&glyph.to_be_bytes());
+        }
+
+        encoded
+    }
+}
+
+/// The pages of a resume while they are laid out.
+struct Layout {
+    template: PdfTemplate,
+    regular: EmbeddedFont,
+    bold: EmbeddedFont,
+    pages: Vec<Content>,
+}
+
+impl Layout {
+    fn new(template: PdfTemplate) -> Result<Self, AppError> {
+        Ok(Layout {
+            template,
+            regular: EmbeddedFont::new(REGULAR_FONT, "IDENOR+DejaVuSans", b"F1")?,
+            bold: EmbeddedFont::new(BOLD_FONT, "IDENOB+DejaVuSans-Bold", b"F2")?,
+            pages: Vec::new(),
+        })
+    }
+
+    fn font(&self, bold: bool) -> &EmbeddedFont {
+        match bold {
+            true => &self.bold,
+            false => &self.regular,
+        }
+    }
+
+    /// Returns the content of a page, adding pages with the background of the template up to it.
+    fn page(&mut self, index: usize) -> &mut Content {
+        while self.pages.len() <= index {
+            let mut content = Content::new();
+
+            if self.template == PdfTemplate::Sidebar {
+                let [r, g, b] = SIDEBAR_COLOR;
+                content.set_fill_rgb(r, g, b);
+                content.rect(0.0, 0.0, SIDEBAR_WIDTH, PAGE_HEIGHT);
+                content.fill_nonzero();
+            }
+
+            self.pages.push(content);
+        }
+
+        &mut self.pages[index]
+    }
+
+    fn text(&mut self, page: usize, x: f32, y: f32, text: &str, style: TextStyle) {
+        let font = match style.bold {
+            true => &mut self.bold,
+            false => &mut self.regular,
+        };
+        let resource = font.resource;
+        let encoded = font.encode(text);
+
+        let [r, g, b] = style.color;
+        let content = self.page(page);
+        content.set_fill_rgb(r, g, b);
+        content.begin_text();
+        content.set_font(Name(resource), style.size);
+        content.next_line(x, y);
+        content.show(Str(&encoded));
+        content.end_text();
+    }
+
+    fn rule(&mut self, page: usize, x: f32, width: f32, y: f32) {
+        let [r, g, b] = RULE_COLOR;
+        let content = self.page(page);
+        content.set_stroke_rgb(r, g, b);
+        content.set_line_width(0.6);
+        content.move_to(x, y);
+        content.line_to(x + width, y);
+        content.stroke();
+    }
+}
+
+/// A column of a resume that flows from page to page, with `y` as the top of the next line.
+struct Column {
+    x: f32,
+    width: f32,
+    y: f32,
+    page: usize,
+}
+
+impl Column {
+    fn new(x: f32, width: f32) -> Self {
+        Column {
+            x,
+            width,
+            y: CONTENT_TOP,
+            page: 0,
+        }
+    }
+
+    /// Moves the column to the next page unless the given height still fits on the current one.
+    fn reserve(&mut self, height: f32) {
+        if self.y - height < CONTENT_BOTTOM && self.y < CONTENT_TOP {
+            self.page += 1;
+            self.y = CONTENT_TOP;
+        }
+    }
+
+    fn space(&mut self, height: f32) {
+        if self.y < CONTENT_TOP {
+            self.y -= height;
+        }
+    }
+
+    fn baseline(&self, style: TextStyle) -> f32 {
+        self.y - style.size
+    }
+
+    fn paragraph(&mut self, layout: &mut Layout, text: &str, style: TextStyle, align: Align) {
+        let font = layout.font(style.bold);
+        let lines = wrap(font, text, style.size, self.width);
+
+        for line in lines {
+            self.reserve(style.line_height());
+
+            let x = match align {
+                Align::Left => self.x,
+                Align::Center => {
+                    let width = layout.font(style.bold).width(&line, style.size);
+                    self.x + (self.width - width) / 2.0
+                }
+            };
+            layout.text(self.page, x, self.baseline(style), &line, style);
+
+            self.y -= style.line_height();
+        }
+    }
+
+    /// Writes a text with a second text aligned to the right edge of its first line, such as a title and its dates.
+    fn row(
+        &mut self,
+        layout: &mut Layout,
+        left: &str,
+        left_style: TextStyle,
+        right: &str,
+        right_style: TextStyle,
+    ) {
+        let right_width = layout.font(right_style.bold).width(right, right_style.size);
+        let left_width = match right.is_empty() {
+            true => self.width,
+            false => self.width - right_width - COLUMN_GAP,
+        };
+        let lines = wrap(
+            layout.font(left_style.bold),
+            left,
+            left_style.size,
+            left_width,
+        );
+
+        for (index, line) in lines.iter().enumerate() {
+            self.reserve(left_style.line_height());
+
+            let baseline = self.baseline(left_style);
+            layout.text(self.page, self.x, baseline, line, left_style);
+            if index == 0 && !right.is_empty() {
+                let x = self.x + self.width - right_width;
+                layout.text(self.page, x, baseline, right, right_style);
+            }
+
+            self.y -= left_style.line_height();
+        }
+    }
+
+    /// Writes the heading of a section, kept on the same page as the first lines of the section.
+    fn heading(&mut self, layout: &mut Layout, title: &str) {
+        self.space(10.0);
+        self.reserve(HEADING_STYLE.line_height() + 4.0 + 3.0 * BODY_STYLE.line_height());
+
+        let style = HEADING_STYLE.with_color(layout.template.accent());
+        layout.text(
+            self.page,
+            self.x,
+            self.baseline(style),
+            &title.to_uppercase(),
+            style,
+        );
+        self.y -= style.line_height();
+        layout.rule(self.page, self.x, self.width, self.y + 2.0);
+        self.y -= 4.0;
+    }
+
+    /// Starts an entry of a section, moving it to the next page unless its first lines fit on the current one.
+    fn entry(&mut self) {
+        self.reserve(
+            TITLE_STYLE.line_height() + META_STYLE.line_height() + BODY_STYLE.line_height(),
+        );
+    }
+}
+
+/// Breaks a text into lines that fit into a width, keeping the line breaks of the text.
+///
+/// Words wider than the whole width are broken between characters.
+///
+fn wrap(font: &EmbeddedFont, text: &str, size: f32, width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for paragraph in text.trim().lines() {
+        let mut line = String::new();
+
+        for word in paragraph.split_whitespace() {
+            let candidate = match line.is_empty() {
+                true => word.to_string(),
+                false => format!("{} {}", line, word),
+            };
+            if font.width(&candidate, size) <= width {
+                line = candidate;
+                continue;
+            }
+
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            for c in word.chars() {
+                line.push(c);
+                if line.chars().count() > 1 && font.width(&line, size) > width {
+                    line.pop();
+                    lines.push(std::mem::replace(&mut line, c.to_string()));
+                }
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+fn non_empty(value: Option<&String>) -> Option<&str> {
+    value
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+pub(crate) fn format_date(date: &ProfileDate) -> String {
+    match date.month() {
+        Some(month) => format!("{} {}", MONTHS[usize::from(month) - 1], date.year()),
+        None => date.year().to_string(),
+    }
+}
+
+pub(crate) fn date_range(start: Option<String>, end: Option<String>) -> String {
+    match (start, end) {
+        (Some(start), Some(end)) if start == end => start,
+        (Some(start), Some(end)) => format!("{} – {}", start, end),
+        (Some(date), None) | (None, Some(date)) => date,
+        (None, None) => String::new(),
+    }
+}
+
+pub(crate) fn full_name(profile: &PublicProfileModel) -> Option<String> {
+    let parts: Vec<&str> = [profile.first_name.as_ref(), profile.last_name.as_ref()]
+        .into_iter()
+        .filter_map(non_empty)
+        .collect();
+
+    match parts.is_empty() {
+        true => None,
+        false => Some(parts.join(" ")),
+    }
+}
+
+/// Returns the label and value of the contact entries of a resume, leaving out masked entries.
+fn contact_entries(
+    export: &ProfileExportModel,
+    contact_types: &HashMap<String, ContactTypeModel>,
+) -> Vec<(String, String)> {
+    export
+        .contact_information
+        .iter()
+        .filter(|entry| !entry.masked)
+        .map(|entry| {
+            let label = non_empty(entry.label.as_ref())
+                .map(str::to_string)
+                .or_else(|| {
+                    contact_types
+                        .get(&entry.type_field)
+                        .map(|contact_type| contact_type.label.clone())
+                })
+                .unwrap_or_else(|| entry.type_field.clone());
+
+            (label, entry.value.clone())
+        })
+        .collect()
+}
+
+fn render_header(
+    layout: &mut Layout,
+    column: &mut Column,
+    profile: &PublicProfileModel,
+    align: Align,
+) {
+    if let Some(name) = full_name(profile) {
+        column.paragraph(layout, &name, NAME_STYLE, align);
+        column.space(2.0);
+    }
+    if let Some(headline) = non_empty(profile.headline.as_ref()) {
+        column.paragraph(layout, headline, HEADLINE_STYLE, align);
+    }
+
+    let location: Vec<&str> = [profile.city.as_ref(), profile.country.as_ref()]
+        .into_iter()
+        .filter_map(non_empty)
+        .collect();
+    let details: Vec<String> = [
+        Some(location.join(", ")).filter(|location| !location.is_empty()),
+        non_empty(profile.pronouns.as_ref()).map(str::to_string),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if !details.is_empty() {
+        column.paragraph(layout, &details.join("  ·  "), META_STYLE, align);
+    }
+}
+
+fn render_summary(layout: &mut Layout, column: &mut Column, profile: &PublicProfileModel) {
+    if let Some(bio) = non_empty(profile.bio.as_ref()) {
+        column.heading(layout, "Summary");
+        column.paragraph(layout, bio, BODY_STYLE, Align::Left);
+    }
+}
+
+fn render_experiences(
+    layout: &mut Layout,
+    column: &mut Column,
+    experiences: &[PublicExperienceModel],
+) {
+    if experiences.is_empty() {
+        return;
+    }
+
+    column.heading(layout, "Experience");
+    for experience in experiences {
+        let end = match experience.is_current {
+            true => Some("Present".to_string()),
+            false => experience.end_date.as_ref().map(format_date),
+        };
+        let dates = date_range(experience.start_date.as_ref().map(format_date), end);
+        let company = match non_empty(experience.exp_type.as_ref()) {
+            Some(exp_type) => format!("{}  ·  {}", experience.company, exp_type),
+            None => experience.company.clone(),
+        };
+
+        column.entry();
+        column.row(layout, &experience.title, TITLE_STYLE, &dates, META_STYLE);
+        column.paragraph(layout, &company, META_STYLE, Align::Left);
+        if let Some(description) = non_empty(experience.description.as_ref()) {
+            column.space(2.0);
+            column.paragraph(layout, description, BODY_STYLE, Align::Left);
+        }
+        column.space(6.0);
+    }
+}
+
+fn render_educations(
+    layout: &mut Layout,
+    column: &mut Column,
+    educations: &[PublicEducationModel],
+) {
+    if educations.is_empty() {
+        return;
+    }
+
+    column.heading(layout, "Education");
+    for education in educations {
+        let dates = date_range(
+            education.start_date.as_ref().map(format_date),
+            education.end_date.as_ref().map(format_date),
+        );
+        let degree: Vec<&str> = [education.degree.as_ref(), education.field.as_ref()]
+            .into_iter()
+            .filter_map(non_empty)
+            .collect();
+
+        column.entry();
+        column.row(layout, &education.school, TITLE_STYLE, &dates, META_STYLE);
+        if !degree.is_empty() {
+            column.paragraph(layout, &degree.join(", "), META_STYLE, Align::Left);
+        }
+        column.space(6.0);
+    }
+}
+
+fn render_projects(layout: &mut Layout, column: &mut Column, projects: &[PublicProjectModel]) {
+    if projects.is_empty() {
+        return;
+    }
+
+    column.heading(layout, "Projects");
+    for project in projects {
+        let dates = date_range(
+            project.start_date.as_ref().map(format_date),
+            project.end_date.as_ref().map(format_date),
+        );
+
+        column.entry();
+        column.row(layout, &project.name, TITLE_STYLE, &dates, META_STYLE);
+        if let Some(role) = non_empty(project.role.as_ref()) {
+            column.paragraph(layout, role, META_STYLE, Align::Left);
+        }
+        if let Some(description) = non_empty(project.description.as_ref()) {
+            column.space(2.0);
+            column.paragraph(layout, description, BODY_STYLE, Align::Left);
+        }
+        if !project.skills.is_empty() {
+            column.paragraph(layout, &project.skills.join(", "), SMALL_STYLE, Align::Left);
+        }
+        for url in [project.url.as_ref(), project.repository_url.as_ref()]
+            .into_iter()
+            .filter_map(non_empty)
+        {
+            column.paragraph(layout, url, SMALL_STYLE, Align::Left);
+        }
+        column.space(6.0);
+    }
+}
+
+fn render_custom_sections(
+    layout: &mut Layout,
+    column: &mut Column,
+    sections: &[PublicCustomSectionModel],
+) {
+    for section in sections
+        .iter()
+        .filter(|section| !section.entries.is_empty())
+    {
+        column.heading(layout, &section.title);
+        for entry in section.entries.iter() {
+            let dates = date_range(
+                entry.start_date.as_ref().map(format_date),
+                entry.end_date.as_ref().map(format_date),
+            );
+
+            column.entry();
+            column.row(layout, &entry.title, TITLE_STYLE, &dates, META_STYLE);
+            if let Some(subtitle) = non_empty(entry.subtitle.as_ref()) {
+                column.paragraph(layout, subtitle, META_STYLE, Align::Left);
+            }
+            if let Some(body) = non_empty(entry.body.as_ref()) {
+                column.space(2.0);
+                column.paragraph(layout, body, BODY_STYLE, Align::Left);
+            }
+            if let Some(link) = non_empty(entry.link.as_ref()) {
+                column.paragraph(layout, link, SMALL_STYLE, Align::Left);
+            }
+            column.space(6.0);
+        }
+    }
+}
+
+fn render_certifications(
+    layout: &mut Layout,
+    column: &mut Column,
+    certifications: &[PublicCertificationModel],
+) {
+    if certifications.is_empty() {
+        return;
+    }
+
+    column.heading(layout, "Certifications");
+    for certification in certifications {
+        let details: Vec<String> = [
+            Some(certification.organization.clone()),
+            certification.issue_date.as_ref().map(format_date),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        column.entry();
+        column.paragraph(layout, &certification.name, TITLE_STYLE, Align::Left);
+        column.paragraph(layout, &details.join("  ·  "), META_STYLE, Align::Left);
+        if let Some(credential_id) = non_empty(certification.credential_id.as_ref()) {
+            let credential = format!("Credential ID {}", credential_id);
+            column.paragraph(layout, &credential, SMALL_STYLE, Align::Left);
+        }
+        column.space(6.0);
+    }
+}
+
+fn render_languages(layout: &mut Layout, column: &mut Column, languages: &[PublicLanguageModel]) {
+    if languages.is_empty() {
+        return;
+    }
+
+    column.heading(layout, "Languages");
+    for language in languages {
+        column.row(
+            layout,
+            &language.language_code.to_uppercase(),
+            BODY_STYLE,
+            &language.level,
+            META_STYLE,
+        );
+    }
+}
+
+/// Renders a profile as a PDF resume.
+///
+/// Text is set in the embedded DejaVu Sans fonts, which are subset to the glyphs in use. Sections that do not
+/// fit on a page continue on the next one, and the pages are numbered when there is more than one.
+///
+/// # Arguments
+///
+/// * `export` - The parts of the profile visible to the viewer.
+/// * `contact_types` - The contact types by name, used to label contact entries.
+/// * `template` - The layout of the resume.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the bytes of the PDF document.
+///
+/// # Errors
+///
+/// Returns an `AppError::InternalError` if the embedded fonts could not be read or subset.
+///
+pub fn render_resume_pdf(
+    export: &ProfileExportModel,
+    contact_types: &HashMap<String, ContactTypeModel>,
+    template: PdfTemplate,
+) -> Result<Vec<u8>, AppError> {
+    let mut layout = Layout::new(template)?;
+    let contacts = contact_entries(export, contact_types);
+
+    match template {
+        PdfTemplate::Classic => {
+            let mut column = Column::new(MARGIN, PAGE_WIDTH - 2.0 * MARGIN);
+
+            render_header(&mut layout, &mut column, &export.profile, Align::Center);
+            if !contacts.is_empty() {
+                let values: Vec<&str> = contacts.iter().map(|(_, value)| value.as_str()).collect();
+                column.space(2.0);
+                column.paragraph(
+                    &mut layout,
+                    &values.join("  ·  "),
+                    META_STYLE,
+                    Align::Center,
+                );
+            }
+
+            render_summary(&mut layout, &mut column, &export.profile);
+            render_experiences(&mut layout, &mut column, &export.experiences);
+            render_educations(&mut layout, &mut column, &export.educations);
+            render_projects(&mut layout, &mut column, &export.projects);
+            render_certifications(&mut layout, &mut column, &export.certifications);
+            render_languages(&mut layout, &mut column, &export.languages);
+            render_custom_sections(&mut layout, &mut column, &export.custom_sections);
+        }
+        PdfTemplate::Sidebar => {
+            let mut sidebar = Column::new(SIDEBAR_PADDING, SIDEBAR_WIDTH - 2.0 * SIDEBAR_PADDING);
+            let main_x = SIDEBAR_WIDTH + SIDEBAR_GAP;
+            let mut main = Column::new(main_x, PAGE_WIDTH - main_x - MARGIN);
+
+            render_header(&mut layout, &mut main, &export.profile, Align::Left);
+            render_summary(&mut layout, &mut main, &export.profile);
+            render_experiences(&mut layout, &mut main, &export.experiences);
+            render_educations(&mut layout, &mut main, &export.educations);
+            render_projects(&mut layout, &mut main, &export.projects);
+            render_custom_sections(&mut layout, &mut main, &export.custom_sections);
+
+            if !contacts.is_empty() {
+                sidebar.heading(&mut layout, "Contact");
+                for (label, value) in contacts.iter() {
+                    sidebar.entry();
+                    sidebar.paragraph(&mut layout, label, SMALL_STYLE, Align::Left);
+                    sidebar.paragraph(&mut layout, value, BODY_STYLE, Align::Left);
+                    sidebar.space(4.0);
+                }
+            }
+            render_languages(&mut layout, &mut sidebar, &export.languages);
+            render_certifications(&mut layout, &mut sidebar, &export.certifications);
+        }
+    }
+
+    layout.page(0);
+    let page_count = layout.pages.len();
+    if page_count > 1 {
+        for index in 0..page_count {
+            let number = format!("{} / {}", index + 1, page_count);
+            let width = layout.regular.width(&number, SMALL_STYLE.size);
+            let x = PAGE_WIDTH - MARGIN - width;
+            layout.text(index, x, MARGIN - SMALL_STYLE.size, &number, SMALL_STYLE);
+        }
+    }
+
+    let title = full_name(&export.profile).unwrap_or_else(|| "Resume".to_string());
+
+    write_pdf(layout, &title)
+}
+
+fn deflate(data: &[u8]) -> Result<Vec<u8>, AppError> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(data)
+        .map_err(|_| AppError::InternalError)?;
+
+    encoder.finish().map_err(|_| AppError::InternalError)
+}
+
+fn write_pdf(layout: Layout, title: &str) -> Result<Vec<u8>, AppError> {
+    let mut next_ref = Ref::new(1);
+    let catalog_id = next_ref.bump();
+    let page_tree_id = next_ref.bump();
+    let info_id = next_ref.bump();
+    let regular_id = next_ref.bump();
+    let bold_id = next_ref.bump();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+
+    let page_ids: Vec<(Ref, Ref)> = layout
+        .pages
+        .iter()
+        .map(|_| (next_ref.bump(), next_ref.bump()))
+        .collect();
+    pdf.pages(page_tree_id)
+        .kids(page_ids.iter().map(|(page_id, _)| *page_id))
+        .count(page_ids.len() as i32);
+
+    for ((page_id, content_id), content) in page_ids.into_iter().zip(layout.pages) {
+        let mut page = pdf.page(page_id);
+        page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT))
+            .parent(page_tree_id)
+            .contents(content_id);
+        page.resources()
+            .fonts()
+            .pair(Name(layout.regular.resource), regular_id)
+            .pair(Name(layout.bold.resource), bold_id);
+        page.finish();
+
+        let data = deflate(&content.finish())?;
+        pdf.stream(content_id, &data).filter(Filter::FlateDecode);
+    }
+
+    write_font(&mut pdf, &mut next_ref, regular_id, &layout.regular)?;
+    write_font(&mut pdf, &mut next_ref, bold_id, &layout.bold)?;
+
+    pdf.document_info(info_id).title(TextStr(title));
+
+    Ok(pdf.finish())
+}
+
+/// Writes a font as a composite font with the `Identity-H` encoding, embedding a subset with the glyphs in use and a
+/// `ToUnicode` map so that text can be copied out of the document.
+fn write_font(
+    pdf: &mut Pdf,
+    next_ref: &mut Ref,
+    font_id: Ref,
+    font: &EmbeddedFont,
+) -> Result<(), AppError> {
+    let cid_font_id = next_ref.bump();
+    let descriptor_id = next_ref.bump();
+    let cmap_id = next_ref.bump();
+    let file_id = next_ref.bump();
+
+    let system_info = SystemInfo {
+        registry: Str(b"Adobe"),
+        ordering: Str(b"Identity"),
+        supplement: 0,
+    };
+    let base_font = Name(font.base_font.as_bytes());
+
+    pdf.type0_font(font_id)
+        .base_font(base_font)
+        .encoding_predefined(Name(b"Identity-H"))
+        .descendant_font(cid_font_id)
+        .to_unicode(cmap_id);
+
+    let mut cid_font = pdf.cid_font(cid_font_id);
+    cid_font
+        .subtype(CidFontType::Type2)
+        .base_font(base_font)
+        .system_info(system_info)
+        .font_descriptor(descriptor_id)
+        .default_width(0.0)
+        .cid_to_gid_map_predefined(Name(b"Identity"));
+    let mut widths = cid_font.widths();
+    for glyph in font.used.keys() {
+        widths.consecutive(*glyph, [font.advance(*glyph)]);
+    }
+    widths.finish();
+    cid_font.finish();
+
+    let scale = 1000.0 / f32::from(font.face.units_per_em());
+    let bbox = font.face.global_bounding_box();
+    let ascent = f32::from(font.face.ascender()) * scale;
+    pdf.font_descriptor(descriptor_id)
+        .name(base_font)
+        .flags(FontFlags::NON_SYMBOLIC)
+        .bbox(Rect::new(
+            f32::from(bbox.x_min) * scale,
+            f32::from(bbox.y_min) * scale,
+            f32::from(bbox.x_max) * scale,
+            f32::from(bbox.y_max) * scale,
+        ))
+        .italic_angle(0.0)
+        .ascent(ascent)
+        .descent(f32::from(font.face.descender()) * scale)
+        .cap_height(
+            font.face
+                .capital_height()
+                .map_or(ascent, |height| f32::from(height) * scale),
+        )
+        .stem_v(match font.face.is_bold() {
+            true => 140.0,
+            false => 80.0,
+        })
+        .font_file2(file_id);
+
+    let mut cmap = UnicodeCmap::new(Name(b"Custom"), system_info);
+    for (glyph, c) in font.used.iter() {
+        cmap.pair(*glyph, *c);
+    }
+    pdf.cmap(cmap_id, &cmap.finish());
+
+    let mut glyphs: Vec<u16> = font.used.keys().copied().collect();
+    glyphs.insert(0, 0);
+    let subset = subsetter::subset(font.data, 0, subsetter::Profile::pdf(&glyphs))
+        .map_err(|_| AppError::InternalError)?;
+    let data = deflate(&subset)?;
+    pdf.stream(file_id, &data)
+        .filter(Filter::FlateDecode)
+        .pair(Name(b"Length1"), subset.len() as i32);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(PdfTemplate::from_str("classic"), Some(PdfTemplate::Classic));
+        assert_eq!(PdfTemplate::from_str("sidebar"), Some(PdfTemplate::Sidebar));
+        assert_eq!(PdfTemplate::from_str("Classic"), None);
+    }
+
+    #[test]
+    fn test_render_resume_pdf() {
+        let description = "Wrote programs for the analytical engine. ".repeat(12);
+        let export = ProfileExportModel {
+            profile: PublicProfileModel {
+                first_name: Some("Ada".to_string()),
+                last_name: Some("Lovelace".to_string()),
+                pronouns: None,
+                headline: Some("Analyst – Ω".to_string()),
+                country: None,
+                city: Some("London".to_string()),
+                bio: None,
+            },
+            experiences: (0..30)
+                .map(|id| PublicExperienceModel {
+                    id,
+                    company: "Analytical Engines".to_string(),
+                    organization_slug: None,
+                    title: "Programmer".to_string(),
+                    start_date: ProfileDate::parse("1842-09"),
+                    end_date: None,
+                    is_current: true,
+                    duration_months: None,
+                    exp_type: None,
+                    description: Some(description.clone()),
+                    verified: false,
+                })
+                .collect(),
+            educations: vec![],
+            certifications: vec![],
+            contact_information: vec![],
+            projects: vec![],
+            languages: vec![],
+            custom_sections: vec![],
+        };
+
+        for template in [PdfTemplate::Classic, PdfTemplate::Sidebar] {
+            let pdf = render_resume_pdf(&export, &HashMap::new(), template)
+                .ok()
+                .unwrap();
+            let text = String::from_utf8_lossy(&pdf);
+
+            assert!(pdf.starts_with(b"%PDF-"));
+            // Thirty long entries do not fit on one page
+            assert!(text.matches("/Type /Page\n").count() > 1);
+            assert!(text.contains("/FontFile2"));
+        }
+    }
+}
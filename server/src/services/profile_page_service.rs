@@ -0,0 +1,694 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::models::contact_information::PublicContactInformationModel;
+use crate::models::contact_type::{ContactTypeModel, ContactValueFormat};
+use crate::models::resume::ProfileExportModel;
+use crate::services::og_image_service::{OG_IMAGE_HEIGHT, OG_IMAGE_WIDTH};
+use crate::services::pdf_service::{date_range, format_date, full_name};
+
+const SITE_NAME: &str = "Ideno";
+/// The longest page description, as search engines and link previews cut longer texts.
+const MAX_DESCRIPTION_CHARS: usize = 200;
+
+const STYLE: &str = "body{margin:0;font:16px/1.5 system-ui,sans-serif;color:#1f2933;background:#f5f7fa}\
+main{max-width:44rem;margin:2rem auto;padding:2rem;background:#fff;border-radius:.5rem}\
+h1{margin:0;font-size:2rem}h2{margin:2rem 0 .5rem;font-size:1.1rem;text-transform:uppercase;color:#52606d}\
+ul{margin:0;padding:0;list-style:none}li{margin:0 0 1rem}.meta{color:#616e7c;font-size:.9rem}\
+.contact li{display:inline;margin-right:1rem}";
+
+fn non_empty(value: Option<&String>) -> Option<&str> {
+    value
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+/// Escapes text for HTML element content and quoted attribute values.
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Serializes JSON for a `<script>` element, escaping `<` so text in the data cannot end the element.
+fn script_json(value: &Value) -> String {
+    value.to_string().replace('<', "\\u003c")
+}
+
+/// Shortens a text to the length of a page description at a word boundary.
+fn truncate_description(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= MAX_DESCRIPTION_CHARS {
+        return text;
+    }
+
+    let cut: String = text.chars().take(MAX_DESCRIPTION_CHARS - 1).collect();
+    let cut = match cut.rfind(' ') {
+        Some(index) => &cut[..index],
+        None => &cut,
+    };
+    format!(
+        "{}…",
+        cut.trim_end_matches(|c: char| c.is_ascii_punctuation())
+    )
+}
+
+/// Returns the value format of a contact entry.
+fn value_format(
+    entry: &PublicContactInformationModel,
+    contact_types: &HashMap<String, ContactTypeModel>,
+) -> Option<ContactValueFormat> {
+    contact_types
+        .get(&entry.type_field)
+        .and_then(|contact_type| ContactValueFormat::from_str(&contact_type.value_format))
+}
+
+/// Builds the schema.org `Person` of a profile, see <https://schema.org/Person>.
+fn person_json_ld(
+    username: &str,
+    export: &ProfileExportModel,
+    contact_types: &HashMap<String, ContactTypeModel>,
+    canonical_url: &str,
+) -> Value {
+    let profile = &export.profile;
+    let mut person = Map::new();
+
+    person.insert("@context".to_string(), json!("https://schema.org"));
+    person.insert("@type".to_string(), json!("Person"));
+    person.insert(
+        "name".to_string(),
+        json!(full_name(profile).unwrap_or_else(|| username.to_string())),
+    );
+    person.insert("alternateName".to_string(), json!(username));
+    person.insert("url".to_string(), json!(canonical_url));
+    for (key, value) in [
+        ("givenName", profile.first_name.as_ref()),
+        ("familyName", profile.last_name.as_ref()),
+        ("jobTitle", profile.headline.as_ref()),
+        ("description", profile.bio.as_ref()),
+    ] {
+        if let Some(value) = non_empty(value) {
+            person.insert(key.to_string(), json!(value));
+        }
+    }
+
+    let mut address = Map::new();
+    if let Some(city) = non_empty(profile.city.as_ref()) {
+        address.insert("addressLocality".to_string(), json!(city));
+    }
+    if let Some(country) = non_empty(profile.country.as_ref()) {
+        address.insert("addressCountry".to_string(), json!(country));
+    }
+    if !address.is_empty() {
+        address.insert("@type".to_string(), json!("PostalAddress"));
+        person.insert("address".to_string(), Value::Object(address));
+    }
+
+    let mut same_as = Vec::new();
+    for entry in export
+        .contact_information
+        .iter()
+        .filter(|entry| !entry.masked)
+    {
+        match value_format(entry, contact_types) {
+            Some(ContactValueFormat::Email) => {
+                person.entry("email").or_insert_with(|| json!(entry.value));
+            }
+            Some(ContactValueFormat::Phone) => {
+                person
+                    .entry("telephone")
+                    .or_insert_with(|| json!(entry.value));
+            }
+            _ => same_as.extend(entry.url.clone()),
+        }
+    }
+    if !same_as.is_empty() {
+        person.insert("sameAs".to_string(), json!(same_as));
+    }
+
+    let works_for: Vec<Value> = export
+        .experiences
+        .iter()
+        .filter(|experience| experience.is_current)
+        .map(|experience| json!({ "@type": "Organization", "name": experience.company }))
+        .collect();
+    if !works_for.is_empty() {
+        person.insert("worksFor".to_string(), json!(works_for));
+    }
+
+    let alumni_of: Vec<Value> = export
+        .educations
+        .iter()
+        .map(|education| json!({ "@type": "EducationalOrganization", "name": education.school }))
+        .collect();
+    if !alumni_of.is_empty() {
+        person.insert("alumniOf".to_string(), json!(alumni_of));
+    }
+
+    let languages: Vec<String> = export
+        .languages
+        .iter()
+        .map(|language| language.language_code.to_lowercase())
+        .collect();
+    if !languages.is_empty() {
+        person.insert("knowsLanguage".to_string(), json!(languages));
+    }
+
+    Value::Object(person)
+}
+
+/// Writes the contact entries of the h-card, with the microformats class of their value format.
+fn write_contacts(
+    html: &mut String,
+    export: &ProfileExportModel,
+    contact_types: &HashMap<String, ContactTypeModel>,
+) {
+    let mut items = Vec::new();
+
+    for entry in export
+        .contact_information
+        .iter()
+        .filter(|entry| !entry.masked)
+    {
+        let label = non_empty(entry.label.as_ref())
+            .map(str::to_string)
+            .or_else(|| {
+                contact_types
+                    .get(&entry.type_field)
+                    .map(|contact_type| contact_type.label.clone())
+            })
+            .unwrap_or_else(|| entry.type_field.clone());
+        let value = escape_html(&entry.value);
+
+        let item = match (value_format(entry, contact_types), entry.url.as_deref()) {
+            (Some(ContactValueFormat::Email), Some(url)) => format!(
+                "<a class=\"u-email\" href=\"{}\">{}</a>",
+                escape_html(url),
+                value
+            ),
+            (Some(ContactValueFormat::Phone), Some(url)) => format!(
+                "<a class=\"p-tel\" href=\"{}\">{}</a>",
+                escape_html(url),
+                value
+            ),
+            (Some(ContactValueFormat::Phone), None) => {
+                format!("<span class=\"p-tel\">{}</span>", value)
+            }
+            (_, Some(url)) => format!(
+                "<a class=\"u-url\" rel=\"me\" href=\"{}\">{}</a>",
+                escape_html(url),
+                value
+            ),
+            (_, None) => value,
+        };
+        items.push(format!("<li>{}: {}</li>", escape_html(&label), item));
+    }
+
+    if !items.is_empty() {
+        let _ = write!(html, "<ul class=\"contact\">{}</ul>", items.concat());
+    }
+}
+
+/// Writes a section of entries, each with a title, a line of details and an optional text.
+fn write_section(html: &mut String, title: &str, entries: &[(String, String, Option<String>)]) {
+    if entries.is_empty() {
+        return;
+    }
+
+    let _ = write!(html, "<section><h2>{}</h2><ul>", escape_html(title));
+    for (entry_title, details, text) in entries {
+        let _ = write!(html, "<li><strong>{}</strong>", escape_html(entry_title));
+        if !details.is_empty() {
+            let _ = write!(html, "<div class=\"meta\">{}</div>", escape_html(details));
+        }
+        if let Some(text) = text {
+            let _ = write!(html, "<p>{}</p>", escape_html(text));
+        }
+        html.push_str("</li>");
+    }
+    html.push_str("</ul></section>");
+}
+
+fn joined(parts: &[Option<&str>], separator: &str) -> String {
+    parts
+        .iter()
+        .flatten()
+        .filter(|part| !part.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Renders the public page of a profile as an HTML document for link previews and search engines.
+///
+/// The head carries the canonical URL, Open Graph and Twitter card tags and the profile as a schema.org `Person` in
+/// JSON-LD. The body marks the profile up as an h-card. Masked contact entries are left out.
+///
+/// # Arguments
+///
+/// * `username` - The username of the profile owner, used as the name when the profile has no name.
+/// * `export` - The public parts of the profile.
+/// * `contact_types` - The contact types by name.
+/// * `canonical_url` - The canonical link of the profile page.
+/// * `image_url` - The absolute link to the preview image of the profile, if there is one.
+///
+/// # Returns
+///
+/// Returns the HTML document.
+///
+pub fn render_profile_page(
+    username: &str,
+    export: &ProfileExportModel,
+    contact_types: &HashMap<String, ContactTypeModel>,
+    canonical_url: &str,
+    image_url: Option<&str>,
+) -> String {
+    let profile = &export.profile;
+    let name = full_name(profile).unwrap_or_else(|| username.to_string());
+    let headline = non_empty(profile.headline.as_ref());
+    let title = match headline {
+        Some(headline) => format!("{} – {} | {}", name, headline, SITE_NAME),
+        None => format!("{} | {}", name, SITE_NAME),
+    };
+    let description = match non_empty(profile.bio.as_ref()).or(headline) {
+        Some(text) => truncate_description(text),
+        None => format!("The profile of {} on {}", name, SITE_NAME),
+    };
+    let json_ld = person_json_ld(username, export, contact_types, canonical_url);
+
+    let mut html = String::with_capacity(8 * 1024);
+    html.push_str("<!DOCTYPE html><html lang=\"en\"><head><meta charset=\"utf-8\">");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">");
+    let _ = write!(html, "<title>{}</title>", escape_html(&title));
+    let _ = write!(
+        html,
+        "<meta name=\"description\" content=\"{}\">",
+        escape_html(&description)
+    );
+    let _ = write!(
+        html,
+        "<link rel=\"canonical\" href=\"{}\">",
+        escape_html(canonical_url)
+    );
+
+    let mut meta = vec![
+        ("property", "og:type", "profile".to_string()),
+        ("property", "og:site_name", SITE_NAME.to_string()),
+        ("property", "og:title", name.clone()),
+        ("property", "og:description", description.clone()),
+        ("property", "og:url", canonical_url.to_string()),
+        ("property", "profile:username", username.to_string()),
+    ];
+    if let Some(first_name) = non_empty(profile.first_name.as_ref()) {
+        meta.push(("property", "profile:first_name", first_name.to_string()));
+    }
+    if let Some(last_name) = non_empty(profile.last_name.as_ref()) {
+        meta.push(("property", "profile:last_name", last_name.to_string()));
+    }
+    if let Some(image_url) = image_url {
+        meta.extend([
+            ("property", "og:image", image_url.to_string()),
+            ("property", "og:image:type", "image/png".to_string()),
+            ("property", "og:image:width", OG_IMAGE_WIDTH.to_string()),
+            ("property", "og:image:height", OG_IMAGE_HEIGHT.to_string()),
+            (
+                "property",
+                "og:image:alt",
+                format!("The profile card of {}", name),
+            ),
+            ("name", "twitter:image", image_url.to_string()),
+        ]);
+    }
+    let twitter_card = match image_url {
+        Some(_) => "summary_large_image",
+        None => "summary",
+    };
+    meta.extend([
+        ("name", "twitter:card", twitter_card.to_string()),
+        ("name", "twitter:title", name.clone()),
+        ("name", "twitter:description", description),
+    ]);
+    for (attribute, key, content) in meta {
+        let _ = write!(
+            html,
+            "<meta {}=\"{}\" content=\"{}\">",
+            attribute,
+            key,
+            escape_html(&content)
+        );
+    }
+
+    let _ = write!(
+        html,
+        "<script type=\"application/ld+json\">{}</script>",
+        script_json(&json_ld)
+    );
+    let _ = write!(html, "<style>{}</style></head><body>", STYLE);
+
+    html.push_str("<main class=\"h-card\"><header>");
+    let _ = write!(
+        html,
+        "<h1><a class=\"p-name u-url u-uid\" href=\"{}\">{}</a></h1>",
+        escape_html(canonical_url),
+        escape_html(&name)
+    );
+    let _ = write!(
+        html,
+        "<data class=\"p-nickname\" value=\"{}\"></data>",
+        escape_html(username)
+    );
+    for (class, value) in [
+        ("p-given-name", profile.first_name.as_ref()),
+        ("p-family-name", profile.last_name.as_ref()),
+    ] {
+        if let Some(value) = non_empty(value) {
+            let _ = write!(
+                html,
+                "<data class=\"{}\" value=\"{}\"></data>",
+                class,
+                escape_html(value)
+            );
+        }
+    }
+    if let Some(headline) = headline {
+        let _ = write!(
+            html,
+            "<p class=\"p-job-title\">{}</p>",
+            escape_html(headline)
+        );
+    }
+
+    let city = non_empty(profile.city.as_ref());
+    let country = non_empty(profile.country.as_ref());
+    let pronouns = non_empty(profile.pronouns.as_ref());
+    if city.is_some() || country.is_some() || pronouns.is_some() {
+        html.push_str("<p class=\"meta\">");
+        if city.is_some() || country.is_some() {
+            html.push_str("<span class=\"p-adr h-adr\">");
+            if let Some(city) = city {
+                let _ = write!(
+                    html,
+                    "<span class=\"p-locality\">{}</span>",
+                    escape_html(city)
+                );
+            }
+            if city.is_some() && country.is_some() {
+                html.push_str(", ");
+            }
+            if let Some(country) = country {
+                let _ = write!(
+                    html,
+                    "<span class=\"p-country-name\">{}</span>",
+                    escape_html(country)
+                );
+            }
+            html.push_str("</span>");
+        }
+        if let Some(pronouns) = pronouns {
+            if city.is_some() || country.is_some() {
+                html.push_str(" · ");
+            }
+            let _ = write!(
+                html,
+                "<span class=\"p-pronouns\">{}</span>",
+                escape_html(pronouns)
+            );
+        }
+        html.push_str("</p>");
+    }
+    write_contacts(&mut html, export, contact_types);
+    html.push_str("</header>");
+
+    if let Some(bio) = non_empty(profile.bio.as_ref()) {
+        let _ = write!(
+            html,
+            "<section><h2>About</h2><p class=\"p-note\">{}</p></section>",
+            escape_html(bio)
+        );
+    }
+
+    let experiences: Vec<_> = export
+        .experiences
+        .iter()
+        .map(|experience| {
+            let end = match experience.is_current {
+                true => Some("Present".to_string()),
+                false => experience.end_date.as_ref().map(format_date),
+            };
+            let dates = date_range(experience.start_date.as_ref().map(format_date), end);
+            (
+                experience.title.clone(),
+                joined(
+                    &[
+                        Some(experience.company.as_str()),
+                        non_empty(experience.exp_type.as_ref()),
+                        Some(dates.as_str()),
+                    ],
+                    " · ",
+                ),
+                non_empty(experience.description.as_ref()).map(str::to_string),
+            )
+        })
+        .collect();
+    write_section(&mut html, "Experience", &experiences);
+
+    let educations: Vec<_> = export
+        .educations
+        .iter()
+        .map(|education| {
+            let dates = date_range(
+                education.start_date.as_ref().map(format_date),
+                education.end_date.as_ref().map(format_date),
+            );
+            (
+                education.school.clone(),
+                joined(
+                    &[
+                        non_empty(education.degree.as_ref()),
+                        non_empty(education.field.as_ref()),
+                        Some(dates.as_str()),
+                    ],
+                    " · ",
+                ),
+                None,
+            )
+        })
+        .collect();
+    write_section(&mut html, "Education", &educations);
+
+    let certifications: Vec<_> = export
+        .certifications
+        .iter()
+        .map(|certification| {
+            let issued = certification.issue_date.as_ref().map(format_date);
+            (
+                certification.name.clone(),
+                joined(
+                    &[Some(certification.organization.as_str()), issued.as_deref()],
+                    " · ",
+                ),
+                None,
+            )
+        })
+        .collect();
+    write_section(&mut html, "Certifications", &certifications);
+
+    let projects: Vec<_> = export
+        .projects
+        .iter()
+        .map(|project| {
+            (
+                project.name.clone(),
+                joined(
+                    &[
+                        non_empty(project.role.as_ref()),
+                        non_empty(project.url.as_ref()),
+                    ],
+                    " · ",
+                ),
+                non_empty(project.description.as_ref()).map(str::to_string),
+            )
+        })
+        .collect();
+    write_section(&mut html, "Projects", &projects);
+
+    let languages: Vec<_> = export
+        .languages
+        .iter()
+        .map(|language| {
+            (
+                language.language_code.to_uppercase(),
+                language.level.clone(),
+                None,
+            )
+        })
+        .collect();
+    write_section(&mut html, "Languages", &languages);
+
+    for section in export.custom_sections.iter() {
+        let entries: Vec<_> = section
+            .entries
+            .iter()
+            .map(|entry| {
+                let dates = date_range(
+                    entry.start_date.as_ref().map(format_date),
+                    entry.end_date.as_ref().map(format_date),
+                );
+                (
+                    entry.title.clone(),
+                    joined(
+                        &[non_empty(entry.subtitle.as_ref()), Some(dates.as_str())],
+                        " · ",
+                    ),
+                    non_empty(entry.body.as_ref()).map(str::to_string),
+                )
+            })
+            .collect();
+        write_section(&mut html, &section.title, &entries);
+    }
+
+    html.push_str("</main></body></html>");
+    html
+}
+
+/// Computes the entity tag of a rendered page, a quoted hash of its content.
+pub fn entity_tag(content: &str) -> String {
+    format!(
+        "\"{}\"",
+        hex::encode(&Sha256::digest(content.as_bytes())[..16])
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::date::ProfileDate;
+    use crate::models::experience::PublicExperienceModel;
+    use crate::models::language::PublicLanguageModel;
+    use crate::models::profile::PublicProfileModel;
+
+    #[test]
+    fn test_render_profile_page() {
+        let export = ProfileExportModel {
+            profile: PublicProfileModel {
+                first_name: Some("Ada".to_string()),
+                last_name: Some("Lovelace".to_string()),
+                pronouns: Some("she/her".to_string()),
+                headline: Some("Analyst & \"programmer\"".to_string()),
+                country: Some("GB".to_string()),
+                city: Some("London".to_string()),
+                bio: Some(format!(
+                    "</script><script>alert(1)</script> {}",
+                    "poetry ".repeat(50)
+                )),
+            },
+            experiences: vec![PublicExperienceModel {
+                id: 1,
+                company: "Analytical Engines".to_string(),
+                organization_slug: None,
+                title: "Programmer".to_string(),
+                start_date: ProfileDate::parse("1842-09"),
+                end_date: None,
+                is_current: true,
+                duration_months: None,
+                exp_type: None,
+                description: None,
+                verified: false,
+            }],
+            educations: vec![],
+            certifications: vec![],
+            contact_information: vec![PublicContactInformationModel {
+                id: 1,
+                type_field: "phone".to_string(),
+                label: None,
+                value: "+44******90".to_string(),
+                url: None,
+                visibility: "masked".to_string(),
+                masked: true,
+            }],
+            projects: vec![],
+            languages: vec![PublicLanguageModel {
+                language_code: "en".to_string(),
+                level: "Native".to_string(),
+            }],
+            custom_sections: vec![],
+        };
+
+        let html = render_profile_page(
+            "ada",
+            &export,
+            &HashMap::new(),
+            "https://ideno.example/profile/ada",
+            None,
+        );
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(
+            "<title>Ada Lovelace – Analyst &amp; &quot;programmer&quot; | Ideno</title>"
+        ));
+        assert!(
+            html.contains("<link rel=\"canonical\" href=\"https://ideno.example/profile/ada\">")
+        );
+        assert!(html.contains("<meta property=\"og:type\" content=\"profile\">"));
+        assert!(html.contains("<meta name=\"twitter:card\" content=\"summary\">"));
+        assert!(!html.contains("og:image"));
+        assert!(html.contains("class=\"h-card\""));
+        assert!(html.contains("<span class=\"p-locality\">London</span>"));
+        assert!(html.contains("Analytical Engines · Sep 1842 – Present"));
+        // The bio cannot end the script element of the JSON-LD
+        assert_eq!(html.matches("</script>").count(), 1);
+        // Masked entries are left out
+        assert!(!html.contains("+44"));
+
+        let start = html.find("application/ld+json\">").unwrap() + 21;
+        let end = html[start..].find("</script>").unwrap() + start;
+        let person: Value = serde_json::from_str(&html[start..end]).unwrap();
+        assert_eq!(person["@type"], "Person");
+        assert_eq!(person["name"], "Ada Lovelace");
+        assert_eq!(person["worksFor"][0]["name"], "Analytical Engines");
+        assert_eq!(person["knowsLanguage"][0], "en");
+        assert!(person["description"]
+            .as_str()
+            .unwrap()
+            .starts_with("</script>"));
+
+        let description = truncate_description(export.profile.bio.as_ref().unwrap());
+        assert!(description.ends_with("poetry…"));
+        assert!(description.chars().count() <= MAX_DESCRIPTION_CHARS);
+        assert_eq!(truncate_description("  short\n text "), "short text");
+
+        let html = render_profile_page(
+            "ada",
+            &export,
+            &HashMap::new(),
+            "https://ideno.example/profile/ada",
+            Some("https://api.ideno.example/api/v1/profile/ada/og-image"),
+        );
+        assert!(html.contains(
+            "<meta property=\"og:image\" content=\"https://api.ideno.example/api/v1/profile/ada/og-image\">"
+        ));
+        assert!(html.contains("<meta property=\"og:image:width\" content=\"1200\">"));
+        assert!(html.contains("<meta name=\"twitter:card\" content=\"summary_large_image\">"));
+    }
+
+    #[test]
+    fn test_entity_tag() {
+        assert_eq!(entity_tag("a"), entity_tag("a"));
+        assert_ne!(entity_tag("a"), entity_tag("b"));
+        assert_eq!(entity_tag("a").len(), 34);
+    }
+}
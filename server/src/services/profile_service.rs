@@ -1,15 +1,39 @@
 use crate::models::profile::{ProfileModel, PublicProfileModel};
+use crate::models::user::UserModel;
 use crate::response::error_handling::AppError;
 use crate::{IdenoDBResult, IdenoPool};
 
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdateProfileVisibilityPayload {
+    pub visibility: String,
+}
+
 #[derive(Clone)]
 pub struct ProfileService {
     db_pool: IdenoPool,
+    client_origin: String,
 }
 
 impl ProfileService {
-    pub fn new(db_pool: IdenoPool) -> Self {
-        ProfileService { db_pool }
+    pub fn new(db_pool: IdenoPool, client_origin: &str) -> Self {
+        ProfileService {
+            db_pool,
+            client_origin: client_origin.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Builds the link to the profile page of a user in the web client.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - The username of the user.
+    ///
+    /// # Returns
+    ///
+    /// Returns the absolute URL of the profile page, e.g. `https://ideno.example/profile/ada`.
+    ///
+    pub fn profile_url(&self, username: &str) -> String {
+        format!("{}/profile/{}", self.client_origin, username)
     }
 
     /// Asynchronously creates a profile for a user in the database.
@@ -106,4 +130,101 @@ impl ProfileService {
         .await
         .map_err(|_| AppError::InternalError)
     }
+
+    /// Asynchronously retrieves a user's own profile, including its visibility, from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose profile is to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the `ProfileModel` of the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_profile(&self, user_id: i32) -> Result<ProfileModel, AppError> {
+        sqlx::query_as::<_, ProfileModel>("SELECT * FROM profiles WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously updates who may see a user's profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose profile visibility is to be updated.
+    /// * `visibility` - The new visibility, one of `public`, `members` or `private`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the visibility was updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn update_profile_visibility(
+        &self,
+        user_id: i32,
+        visibility: &str,
+    ) -> Result<(), AppError> {
+        sqlx::query("UPDATE profiles SET visibility = $1 WHERE user_id = $2")
+            .bind(visibility)
+            .bind(user_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+
+    /// Asynchronously checks whether a viewer may see a user's profile.
+    ///
+    /// Public profiles are visible to everyone, member profiles to logged-in users and private profiles
+    /// only to their owner. Admins may see every profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose profile is viewed.
+    /// * `viewer` - The logged-in user viewing the profile, if any.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the viewer may see the profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::UserNotFound` if the viewer may not see the profile, so hidden profiles cannot be told apart from missing ones.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn check_profile_visible(
+        &self,
+        user_id: i32,
+        viewer: &Option<UserModel>,
+    ) -> Result<(), AppError> {
+        let visibility =
+            sqlx::query_as::<_, (String,)>("SELECT visibility FROM profiles WHERE user_id = $1")
+                .bind(user_id)
+                .fetch_optional(&self.db_pool)
+                .await
+                .map_err(|_| AppError::InternalError)?
+                .map(|row| row.0)
+                .ok_or(AppError::UserNotFound)?;
+
+        let visible = match viewer {
+            Some(viewer) if viewer.id == user_id || viewer.role == "admin" => true,
+            Some(_) => visibility != "private",
+            None => visibility == "public",
+        };
+
+        if !visible {
+            return Err(AppError::UserNotFound);
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,672 @@
+use crate::models::date::{deserialize_optional_date, ProfileDate};
+use crate::models::project::{
+    AuthProjectModel, ProjectImageModel, ProjectModel, ProjectSkillModel, PublicProjectModel,
+};
+use crate::response::error_handling::AppError;
+use crate::services::skill_service::get_or_create_vocabulary_skill;
+use crate::{IdenoDBResult, IdenoPool};
+
+const PROJECT_IMAGE_LIMIT: usize = 10;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AddProjectPayload {
+    pub name: String,
+    pub role: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub repository_url: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub start_date: Option<ProfileDate>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub end_date: Option<ProfileDate>,
+    pub skills: Option<Vec<String>>,
+    pub images: Option<Vec<ProjectImageModel>>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdateProjectPayload {
+    pub name: String,
+    pub role: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub repository_url: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub start_date: Option<ProfileDate>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub end_date: Option<ProfileDate>,
+    pub skills: Option<Vec<String>>,
+    pub images: Option<Vec<ProjectImageModel>>,
+}
+
+#[derive(Clone)]
+pub struct ProjectService {
+    db_pool: IdenoPool,
+}
+
+pub(crate) fn is_http_url(value: &str) -> bool {
+    let rest = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"));
+
+    matches!(rest, Some(host) if !host.is_empty() && !host.contains(char::is_whitespace))
+}
+
+impl ProjectService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        ProjectService { db_pool }
+    }
+
+    /// Asynchronously retrieves authenticated projects associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the authenticated user whose projects are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `AuthProjectModel` instances representing the projects associated with the user,
+    /// each including its skills and images.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_authenticated_projects(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<AuthProjectModel>, AppError> {
+        let mut projects = sqlx::query_as::<_, AuthProjectModel>(
+            "SELECT
+                id,
+                name,
+                role,
+                description,
+                url,
+                repository_url,
+                start_date,
+                end_date
+              FROM projects
+              WHERE user_id = ?
+              ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        let skills = self.get_project_skills(user_id).await?;
+        let images = self.get_project_images(user_id).await?;
+
+        for project in projects.iter_mut() {
+            project.skills = skills
+                .iter()
+                .filter(|skill| skill.project_id == project.id)
+                .map(|skill| skill.name.clone())
+                .collect();
+            project.images = images
+                .iter()
+                .filter(|image| image.project_id == project.id)
+                .cloned()
+                .collect();
+        }
+
+        Ok(projects)
+    }
+
+    /// Asynchronously retrieves public projects associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose public projects are to be retrieved.
+    /// * `limit` - An optional limit on the number of projects to retrieve. If not provided, retrieves all projects.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `PublicProjectModel` instances representing the public projects associated with the user,
+    /// each including its skills and images.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_public_projects(
+        &self,
+        user_id: i32,
+        limit: Option<i32>,
+    ) -> Result<Vec<PublicProjectModel>, AppError> {
+        let limit = limit.unwrap_or(-1);
+        let mut projects = sqlx::query_as::<_, PublicProjectModel>(
+            "SELECT
+                id,
+                name,
+                role,
+                description,
+                url,
+                repository_url,
+                start_date,
+                end_date
+              FROM projects
+              WHERE user_id = $1
+              ORDER BY created_at DESC
+              LIMIT $2",
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        let skills = self.get_project_skills(user_id).await?;
+        let images = self.get_project_images(user_id).await?;
+
+        for project in projects.iter_mut() {
+            project.skills = skills
+                .iter()
+                .filter(|skill| skill.project_id == project.id)
+                .map(|skill| skill.name.clone())
+                .collect();
+            project.images = images
+                .iter()
+                .filter(|image| image.project_id == project.id)
+                .cloned()
+                .collect();
+        }
+
+        Ok(projects)
+    }
+
+    /// Asynchronously retrieves the skills linked to all projects of a user.
+    async fn get_project_skills(&self, user_id: i32) -> Result<Vec<ProjectSkillModel>, AppError> {
+        sqlx::query_as::<_, ProjectSkillModel>(
+            "SELECT
+                project_skills.project_id,
+                skills.name
+              FROM project_skills
+              JOIN projects ON projects.id = project_skills.project_id
+              JOIN skills ON skills.id = project_skills.skill_id
+              WHERE projects.user_id = ?
+              ORDER BY skills.name",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously retrieves the images of all projects of a user.
+    async fn get_project_images(&self, user_id: i32) -> Result<Vec<ProjectImageModel>, AppError> {
+        sqlx::query_as::<_, ProjectImageModel>(
+            "SELECT
+                project_images.project_id,
+                project_images.url,
+                project_images.caption
+              FROM project_images
+              JOIN projects ON projects.id = project_images.project_id
+              WHERE projects.user_id = ?
+              ORDER BY project_images.position",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously checks if a user owns a specific project.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user to check ownership.
+    /// * `project_id` - The ID of the project to check ownership against.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option<bool>`. If the user owns the project, it returns `Some(true)`,
+    /// if the user does not own the project, it returns `Some(false)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` with an error message if the project is not found.
+    ///
+    pub async fn user_owns_project(
+        &self,
+        user_id: i32,
+        project_id: i32,
+    ) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM projects WHERE id = $1 AND user_id = $2",
+        )
+        .bind(project_id)
+        .bind(user_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map_or_else(|_| None, |count| Some(count.0 > 0));
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Project not found".to_string(),
+            }),
+        }
+    }
+
+    /// Validates the project name, links, skills and images.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the project.
+    /// * `urls` - The project and repository URLs.
+    /// * `skills` - The optional names of the skills used in the project.
+    /// * `images` - The optional images of the project.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the project is valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the name or a skill is empty, a link is not an http(s) URL
+    /// or there are too many images.
+    ///
+    pub fn validate_project(
+        &self,
+        name: &str,
+        urls: [&Option<String>; 2],
+        skills: &Option<Vec<String>>,
+        images: &Option<Vec<ProjectImageModel>>,
+    ) -> Result<bool, AppError> {
+        if name.trim().is_empty() {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid project name".to_string()),
+            });
+        }
+
+        for url in urls.into_iter().flatten() {
+            if !url.is_empty() && !is_http_url(url) {
+                return Err(AppError::BadRequest {
+                    error: Some("Invalid project url".to_string()),
+                });
+            }
+        }
+
+        if let Some(skills) = skills {
+            if skills.iter().any(|skill| skill.trim().is_empty()) {
+                return Err(AppError::BadRequest {
+                    error: Some("Invalid skill name".to_string()),
+                });
+            }
+        }
+
+        if let Some(images) = images {
+            if images.len() > PROJECT_IMAGE_LIMIT {
+                return Err(AppError::BadRequest {
+                    error: Some("Project image limit reached".to_string()),
+                });
+            }
+
+            if images.iter().any(|image| !is_http_url(&image.url)) {
+                return Err(AppError::BadRequest {
+                    error: Some("Invalid project image url".to_string()),
+                });
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Asynchronously deletes a project associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the project to be deleted.
+    /// * `project_id` - The ID of the project to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the delete operation. If the project is successfully deleted,
+    /// it returns `Ok(IdenoDBResult)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn delete_project(
+        &self,
+        user_id: i32,
+        project_id: i32,
+    ) -> Result<IdenoDBResult, AppError> {
+        sqlx::query("DELETE FROM projects WHERE id = $1 AND user_id = $2")
+            .bind(project_id)
+            .bind(user_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously updates a project associated with a user in the database.
+    ///
+    /// The skills and images of the project are replaced by the ones given in the payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the project to be updated.
+    /// * `project_id` - The ID of the project to be updated.
+    /// * `payload` - An `UpdateProjectPayload` containing the updated data for the project.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the update operation. If the project is successfully updated,
+    /// it returns `Ok(())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the project does not exist or does not belong to the user.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn update_project(
+        &self,
+        user_id: i32,
+        project_id: i32,
+        payload: UpdateProjectPayload,
+    ) -> Result<(), AppError> {
+        let mut tx = self
+            .db_pool
+            .begin()
+            .await
+            .map_err(|_| AppError::InternalError)?;
+
+        let result = sqlx::query(
+            "UPDATE projects SET name = $1, role = $2, description = $3, url = $4, repository_url = $5, start_date = $6, end_date = $7 WHERE id = $8 AND user_id = $9",
+        )
+            .bind(payload.name)
+            .bind(payload.role)
+            .bind(payload.description)
+            .bind(payload.url)
+            .bind(payload.repository_url)
+            .bind(payload.start_date)
+            .bind(payload.end_date)
+            .bind(project_id)
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|_| AppError::InternalError)?;
+
+        // The skills and images are only replaced once the project is known to belong to the user.
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound {
+                error: "Project not found".to_string(),
+            });
+        }
+
+        sqlx::query("DELETE FROM project_skills WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|_| AppError::InternalError)?;
+
+        sqlx::query("DELETE FROM project_images WHERE project_id = $1")
+            .bind(project_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|_| AppError::InternalError)?;
+
+        for skill in payload.skills.unwrap_or_default() {
+            let skill_id = get_or_create_vocabulary_skill(&mut tx, &skill).await?;
+            sqlx::query("INSERT OR IGNORE INTO project_skills (project_id, skill_id) VALUES ($1, $2)")
+                .bind(project_id)
+                .bind(skill_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|_| AppError::InternalError)?;
+        }
+
+        for (position, image) in payload.images.unwrap_or_default().into_iter().enumerate() {
+            sqlx::query("INSERT INTO project_images (project_id, url, caption, position) VALUES ($1, $2, $3, $4)")
+                .bind(project_id)
+                .bind(image.url)
+                .bind(image.caption)
+                .bind(position as i32)
+                .execute(&mut *tx)
+                .await
+                .map_err(|_| AppError::InternalError)?;
+        }
+
+        tx.commit().await.map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously creates a new project for a user in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the project is to be created.
+    /// * `payload` - An `AddProjectPayload` containing the details of the project to be created.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created project if the operation is successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_project(
+        &self,
+        user_id: i32,
+        payload: AddProjectPayload,
+    ) -> Result<i64, AppError> {
+        let mut tx = self
+            .db_pool
+            .begin()
+            .await
+            .map_err(|_| AppError::InternalError)?;
+
+        let project_id = sqlx::query_as::<_, (i64,)>(
+            "INSERT INTO projects (name, role, description, url, repository_url, start_date, end_date, user_id) VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING id",
+        )
+            .bind(payload.name)
+            .bind(payload.role)
+            .bind(payload.description)
+            .bind(payload.url)
+            .bind(payload.repository_url)
+            .bind(payload.start_date)
+            .bind(payload.end_date)
+            .bind(user_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|id| id.0)?;
+
+        for skill in payload.skills.unwrap_or_default() {
+            let skill_id = get_or_create_vocabulary_skill(&mut tx, &skill).await?;
+            sqlx::query("INSERT OR IGNORE INTO project_skills (project_id, skill_id) VALUES ($1, $2)")
+                .bind(project_id)
+                .bind(skill_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|_| AppError::InternalError)?;
+        }
+
+        for (position, image) in payload.images.unwrap_or_default().into_iter().enumerate() {
+            sqlx::query("INSERT INTO project_images (project_id, url, caption, position) VALUES ($1, $2, $3, $4)")
+                .bind(project_id)
+                .bind(image.url)
+                .bind(image.caption)
+                .bind(position as i32)
+                .execute(&mut *tx)
+                .await
+                .map_err(|_| AppError::InternalError)?;
+        }
+
+        tx.commit().await.map_err(|_| AppError::InternalError)?;
+
+        Ok(project_id)
+    }
+
+    /// Asynchronously retrieves the count of projects associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose project count is to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the count of projects associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_project_count(&self, user_id: i32) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM projects WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|count| count.0)
+    }
+
+    /// Asynchronously retrieves all projects associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose projects are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `ProjectModel` instances representing all projects associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_all_projects(&self, user_id: i32) -> Result<Vec<ProjectModel>, AppError> {
+        sqlx::query_as::<_, ProjectModel>(
+            "SELECT * FROM projects WHERE user_id = $1 ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously deletes a project from the database for administrative purposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The ID of the project to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), AppError>` indicating the outcome of the delete operation. If the project is successfully deleted, it returns `Ok(())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn admin_delete_project(&self, project_id: i32) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM projects WHERE id = $1")
+            .bind(project_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+
+    /// Asynchronously checks if a project exists in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The ID of the project to check for existence.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option<bool>`. If the project exists, it returns `Some(true)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` with an error message if the project is not found.
+    ///
+    pub async fn project_exists(&self, project_id: i32) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM projects WHERE id = $1")
+            .bind(project_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map_or_else(|_| None, |count| Some(count.0 > 0));
+
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Project not found".to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AddProjectPayload, ProjectService, UpdateProjectPayload};
+    use crate::models::project::ProjectImageModel;
+    use crate::test_utils::{create_test_user, test_pool};
+
+    fn image(url: &str) -> ProjectImageModel {
+        ProjectImageModel {
+            project_id: 0,
+            url: url.to_string(),
+            caption: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_project_of_other_user() {
+        let pool = test_pool().await;
+        let owner_id = create_test_user(&pool, "owner").await;
+        let other_id = create_test_user(&pool, "other").await;
+        let service = ProjectService::new(pool);
+
+        let project_id = service
+            .create_project(
+                owner_id,
+                AddProjectPayload {
+                    name: "Ideno".to_string(),
+                    role: None,
+                    description: None,
+                    url: None,
+                    repository_url: None,
+                    start_date: None,
+                    end_date: None,
+                    skills: Some(vec!["Rust".to_string()]),
+                    images: Some(vec![image("https://example.com/owner.png")]),
+                },
+            )
+            .await
+            .ok()
+            .unwrap();
+
+        // Updating a project of another user must fail without touching its skills and images
+        let result = service
+            .update_project(
+                other_id,
+                project_id as i32,
+                UpdateProjectPayload {
+                    name: "Hijacked".to_string(),
+                    role: None,
+                    description: None,
+                    url: None,
+                    repository_url: None,
+                    start_date: None,
+                    end_date: None,
+                    skills: Some(vec![]),
+                    images: Some(vec![image("https://example.com/other.png")]),
+                },
+            )
+            .await;
+        assert!(result.is_err());
+
+        let projects = service.get_authenticated_projects(owner_id).await.ok().unwrap();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "Ideno");
+        assert_eq!(projects[0].skills, vec!["Rust".to_string()]);
+        assert_eq!(projects[0].images.len(), 1);
+        assert_eq!(projects[0].images[0].url, "https://example.com/owner.png");
+    }
+}
@@ -0,0 +1,421 @@
+use crate::models::date::{deserialize_optional_date, ProfileDate};
+use crate::models::publication::{AuthPublicationModel, PublicationModel, PublicPublicationModel};
+use crate::response::error_handling::AppError;
+use crate::{IdenoDBResult, IdenoPool};
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AddPublicationPayload {
+    pub title: String,
+    pub venue: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub publication_date: Option<ProfileDate>,
+    pub co_authors: Option<String>,
+    pub doi: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdatePublicationPayload {
+    pub title: String,
+    pub venue: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_date")]
+    pub publication_date: Option<ProfileDate>,
+    pub co_authors: Option<String>,
+    pub doi: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct PublicationService {
+    db_pool: IdenoPool,
+}
+
+/// Checks that a value has the shape of a DOI: the `10.` directory indicator, a numeric
+/// registrant code and a non-empty suffix separated by a slash.
+fn is_doi(value: &str) -> bool {
+    let Some((prefix, suffix)) = value.split_once('/') else {
+        return false;
+    };
+
+    let registrant = match prefix.strip_prefix("10.") {
+        Some(registrant) => registrant,
+        None => return false,
+    };
+
+    !registrant.is_empty()
+        && registrant.split('.').all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+        && !suffix.is_empty()
+        && !suffix.contains(char::is_whitespace)
+}
+
+impl PublicationService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        PublicationService { db_pool }
+    }
+
+    /// Asynchronously retrieves authenticated publications associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the authenticated publications are being retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `AuthPublicationModel` representing authenticated publications associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_authenticated_publications(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<AuthPublicationModel>, AppError> {
+        sqlx::query_as::<_, AuthPublicationModel>(
+            "SELECT
+                id,
+                title,
+                venue,
+                publication_date,
+                co_authors,
+                doi,
+                url
+              FROM publications
+              WHERE user_id = ?
+              ORDER BY created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously retrieves public publications associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the public publications are being retrieved.
+    /// * `limit` - An optional limit on the number of publications to retrieve. If not provided, all publications are fetched.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `PublicPublicationModel` representing public publications associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_public_publications(
+        &self,
+        user_id: i32,
+        limit: Option<i32>,
+    ) -> Result<Vec<PublicPublicationModel>, AppError> {
+        let limit = limit.unwrap_or(-1);
+        sqlx::query_as::<_, PublicPublicationModel>(
+            "SELECT
+                title,
+                venue,
+                publication_date,
+                co_authors,
+                doi,
+                url
+              FROM publications
+              WHERE user_id = $1
+              ORDER BY created_at DESC
+              LIMIT $2",
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously checks if a user owns a specific publication.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose ownership of the publication is being checked.
+    /// * `publication_id` - The ID of the publication being checked for ownership.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an optional boolean value indicating whether the user owns the publication.
+    /// - `Some(true)` if the user owns the publication.
+    /// - `Some(false)` if the user does not own the publication.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the publication with the provided ID could not be checked.
+    ///
+    pub async fn user_owns_publication(
+        &self,
+        user_id: i32,
+        publication_id: i32,
+    ) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM publications WHERE id = $1 AND user_id = $2",
+        )
+        .bind(publication_id)
+        .bind(user_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map_or_else(|_| None, |count| Some(count.0 > 0));
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Publication not found".to_string(),
+            }),
+        }
+    }
+
+    /// Asynchronously deletes a publication entry associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the publication entry to be deleted.
+    /// * `publication_id` - The ID of the publication entry to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the delete operation. If the publication entry is successfully deleted,
+    /// it returns `Ok(IdenoDBResult)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn delete_publication(
+        &self,
+        user_id: i32,
+        publication_id: i32,
+    ) -> Result<IdenoDBResult, AppError> {
+        sqlx::query("DELETE FROM publications WHERE id = $1 AND user_id = $2")
+            .bind(publication_id)
+            .bind(user_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously updates a publication entry associated with a user in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the publication entry to be updated.
+    /// * `publication_id` - The ID of the publication entry to be updated.
+    /// * `payload` - An `UpdatePublicationPayload` containing the updated publication details.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the update operation. If the publication entry is successfully updated,
+    /// it returns `Ok(IdenoDBResult)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn update_publication(
+        &self,
+        user_id: i32,
+        publication_id: i32,
+        payload: UpdatePublicationPayload,
+    ) -> Result<IdenoDBResult, AppError> {
+        sqlx::query("UPDATE publications SET title = $1, venue = $2, publication_date = $3, co_authors = $4, doi = $5, url = $6 WHERE id = $7 AND user_id = $8")
+            .bind(payload.title)
+            .bind(payload.venue)
+            .bind(payload.publication_date)
+            .bind(payload.co_authors)
+            .bind(payload.doi)
+            .bind(payload.url)
+            .bind(publication_id)
+            .bind(user_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously creates a new publication entry associated with a user in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the publication is being created.
+    /// * `payload` - An `AddPublicationPayload` containing the details of the publication to be created.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created publication entry if successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_publication(
+        &self,
+        user_id: i32,
+        payload: AddPublicationPayload,
+    ) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>("INSERT INTO publications (user_id, title, venue, publication_date, co_authors, doi, url) VALUES ($1, $2, $3, $4, $5, $6, $7) RETURNING id")
+            .bind(user_id)
+            .bind(payload.title)
+            .bind(payload.venue)
+            .bind(payload.publication_date)
+            .bind(payload.co_authors)
+            .bind(payload.doi)
+            .bind(payload.url)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|id| id.0)
+    }
+
+    /// Validates the publication title and DOI.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title of the publication.
+    /// * `doi` - An optional reference to the DOI of the publication, e.g. `10.1000/xyz123`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the publication is valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the title is empty or the DOI is malformed.
+    ///
+    pub fn validate_publication(&self, title: &str, doi: &Option<String>) -> Result<bool, AppError> {
+        if title.trim().is_empty() {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid publication title".to_string()),
+            });
+        }
+
+        if let Some(doi) = doi {
+            if !doi.is_empty() && !is_doi(doi) {
+                return Err(AppError::BadRequest {
+                    error: Some("Invalid DOI".to_string()),
+                });
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Asynchronously retrieves the count of publication entries associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the count of publication entries is being retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the count of publication entries associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_publication_count(&self, user_id: i32) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM publications WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|count| count.0)
+    }
+
+    /// Asynchronously retrieves all publication entries associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the publication entries are being retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `PublicationModel` representing all publication entries associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_all_publications(&self, user_id: i32) -> Result<Vec<PublicationModel>, AppError> {
+        sqlx::query_as::<_, PublicationModel>("SELECT * FROM publications WHERE user_id = $1 ORDER BY created_at DESC")
+            .bind(user_id)
+            .fetch_all(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously deletes a publication entry from the database for administrative purposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `publication_id` - The ID of the publication entry to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the delete operation. If the operation is successful, it returns `Ok(())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn admin_delete_publication(&self, publication_id: i32) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM publications WHERE id = $1")
+            .bind(publication_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+
+    /// Asynchronously checks if a publication entry exists in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `publication_id` - The ID of the publication entry to be checked.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an optional boolean value.
+    /// If the publication entry exists, it returns `Some(true)`, otherwise `Some(false)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` with an error message if the publication entry could not be checked.
+    ///
+    pub async fn publication_exists(&self, publication_id: i32) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM publications WHERE id = $1")
+            .bind(publication_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map_or_else(|_| None, |count| Some(count.0 > 0));
+
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Publication not found".to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_doi;
+
+    #[test]
+    fn test_is_doi() {
+        assert!(is_doi("10.1000/xyz123"));
+        assert!(is_doi("10.1038/nphys1170"));
+        assert!(is_doi("10.1000.10/abc(def)"));
+
+        assert!(!is_doi("https://doi.org/10.1000/xyz123"));
+        assert!(!is_doi("10.abc/xyz"));
+        assert!(!is_doi("10.1000/"));
+        assert!(!is_doi("11.1000/xyz"));
+    }
+}
@@ -0,0 +1,159 @@
+use qrcode::render::svg;
+use qrcode::{Color, EcLevel, QrCode};
+use serde::Deserialize;
+
+use crate::response::error_handling::AppError;
+
+/// The size of a module of a PNG QR code in pixels.
+const PNG_MODULE_PIXELS: usize = 8;
+/// The width of the light border around a QR code in modules.
+const QUIET_ZONE_MODULES: usize = 4;
+
+#[derive(Deserialize)]
+pub struct QrCodeQuery {
+    pub format: Option<String>,
+    pub content: Option<String>,
+}
+
+/// The image formats QR codes are rendered in.
+#[derive(PartialEq, Debug)]
+pub enum QrCodeFormat {
+    Svg,
+    Png,
+}
+
+impl QrCodeFormat {
+    pub(crate) fn from_str(s: &str) -> Option<QrCodeFormat> {
+        match s {
+            "svg" => Some(QrCodeFormat::Svg),
+            "png" => Some(QrCodeFormat::Png),
+            _ => None,
+        }
+    }
+}
+
+/// What a profile QR code encodes, the link to the profile page or the vCard of the profile.
+#[derive(PartialEq, Debug)]
+pub enum QrCodeContent {
+    Url,
+    Vcard,
+}
+
+impl QrCodeContent {
+    pub(crate) fn from_str(s: &str) -> Option<QrCodeContent> {
+        match s {
+            "url" => Some(QrCodeContent::Url),
+            "vcard" => Some(QrCodeContent::Vcard),
+            _ => None,
+        }
+    }
+}
+
+/// Encodes data as a QR code, lowering the error correction level for data that does not fit otherwise.
+fn encode(data: &str) -> Result<QrCode, AppError> {
+    QrCode::with_error_correction_level(data, EcLevel::M)
+        .or_else(|_| QrCode::with_error_correction_level(data, EcLevel::L))
+        .map_err(|_| AppError::BadRequest {
+            error: Some("Too much data for a QR code".to_string()),
+        })
+}
+
+/// Renders data as an SVG QR code.
+///
+/// # Arguments
+///
+/// * `data` - The data to encode.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the SVG document.
+///
+/// # Errors
+///
+/// Returns an `AppError::BadRequest` if the data does not fit into a QR code.
+///
+pub fn render_qr_svg(data: &str) -> Result<String, AppError> {
+    let code = encode(data)?;
+
+    Ok(code
+        .render::<svg::Color>()
+        .min_dimensions(256, 256)
+        .quiet_zone(true)
+        .build())
+}
+
+/// Renders data as a black and white PNG QR code.
+///
+/// # Arguments
+///
+/// * `data` - The data to encode.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the PNG image.
+///
+/// # Errors
+///
+/// Returns an `AppError::BadRequest` if the data does not fit into a QR code.
+/// Returns an `AppError::InternalError` if the image could not be encoded.
+///
+pub fn render_qr_png(data: &str) -> Result<Vec<u8>, AppError> {
+    let code = encode(data)?;
+    let width = code.width();
+    let colors = code.to_colors();
+
+    let size = (width + 2 * QUIET_ZONE_MODULES) * PNG_MODULE_PIXELS;
+    let mut pixels = vec![255u8; size * size];
+    for (index, color) in colors.iter().enumerate() {
+        if *color != Color::Dark {
+            continue;
+        }
+
+        let left = (index % width + QUIET_ZONE_MODULES) * PNG_MODULE_PIXELS;
+        let top = (index / width + QUIET_ZONE_MODULES) * PNG_MODULE_PIXELS;
+        for y in top..top + PNG_MODULE_PIXELS {
+            pixels[y * size + left..y * size + left + PNG_MODULE_PIXELS].fill(0);
+        }
+    }
+
+    let mut image = Vec::new();
+    let mut encoder = png::Encoder::new(&mut image, size as u32, size as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&pixels))
+        .map_err(|_| AppError::InternalError)?;
+
+    Ok(image)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(QrCodeFormat::from_str("svg"), Some(QrCodeFormat::Svg));
+        assert_eq!(QrCodeFormat::from_str("png"), Some(QrCodeFormat::Png));
+        assert_eq!(QrCodeFormat::from_str("gif"), None);
+        assert_eq!(QrCodeContent::from_str("url"), Some(QrCodeContent::Url));
+        assert_eq!(QrCodeContent::from_str("vcard"), Some(QrCodeContent::Vcard));
+        assert_eq!(QrCodeContent::from_str("text"), None);
+    }
+
+    #[test]
+    fn test_render_qr_png() {
+        let image = render_qr_png("https://ideno.example/profile/ada")
+            .ok()
+            .unwrap();
+
+        let decoder = png::Decoder::new(image.as_slice());
+        let reader = decoder.read_info().unwrap();
+        let info = reader.info();
+        // A version 3 code of 29 modules with its quiet zone
+        assert_eq!(info.width, (29 + 8) * 8);
+        assert_eq!(info.height, info.width);
+        assert!(render_qr_png(&"a".repeat(4000)).is_err());
+    }
+}
@@ -0,0 +1,646 @@
+use crate::models::recommendation::{
+    AuthRecommendationModel, PublicRecommendationModel, RecommendationModel,
+    WrittenRecommendationModel,
+};
+use crate::response::error_handling::AppError;
+use crate::routes::api::auth::profile::recommendation::RecommendationRelationship;
+use crate::IdenoPool;
+
+const RECOMMENDATION_BODY_LIMIT: usize = 3000;
+/// A request stays open until the author writes the recommendation or this much time has passed.
+const RECOMMENDATION_REQUEST_VALIDITY: &str = "-30 days";
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AddRecommendationPayload {
+    pub username: String,
+    pub relationship: String,
+    pub experience_id: Option<i32>,
+    pub body: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct RequestRecommendationPayload {
+    pub username: String,
+}
+
+#[derive(Clone)]
+pub struct RecommendationService {
+    db_pool: IdenoPool,
+}
+
+impl RecommendationService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        RecommendationService { db_pool }
+    }
+
+    /// Asynchronously retrieves the recommendations a user has received, regardless of their status.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the recipient whose recommendations are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `AuthRecommendationModel` instances, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_authenticated_recommendations(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<AuthRecommendationModel>, AppError> {
+        sqlx::query_as::<_, AuthRecommendationModel>(
+            "SELECT
+                recommendations.id,
+                users.username AS author,
+                recommendations.relationship,
+                recommendations.body,
+                recommendations.status,
+                recommendations.experience_id,
+                experiences.title AS experience_title,
+                experiences.company AS experience_company,
+                recommendations.created_at
+              FROM recommendations
+              JOIN users ON users.id = recommendations.author_id
+              LEFT JOIN experiences ON experiences.id = recommendations.experience_id
+              WHERE recommendations.recipient_id = $1
+              ORDER BY recommendations.created_at DESC, recommendations.id DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously retrieves the approved recommendations of a user for public display.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the recipient whose recommendations are to be retrieved.
+    /// * `limit` - An optional limit on the number of recommendations to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `PublicRecommendationModel` instances, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_public_recommendations(
+        &self,
+        user_id: i32,
+        limit: Option<i32>,
+    ) -> Result<Vec<PublicRecommendationModel>, AppError> {
+        sqlx::query_as::<_, PublicRecommendationModel>(
+            "SELECT
+                users.username AS author,
+                recommendations.relationship,
+                recommendations.body,
+                experiences.title AS experience_title,
+                experiences.company AS experience_company,
+                recommendations.created_at
+              FROM recommendations
+              JOIN users ON users.id = recommendations.author_id
+              LEFT JOIN experiences ON experiences.id = recommendations.experience_id
+              WHERE recommendations.recipient_id = $1 AND recommendations.status = 'approved'
+              ORDER BY recommendations.created_at DESC, recommendations.id DESC
+              LIMIT $2",
+        )
+        .bind(user_id)
+        .bind(limit.unwrap_or(-1))
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously retrieves the recommendations a user has written for others.
+    ///
+    /// # Arguments
+    ///
+    /// * `author_id` - The ID of the user who wrote the recommendations.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `WrittenRecommendationModel` instances, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_written_recommendations(
+        &self,
+        author_id: i32,
+    ) -> Result<Vec<WrittenRecommendationModel>, AppError> {
+        sqlx::query_as::<_, WrittenRecommendationModel>(
+            "SELECT
+                recommendations.id,
+                users.username AS recipient,
+                recommendations.relationship,
+                recommendations.body,
+                recommendations.status,
+                experiences.title AS experience_title,
+                experiences.company AS experience_company,
+                recommendations.created_at
+              FROM recommendations
+              JOIN users ON users.id = recommendations.recipient_id
+              LEFT JOIN experiences ON experiences.id = recommendations.experience_id
+              WHERE recommendations.author_id = $1
+              ORDER BY recommendations.created_at DESC, recommendations.id DESC",
+        )
+        .bind(author_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Validates a recommendation before it is written.
+    ///
+    /// # Arguments
+    ///
+    /// * `author_id` - The ID of the user writing the recommendation.
+    /// * `recipient_id` - The ID of the user the recommendation is written for.
+    /// * `relationship` - A reference to the relationship between author and recipient.
+    /// * `body` - A reference to the text of the recommendation.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the recommendation is valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the author recommends themselves, the relationship
+    /// is unknown or the body is empty or too long.
+    ///
+    pub fn validate_recommendation(
+        &self,
+        author_id: i32,
+        recipient_id: i32,
+        relationship: &str,
+        body: &str,
+    ) -> Result<bool, AppError> {
+        if author_id == recipient_id {
+            return Err(AppError::BadRequest {
+                error: Some("You cannot recommend yourself".to_string()),
+            });
+        }
+
+        if RecommendationRelationship::from_str(relationship).is_none() {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid recommendation relationship".to_string()),
+            });
+        }
+
+        if body.trim().is_empty() || body.chars().count() > RECOMMENDATION_BODY_LIMIT {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid recommendation body".to_string()),
+            });
+        }
+
+        Ok(true)
+    }
+
+    /// Asynchronously checks that an experience belongs to the recipient of a recommendation.
+    ///
+    /// # Arguments
+    ///
+    /// * `recipient_id` - The ID of the user the recommendation is written for.
+    /// * `experience_id` - The ID of the experience the recommendation relates to.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the experience belongs to the recipient.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` if the experience does not belong to the recipient.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn validate_recommendation_experience(
+        &self,
+        recipient_id: i32,
+        experience_id: i32,
+    ) -> Result<(), AppError> {
+        let count = sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM experiences WHERE id = $1 AND user_id = $2",
+        )
+        .bind(experience_id)
+        .bind(recipient_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        if count.0 == 0 {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid recommendation experience".to_string()),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Asynchronously checks if an author already has a recommendation awaiting approval by the recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `author_id` - The ID of the user writing the recommendation.
+    /// * `recipient_id` - The ID of the user the recommendation is written for.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing `true` if a pending recommendation exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn has_pending_recommendation(
+        &self,
+        author_id: i32,
+        recipient_id: i32,
+    ) -> Result<bool, AppError> {
+        sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM recommendations WHERE author_id = $1 AND recipient_id = $2 AND status = 'pending'",
+        )
+        .bind(author_id)
+        .bind(recipient_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|count| count.0 > 0)
+    }
+
+    /// Asynchronously checks if a user has an open request for a recommendation by an author.
+    ///
+    /// A request is open until the author writes a recommendation for the requester or the request expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `requester_id` - The ID of the user asking for the recommendation.
+    /// * `author_id` - The ID of the user asked to write the recommendation.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing `true` if an open request exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn has_open_recommendation_request(
+        &self,
+        requester_id: i32,
+        author_id: i32,
+    ) -> Result<bool, AppError> {
+        sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*)
+              FROM recommendation_requests
+              WHERE requester_id = $1
+                AND author_id = $2
+                AND created_at > datetime('now', $3)
+                AND NOT EXISTS (
+                  SELECT 1 FROM recommendations
+                  WHERE recommendations.author_id = recommendation_requests.author_id
+                    AND recommendations.recipient_id = recommendation_requests.requester_id
+                    AND recommendations.created_at >= recommendation_requests.created_at
+                )",
+        )
+        .bind(requester_id)
+        .bind(author_id)
+        .bind(RECOMMENDATION_REQUEST_VALIDITY)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|count| count.0 > 0)
+    }
+
+    /// Asynchronously retrieves the number of recommendation requests a user sent within the last hour.
+    ///
+    /// # Arguments
+    ///
+    /// * `requester_id` - The ID of the user whose requests are counted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the number of requests sent within the last hour.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_recent_recommendation_request_count(
+        &self,
+        requester_id: i32,
+    ) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM recommendation_requests WHERE requester_id = $1 AND created_at > datetime('now', '-1 hour')",
+        )
+        .bind(requester_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|count| count.0)
+    }
+
+    /// Asynchronously records a request for a recommendation.
+    ///
+    /// # Arguments
+    ///
+    /// * `requester_id` - The ID of the user asking for the recommendation.
+    /// * `author_id` - The ID of the user asked to write the recommendation.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the request was recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_recommendation_request(
+        &self,
+        requester_id: i32,
+        author_id: i32,
+    ) -> Result<(), AppError> {
+        sqlx::query("INSERT INTO recommendation_requests (requester_id, author_id) VALUES ($1, $2)")
+            .bind(requester_id)
+            .bind(author_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+
+    /// Asynchronously retrieves the count of recommendations written by a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `author_id` - The ID of the user whose written recommendation count is to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the count of recommendations written by the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_written_recommendation_count(&self, author_id: i32) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM recommendations WHERE author_id = $1")
+            .bind(author_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|count| count.0)
+    }
+
+    /// Asynchronously writes a new recommendation, which awaits approval by the recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `author_id` - The ID of the user writing the recommendation.
+    /// * `recipient_id` - The ID of the user the recommendation is written for.
+    /// * `payload` - An `AddRecommendationPayload` containing the details of the recommendation.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created recommendation if the operation is successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_recommendation(
+        &self,
+        author_id: i32,
+        recipient_id: i32,
+        payload: AddRecommendationPayload,
+    ) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>(
+            "INSERT INTO recommendations (author_id, recipient_id, experience_id, relationship, body)
+              VALUES ($1, $2, $3, $4, $5)
+              RETURNING id",
+        )
+        .bind(author_id)
+        .bind(recipient_id)
+        .bind(payload.experience_id)
+        .bind(payload.relationship)
+        .bind(payload.body.trim())
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|id| id.0)
+    }
+
+    /// Asynchronously moves a received recommendation from one of the given states to a new state.
+    ///
+    /// # Arguments
+    ///
+    /// * `recipient_id` - The ID of the user who received the recommendation.
+    /// * `recommendation_id` - The ID of the recommendation to be updated.
+    /// * `from` - The states the recommendation may currently be in.
+    /// * `to` - The new state of the recommendation.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the author, so they can be notified.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the recipient has no such recommendation in one of the given states.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn transition_recommendation(
+        &self,
+        recipient_id: i32,
+        recommendation_id: i32,
+        from: &[&str],
+        to: &str,
+    ) -> Result<i32, AppError> {
+        let author = sqlx::query_as::<_, (i32,)>(
+            "UPDATE recommendations SET status = $1
+              WHERE id = $2
+                AND recipient_id = $3
+                AND status IN (SELECT value FROM json_each($4))
+              RETURNING author_id",
+        )
+        .bind(to)
+        .bind(recommendation_id)
+        .bind(recipient_id)
+        .bind(serde_json::to_string(from).unwrap())
+        .fetch_optional(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        match author {
+            Some(author) => Ok(author.0),
+            None => Err(AppError::NotFound {
+                error: "Recommendation not found".to_string(),
+            }),
+        }
+    }
+
+    /// Asynchronously deletes a recommendation written or received by a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the author or recipient of the recommendation.
+    /// * `recommendation_id` - The ID of the recommendation to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the recommendation was deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the user neither wrote nor received the recommendation.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn delete_recommendation(
+        &self,
+        user_id: i32,
+        recommendation_id: i32,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "DELETE FROM recommendations WHERE id = $1 AND (recipient_id = $2 OR author_id = $2)",
+        )
+        .bind(recommendation_id)
+        .bind(user_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound {
+                error: "Recommendation not found".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Asynchronously retrieves all recommendations for moderation purposes.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `RecommendationModel` instances, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_all_recommendations(&self) -> Result<Vec<RecommendationModel>, AppError> {
+        sqlx::query_as::<_, RecommendationModel>(
+            "SELECT * FROM recommendations ORDER BY created_at DESC, id DESC",
+        )
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously deletes a recommendation from the database for administrative purposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `recommendation_id` - The ID of the recommendation to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), AppError>` indicating the outcome of the delete operation. If the recommendation is successfully deleted, it returns `Ok(())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn admin_delete_recommendation(
+        &self,
+        recommendation_id: i32,
+    ) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM recommendations WHERE id = $1")
+            .bind(recommendation_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+
+    /// Asynchronously checks if a recommendation exists in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `recommendation_id` - The ID of the recommendation to check for existence.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option<bool>`. If the recommendation exists, it returns `Some(true)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` with an error message if the recommendation does not exist.
+    ///
+    pub async fn recommendation_exists(
+        &self,
+        recommendation_id: i32,
+    ) -> Result<Option<bool>, AppError> {
+        let count =
+            sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM recommendations WHERE id = $1")
+                .bind(recommendation_id)
+                .fetch_one(&self.db_pool)
+                .await
+                .map_err(|_| AppError::InternalError)?;
+
+        if count.0 == 0 {
+            return Err(AppError::NotFound {
+                error: "Recommendation not found".to_string(),
+            });
+        }
+
+        Ok(Some(true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RecommendationService;
+    use crate::test_utils::{create_test_user, test_pool};
+
+    #[tokio::test]
+    async fn test_recommendation_requests() {
+        let pool = test_pool().await;
+        let requester_id = create_test_user(&pool, "requester").await;
+        let author_id = create_test_user(&pool, "author").await;
+        let service = RecommendationService::new(pool.clone());
+
+        assert!(!service.has_open_recommendation_request(requester_id, author_id).await.ok().unwrap());
+
+        service.create_recommendation_request(requester_id, author_id).await.ok().unwrap();
+
+        assert!(service.has_open_recommendation_request(requester_id, author_id).await.ok().unwrap());
+        assert!(!service.has_open_recommendation_request(author_id, requester_id).await.ok().unwrap());
+        assert_eq!(service.get_recent_recommendation_request_count(requester_id).await.ok().unwrap(), 1);
+
+        // Writing the recommendation closes the request
+        sqlx::query(
+            "INSERT INTO recommendations (author_id, recipient_id, relationship, body) VALUES ($1, $2, 'Colleague', 'Great')",
+        )
+        .bind(author_id)
+        .bind(requester_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        assert!(!service.has_open_recommendation_request(requester_id, author_id).await.ok().unwrap());
+
+        // Expired requests no longer block a new one nor count towards the hourly limit
+        sqlx::query("DELETE FROM recommendations").execute(&pool).await.unwrap();
+        sqlx::query("UPDATE recommendation_requests SET created_at = datetime('now', '-31 days')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert!(!service.has_open_recommendation_request(requester_id, author_id).await.ok().unwrap());
+        assert_eq!(service.get_recent_recommendation_request_count(requester_id).await.ok().unwrap(), 0);
+    }
+}
@@ -0,0 +1,858 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::contact_type::{ContactTypeModel, ContactValueFormat};
+use crate::models::date::ProfileDate;
+use crate::models::profile::PublicProfileModel;
+use crate::models::resume::{
+    JsonResumeBasicsModel, JsonResumeCertificateModel, JsonResumeEducationModel,
+    JsonResumeLocationModel, JsonResumeMetaModel, JsonResumeModel, JsonResumeProfileModel,
+    JsonResumeWorkModel, ProfileExportModel,
+};
+use crate::response::error_handling::AppError;
+use crate::services::certification_service::AddCertificationPayload;
+use crate::services::contact_information_service::AddContactInformationPayload;
+use crate::services::education_service::AddEducationPayload;
+use crate::services::experience_service::AddExperiencePayload;
+use crate::services::project_service::is_http_url;
+use crate::IdenoPool;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ImportResumeQuery {
+    pub mode: Option<String>,
+}
+
+/// An entry read from an imported resume, with the section of the resume it was read from and its position there.
+#[derive(Serialize, Deserialize)]
+pub struct ResumeImportItem<T> {
+    pub section: String,
+    pub index: Option<usize>,
+    #[serde(flatten)]
+    pub payload: T,
+}
+
+impl<T> ResumeImportItem<T> {
+    pub(crate) fn new(section: &str, index: Option<usize>, payload: T) -> Self {
+        ResumeImportItem {
+            section: section.to_string(),
+            index,
+            payload,
+        }
+    }
+
+    pub fn problem(&self, error: &str) -> ResumeImportProblem {
+        ResumeImportProblem {
+            section: self.section.clone(),
+            index: self.index,
+            error: error.to_string(),
+        }
+    }
+}
+
+/// An entry of an imported resume that is left out of the import, and why.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ResumeImportProblem {
+    pub section: String,
+    pub index: Option<usize>,
+    pub error: String,
+}
+
+/// The profile fields and entries read from an imported resume, together with the problems of the entries
+/// that are left out.
+#[derive(Serialize, Deserialize, Default)]
+pub struct ResumeImport {
+    pub profile: Option<PublicProfileModel>,
+    pub experiences: Vec<ResumeImportItem<AddExperiencePayload>>,
+    pub educations: Vec<ResumeImportItem<AddEducationPayload>>,
+    pub certifications: Vec<ResumeImportItem<AddCertificationPayload>>,
+    pub contact_information: Vec<ResumeImportItem<AddContactInformationPayload>>,
+    pub problems: Vec<ResumeImportProblem>,
+}
+
+impl ResumeImport {
+    pub fn add_problem(&mut self, section: &str, index: Option<usize>, error: &str) {
+        self.problems.push(ResumeImportProblem {
+            section: section.to_string(),
+            index,
+            error: error.to_string(),
+        });
+    }
+}
+
+/// An import waiting in the session of a user for the confirmation of its preview.
+#[derive(Serialize, Deserialize)]
+pub struct PendingResumeImport {
+    pub user_id: i32,
+    pub replace: bool,
+    pub import: ResumeImport,
+}
+
+#[derive(Clone)]
+pub struct ResumeService {
+    db_pool: IdenoPool,
+}
+
+impl ResumeService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        ResumeService { db_pool }
+    }
+
+    /// Asynchronously imports the profile fields and entries of a resume into the profile of a user.
+    ///
+    /// Everything is written in one transaction, so either the whole import is applied or nothing is. When merging,
+    /// the entries are added to the existing ones and only the profile fields present in the resume are overwritten.
+    /// When replacing, the existing experiences, educations, certifications and contact information are deleted
+    /// first and the profile fields are set to the ones of the resume.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose profile is imported into.
+    /// * `import` - A `ResumeImport` containing the validated profile fields and entries.
+    /// * `replace` - Whether to replace the existing profile instead of merging into it.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the import was applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the transaction.
+    ///
+    pub async fn import_resume(
+        &self,
+        user_id: i32,
+        import: ResumeImport,
+        replace: bool,
+    ) -> Result<(), AppError> {
+        let mut tx = self
+            .db_pool
+            .begin()
+            .await
+            .map_err(|_| AppError::InternalError)?;
+
+        if let Some(profile) = import.profile {
+            let query = match replace {
+                true => "UPDATE profiles SET first_name = $1, last_name = $2, headline = $3, country = $4, city = $5, bio = $6 WHERE user_id = $7",
+                false => "UPDATE profiles SET first_name = COALESCE($1, first_name), last_name = COALESCE($2, last_name), headline = COALESCE($3, headline), country = COALESCE($4, country), city = COALESCE($5, city), bio = COALESCE($6, bio) WHERE user_id = $7",
+            };
+
+            sqlx::query(query)
+                .bind(profile.first_name)
+                .bind(profile.last_name)
+                .bind(profile.headline)
+                .bind(profile.country)
+                .bind(profile.city)
+                .bind(profile.bio)
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|_| AppError::InternalError)?;
+        }
+
+        if replace {
+            for table in [
+                "experiences",
+                "educations",
+                "certification",
+                "contact_information",
+            ] {
+                sqlx::query(&format!("DELETE FROM {} WHERE user_id = $1", table))
+                    .bind(user_id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|_| AppError::InternalError)?;
+            }
+        }
+
+        for ResumeImportItem { payload, .. } in import.experiences {
+            sqlx::query(
+                "INSERT INTO experiences (company, title, start_date, end_date, is_current, exp_type, description, organization_id, user_id) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+            )
+            .bind(payload.company)
+            .bind(payload.title)
+            .bind(payload.start_date)
+            .bind(payload.end_date)
+            .bind(payload.is_current.unwrap_or(false))
+            .bind(payload.exp_type)
+            .bind(payload.description)
+            .bind(payload.organization_id)
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|_| AppError::InternalError)?;
+        }
+
+        for ResumeImportItem { payload, .. } in import.educations {
+            sqlx::query(
+                "INSERT INTO educations (user_id, school, degree, field, start_date, end_date, organization_id) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            )
+            .bind(user_id)
+            .bind(payload.school)
+            .bind(payload.degree)
+            .bind(payload.field)
+            .bind(payload.start_date)
+            .bind(payload.end_date)
+            .bind(payload.organization_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|_| AppError::InternalError)?;
+        }
+
+        for ResumeImportItem { payload, .. } in import.certifications {
+            sqlx::query(
+                "INSERT INTO certification (user_id, name, organization, issue_date, expiration_date, credential_id, credential_url) VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            )
+            .bind(user_id)
+            .bind(payload.name)
+            .bind(payload.organization)
+            .bind(payload.issue_date)
+            .bind(payload.expiration_date)
+            .bind(payload.credential_id)
+            .bind(payload.credential_url)
+            .execute(&mut *tx)
+            .await
+            .map_err(|_| AppError::InternalError)?;
+        }
+
+        for ResumeImportItem { payload, .. } in import.contact_information {
+            sqlx::query(
+                "INSERT INTO contact_information (user_id, type_field, label, value, visibility) VALUES ($1, $2, $3, $4, COALESCE($5, 'public'))",
+            )
+            .bind(user_id)
+            .bind(payload.contact_type)
+            .bind(payload.label)
+            .bind(payload.value)
+            .bind(payload.visibility)
+            .execute(&mut *tx)
+            .await
+            .map_err(|_| AppError::InternalError)?;
+        }
+
+        tx.commit().await.map_err(|_| AppError::InternalError)
+    }
+}
+
+const JSON_RESUME_SCHEMA: &str =
+    "https://raw.githubusercontent.com/jsonresume/resume-schema/v1.0.0/schema.json";
+
+const JSON_RESUME_VERSION: &str = "v1.0.0";
+
+/// Converts a profile into a JSON Resume document.
+///
+/// The first email address, phone number and website of the profile become the contact details of the resume,
+/// all other contact entries become profiles. Masked contact entries are left out, as their value is not shown
+/// to the viewer.
+///
+/// # Arguments
+///
+/// * `export` - The parts of the profile visible to the viewer.
+/// * `contact_types` - The contact types of the registry, keyed by name.
+///
+/// # Returns
+///
+/// Returns the `JsonResumeModel` of the profile.
+///
+pub fn export_json_resume(
+    export: &ProfileExportModel,
+    contact_types: &HashMap<String, ContactTypeModel>,
+) -> JsonResumeModel {
+    let profile = &export.profile;
+
+    let name = [&profile.first_name, &profile.last_name]
+        .into_iter()
+        .flatten()
+        .map(|name| name.trim())
+        .filter(|name| !name.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut basics = JsonResumeBasicsModel {
+        name: non_empty(Some(&name)),
+        label: non_empty(profile.headline.as_ref()),
+        summary: non_empty(profile.bio.as_ref()),
+        location: export_location(profile.city.as_ref(), profile.country.as_ref()),
+        ..Default::default()
+    };
+
+    for entry in export
+        .contact_information
+        .iter()
+        .filter(|entry| !entry.masked)
+    {
+        let contact_type = contact_types.get(&entry.type_field);
+        let value_format = contact_type
+            .and_then(|contact_type| ContactValueFormat::from_str(&contact_type.value_format));
+
+        match value_format {
+            Some(ContactValueFormat::Email) if basics.email.is_none() => {
+                basics.email = Some(entry.value.clone());
+            }
+            Some(ContactValueFormat::Phone) if basics.phone.is_none() => {
+                basics.phone = Some(entry.value.clone());
+            }
+            Some(ContactValueFormat::Url)
+                if entry.type_field == "website" && basics.url.is_none() =>
+            {
+                basics.url = Some(entry.value.clone());
+            }
+            Some(ContactValueFormat::Email) | Some(ContactValueFormat::Phone) => {}
+            _ => basics.profiles.push(JsonResumeProfileModel {
+                network: entry
+                    .label
+                    .clone()
+                    .or_else(|| contact_type.map(|contact_type| contact_type.label.clone())),
+                username: match value_format {
+                    Some(ContactValueFormat::Url) => None,
+                    _ => Some(entry.value.clone()),
+                },
+                url: entry.url.clone().filter(|url| is_http_url(url)),
+            }),
+        }
+    }
+
+    let work = export
+        .experiences
+        .iter()
+        .map(|experience| JsonResumeWorkModel {
+            name: Some(experience.company.clone()),
+            position: Some(experience.title.clone()),
+            exp_type: non_empty(experience.exp_type.as_ref()),
+            start_date: experience.start_date.map(|date| date.to_string()),
+            end_date: match experience.is_current {
+                true => None,
+                false => experience.end_date.map(|date| date.to_string()),
+            },
+            summary: non_empty(experience.description.as_ref()),
+        })
+        .collect();
+
+    let education = export
+        .educations
+        .iter()
+        .map(|education| JsonResumeEducationModel {
+            institution: Some(education.school.clone()),
+            area: non_empty(education.field.as_ref()),
+            study_type: non_empty(education.degree.as_ref()),
+            start_date: education.start_date.map(|date| date.to_string()),
+            end_date: education.end_date.map(|date| date.to_string()),
+        })
+        .collect();
+
+    let certificates = export
+        .certifications
+        .iter()
+        .map(|certification| JsonResumeCertificateModel {
+            name: Some(certification.name.clone()),
+            date: certification.issue_date.map(|date| date.to_string()),
+            url: certification
+                .credential_url
+                .clone()
+                .filter(|url| is_http_url(url)),
+            issuer: Some(certification.organization.clone()),
+        })
+        .collect();
+
+    JsonResumeModel {
+        schema: Some(JSON_RESUME_SCHEMA.to_string()),
+        basics,
+        work,
+        education,
+        certificates,
+        meta: Some(JsonResumeMetaModel {
+            version: Some(JSON_RESUME_VERSION.to_string()),
+        }),
+    }
+}
+
+/// Reads the profile fields and entries of a JSON Resume document.
+///
+/// Entries missing required fields or with dates in another format are left out and reported as problems.
+/// Work entries without an end date are read as current positions. Profiles are matched to the contact types
+/// of the registry by name or label, and kept as custom links otherwise.
+///
+/// # Arguments
+///
+/// * `resume` - The JSON Resume document.
+/// * `contact_types` - The contact types of the registry, keyed by name.
+///
+/// # Returns
+///
+/// Returns the `ResumeImport` read from the document, not yet validated against the rules of each entry type.
+///
+pub fn read_json_resume(
+    resume: JsonResumeModel,
+    contact_types: &HashMap<String, ContactTypeModel>,
+) -> ResumeImport {
+    let mut import = ResumeImport::default();
+    let basics = resume.basics;
+
+    let (first_name, last_name) = match non_empty(basics.name.as_ref()) {
+        Some(name) => match name.split_once(' ') {
+            Some((first_name, last_name)) => (
+                Some(first_name.to_string()),
+                Some(last_name.trim().to_string()),
+            ),
+            None => (Some(name), None),
+        },
+        None => (None, None),
+    };
+    let location = basics.location.unwrap_or_default();
+
+    let profile = PublicProfileModel {
+        first_name,
+        last_name,
+        pronouns: None,
+        headline: non_empty(basics.label.as_ref()),
+        country: non_empty(location.country_code.as_ref()),
+        city: non_empty(location.city.as_ref()),
+        bio: non_empty(basics.summary.as_ref()),
+    };
+
+    if [
+        &profile.first_name,
+        &profile.headline,
+        &profile.country,
+        &profile.city,
+        &profile.bio,
+    ]
+    .iter()
+    .any(|field| field.is_some())
+    {
+        import.profile = Some(profile);
+    }
+
+    for (index, work) in resume.work.into_iter().enumerate() {
+        let (Some(company), Some(title)) = (
+            non_empty(work.name.as_ref()),
+            non_empty(work.position.as_ref()),
+        ) else {
+            import.add_problem("work", Some(index), "Company and position are required");
+            continue;
+        };
+
+        let (Some(start_date), Some(end_date)) = (
+            import_date(work.start_date.as_deref()),
+            import_date(work.end_date.as_deref()),
+        ) else {
+            import.add_problem(
+                "work",
+                Some(index),
+                "Invalid date, expected YYYY, YYYY-MM or YYYY-MM-DD",
+            );
+            continue;
+        };
+
+        import.experiences.push(ResumeImportItem::new(
+            "work",
+            Some(index),
+            AddExperiencePayload {
+                company,
+                organization_id: None,
+                title,
+                start_date,
+                end_date,
+                is_current: Some(start_date.is_some() && end_date.is_none()),
+                exp_type: non_empty(work.exp_type.as_ref()),
+                description: non_empty(work.summary.as_ref()),
+            },
+        ));
+    }
+
+    for (index, education) in resume.education.into_iter().enumerate() {
+        let Some(school) = non_empty(education.institution.as_ref()) else {
+            import.add_problem("education", Some(index), "Institution is required");
+            continue;
+        };
+
+        let (Some(start_date), Some(end_date)) = (
+            import_date(education.start_date.as_deref()),
+            import_date(education.end_date.as_deref()),
+        ) else {
+            import.add_problem(
+                "education",
+                Some(index),
+                "Invalid date, expected YYYY, YYYY-MM or YYYY-MM-DD",
+            );
+            continue;
+        };
+
+        import.educations.push(ResumeImportItem::new(
+            "education",
+            Some(index),
+            AddEducationPayload {
+                school,
+                organization_id: None,
+                degree: non_empty(education.study_type.as_ref()),
+                field: non_empty(education.area.as_ref()),
+                start_date,
+                end_date,
+            },
+        ));
+    }
+
+    for (index, certificate) in resume.certificates.into_iter().enumerate() {
+        let (Some(name), Some(organization)) = (
+            non_empty(certificate.name.as_ref()),
+            non_empty(certificate.issuer.as_ref()),
+        ) else {
+            import.add_problem("certificates", Some(index), "Name and issuer are required");
+            continue;
+        };
+
+        let Some(issue_date) = import_date(certificate.date.as_deref()) else {
+            import.add_problem(
+                "certificates",
+                Some(index),
+                "Invalid date, expected YYYY, YYYY-MM or YYYY-MM-DD",
+            );
+            continue;
+        };
+
+        import.certifications.push(ResumeImportItem::new(
+            "certificates",
+            Some(index),
+            AddCertificationPayload {
+                name,
+                organization,
+                issue_date,
+                expiration_date: None,
+                credential_id: None,
+                credential_url: non_empty(certificate.url.as_ref()),
+            },
+        ));
+    }
+
+    let basic_contacts = [
+        ("email", "email", basics.email),
+        ("phone", "phone", basics.phone),
+        ("url", "website", basics.url),
+    ];
+
+    for (field, contact_type, value) in basic_contacts {
+        if let Some(value) = non_empty(value.as_ref()) {
+            import.contact_information.push(ResumeImportItem::new(
+                &format!("basics.{}", field),
+                None,
+                AddContactInformationPayload {
+                    contact_type: contact_type.to_string(),
+                    label: None,
+                    value,
+                    visibility: None,
+                },
+            ));
+        }
+    }
+
+    for (index, profile) in basics.profiles.into_iter().enumerate() {
+        let network = non_empty(profile.network.as_ref());
+        let Some(value) = non_empty(profile.url.as_ref()).or(non_empty(profile.username.as_ref()))
+        else {
+            import.add_problem("profiles", Some(index), "URL or username is required");
+            continue;
+        };
+
+        let contact_type = network.as_ref().and_then(|network| {
+            contact_types.values().find(|contact_type| {
+                contact_type.name.eq_ignore_ascii_case(network)
+                    || contact_type.label.eq_ignore_ascii_case(network)
+            })
+        });
+
+        let (contact_type, label) = match contact_type {
+            Some(contact_type) if !contact_type.has_label => (contact_type.name.clone(), None),
+            _ => ("custom".to_string(), network),
+        };
+
+        import.contact_information.push(ResumeImportItem::new(
+            "profiles",
+            Some(index),
+            AddContactInformationPayload {
+                contact_type,
+                label,
+                value,
+                visibility: None,
+            },
+        ));
+    }
+
+    import
+}
+
+fn non_empty(value: Option<&String>) -> Option<String> {
+    value
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+/// Reads a date of a JSON Resume document, where `Some(None)` stands for a missing date and `None` for an
+/// invalid one.
+fn import_date(value: Option<&str>) -> Option<Option<ProfileDate>> {
+    match value.map(str::trim).filter(|value| !value.is_empty()) {
+        Some(value) => ProfileDate::parse(value).map(Some),
+        None => Some(None),
+    }
+}
+
+/// Converts the free-text city and country of a profile into a JSON Resume location.
+///
+/// Countries given as a two-letter code are exported as the country code, other countries as the address.
+///
+fn export_location(
+    city: Option<&String>,
+    country: Option<&String>,
+) -> Option<JsonResumeLocationModel> {
+    let city = non_empty(city);
+    let country = non_empty(country);
+
+    if city.is_none() && country.is_none() {
+        return None;
+    }
+
+    let (country_code, address) = match country {
+        Some(country) if country.len() == 2 && country.chars().all(|c| c.is_ascii_alphabetic()) => {
+            (Some(country.to_ascii_uppercase()), None)
+        }
+        country => (None, country),
+    };
+
+    Some(JsonResumeLocationModel {
+        address,
+        city,
+        country_code,
+        region: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::models::certification::{CertificationStatus, PublicCertificationModel};
+    use crate::models::contact_information::PublicContactInformationModel;
+    use crate::models::contact_type::ContactTypeModel;
+    use crate::models::date::ProfileDate;
+    use crate::models::education::PublicEducationModel;
+    use crate::models::experience::PublicExperienceModel;
+    use crate::models::profile::PublicProfileModel;
+    use crate::models::resume::ProfileExportModel;
+
+    use super::{export_json_resume, read_json_resume};
+
+    fn contact_type(name: &str, label: &str, value_format: &str) -> (String, ContactTypeModel) {
+        let contact_type = ContactTypeModel {
+            name: name.to_string(),
+            label: label.to_string(),
+            value_format: value_format.to_string(),
+            pattern: None,
+            hosts: None,
+            path_prefix: None,
+            url_template: None,
+            case_sensitive: false,
+            has_label: false,
+            is_enabled: true,
+        };
+
+        (name.to_string(), contact_type)
+    }
+
+    fn contact(
+        type_field: &str,
+        value: &str,
+        url: Option<&str>,
+        masked: bool,
+    ) -> PublicContactInformationModel {
+        PublicContactInformationModel {
+            id: 0,
+            type_field: type_field.to_string(),
+            label: None,
+            value: value.to_string(),
+            url: url.map(str::to_string),
+            visibility: if masked { "masked" } else { "public" }.to_string(),
+            masked,
+        }
+    }
+
+    fn export() -> ProfileExportModel {
+        ProfileExportModel {
+            profile: PublicProfileModel {
+                first_name: Some("Ada".to_string()),
+                last_name: Some("Lovelace".to_string()),
+                pronouns: Some("she/her".to_string()),
+                headline: Some("Analyst".to_string()),
+                country: Some("gb".to_string()),
+                city: Some("London".to_string()),
+                bio: Some("  ".to_string()),
+            },
+            experiences: vec![PublicExperienceModel {
+                id: 1,
+                company: "Analytical Engines".to_string(),
+                organization_slug: None,
+                title: "Programmer".to_string(),
+                start_date: ProfileDate::parse("1842-09"),
+                end_date: ProfileDate::parse("1843"),
+                is_current: false,
+                duration_months: None,
+                exp_type: None,
+                description: Some("Wrote the first program".to_string()),
+                verified: false,
+            }],
+            educations: vec![PublicEducationModel {
+                school: "Home schooling".to_string(),
+                organization_slug: None,
+                degree: None,
+                field: Some("Mathematics".to_string()),
+                start_date: ProfileDate::parse("1820"),
+                end_date: None,
+            }],
+            certifications: vec![PublicCertificationModel {
+                name: "Difference Engine Operator".to_string(),
+                organization: "Babbage & Co".to_string(),
+                issue_date: ProfileDate::parse("1833-06-05"),
+                expiration_date: None,
+                status: CertificationStatus::Valid,
+                credential_id: None,
+                credential_url: Some("https://example.org/credential".to_string()),
+                credential_url_status: None,
+                badge_verification: None,
+            }],
+            contact_information: vec![
+                contact(
+                    "email",
+                    "ada@example.org",
+                    Some("mailto:ada@example.org"),
+                    false,
+                ),
+                contact("phone", "+441234567", None, true),
+                contact(
+                    "website",
+                    "https://ada.example.org",
+                    Some("https://ada.example.org"),
+                    false,
+                ),
+                contact("github", "ada", Some("https://github.com/ada"), false),
+            ],
+            projects: vec![],
+            languages: vec![],
+            custom_sections: vec![],
+        }
+    }
+
+    #[test]
+    fn test_export_json_resume() {
+        let contact_types = HashMap::from([
+            contact_type("email", "Email", "email"),
+            contact_type("phone", "Phone", "phone"),
+            contact_type("website", "Website", "url"),
+            contact_type("github", "GitHub", "handle"),
+        ]);
+
+        let resume = export_json_resume(&export(), &contact_types);
+
+        assert_eq!(resume.basics.name.as_deref(), Some("Ada Lovelace"));
+        assert_eq!(resume.basics.summary, None);
+        assert_eq!(resume.basics.email.as_deref(), Some("ada@example.org"));
+        // Masked entries are left out
+        assert_eq!(resume.basics.phone, None);
+        assert_eq!(
+            resume.basics.url.as_deref(),
+            Some("https://ada.example.org")
+        );
+        assert_eq!(resume.basics.profiles.len(), 1);
+        assert_eq!(resume.basics.profiles[0].network.as_deref(), Some("GitHub"));
+        assert_eq!(
+            resume
+                .basics
+                .location
+                .as_ref()
+                .unwrap()
+                .country_code
+                .as_deref(),
+            Some("GB")
+        );
+        assert_eq!(resume.work[0].start_date.as_deref(), Some("1842-09"));
+        assert_eq!(resume.education[0].end_date, None);
+        assert_eq!(resume.certificates[0].date.as_deref(), Some("1833-06-05"));
+
+        let schema =
+            serde_json::from_str(include_str!("../../schemas/json-resume.schema.json")).unwrap();
+        let schema = jsonschema::JSONSchema::compile(&schema).unwrap();
+        let document = serde_json::to_value(&resume).unwrap();
+
+        assert!(schema.is_valid(&document));
+
+        // The schema rejects documents with malformed dates
+        let mut invalid = document.clone();
+        invalid["work"][0]["startDate"] = serde_json::json!("September 1842");
+        assert!(!schema.is_valid(&invalid));
+    }
+
+    #[test]
+    fn test_read_json_resume() {
+        let contact_types = HashMap::from([
+            contact_type("github", "GitHub", "handle"),
+            contact_type("custom", "Link", "url"),
+        ]);
+
+        let resume = serde_json::from_value(serde_json::json!({
+            "basics": {
+                "name": "Ada King Lovelace",
+                "email": "ada@example.org",
+                "location": { "countryCode": "GB" },
+                "profiles": [
+                    { "network": "github", "username": "ada" },
+                    { "network": "Blog", "url": "https://blog.example.org" },
+                    { "network": "Telegraph" }
+                ]
+            },
+            "work": [
+                { "name": "Analytical Engines", "position": "Programmer", "startDate": "1842-09" },
+                { "name": "Analytical Engines", "startDate": "1842" },
+                { "name": "Analytical Engines", "position": "Author", "startDate": "Autumn 1842" }
+            ],
+            "education": [{ "institution": "Home schooling", "endDate": "1833" }],
+            "certificates": [{ "name": "Operator", "issuer": "Babbage & Co", "date": "1833-06-05" }],
+            "unknown": true
+        }))
+        .unwrap();
+
+        let import = read_json_resume(resume, &contact_types);
+        let profile = import.profile.unwrap();
+
+        assert_eq!(profile.first_name.as_deref(), Some("Ada"));
+        assert_eq!(profile.last_name.as_deref(), Some("King Lovelace"));
+        assert_eq!(profile.country.as_deref(), Some("GB"));
+
+        // Work entries without an end date are current positions
+        assert_eq!(import.experiences.len(), 1);
+        assert_eq!(import.experiences[0].payload.is_current, Some(true));
+        assert_eq!(import.educations.len(), 1);
+        assert_eq!(import.certifications.len(), 1);
+
+        let contacts = import
+            .contact_information
+            .iter()
+            .map(|item| {
+                (
+                    item.payload.contact_type.as_str(),
+                    item.payload.label.as_deref(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            contacts,
+            vec![("email", None), ("github", None), ("custom", Some("Blog"))]
+        );
+
+        let problems = import
+            .problems
+            .iter()
+            .map(|problem| (problem.section.as_str(), problem.index))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            problems,
+            vec![("work", Some(1)), ("work", Some(2)), ("profiles", Some(2))]
+        );
+    }
+}
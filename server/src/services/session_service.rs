@@ -19,7 +19,7 @@ impl SessionService {
     pub async fn get_session_id(session: &Session) -> Option<String> {
         let id = session.get::<String>("user_id").await;
 
-        id.unwrap_or_else(|_| None)
+        id.unwrap_or(None)
     }
 
     /// Asynchronously checks if a user is logged in based on the session.
@@ -40,7 +40,7 @@ impl SessionService {
         let id = SessionService::get_session_id(session).await;
         match id {
             Some(user) => Ok(user),
-            None => return Err(AppError::NotLoggedIn)?,
+            None => Err(AppError::NotLoggedIn)?,
         }
     }
 
@@ -0,0 +1,708 @@
+use sqlx::SqliteConnection;
+
+use crate::models::skill::{
+    AuthSkillExperienceModel, AuthSkillModel, PublicSkillExperienceModel, PublicSkillModel,
+    SkillModel,
+};
+use crate::response::error_handling::AppError;
+use crate::routes::api::auth::profile::skill::SkillProficiency;
+use crate::{IdenoDBResult, IdenoPool};
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AddSkillPayload {
+    pub name: String,
+    pub proficiency: Option<String>,
+    pub experience_ids: Option<Vec<i32>>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct UpdateSkillPayload {
+    pub name: String,
+    pub proficiency: Option<String>,
+    pub experience_ids: Option<Vec<i32>>,
+}
+
+#[derive(Clone)]
+pub struct SkillService {
+    db_pool: IdenoPool,
+}
+
+/// Normalizes a skill name so that the same skill entered with different casing or spacing
+/// resolves to the same entry of the shared skills vocabulary.
+pub(crate) fn normalize_skill_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Asynchronously resolves a skill name against the shared skills vocabulary, adding it if it does not exist yet.
+///
+/// # Arguments
+///
+/// * `conn` - The connection or transaction the lookup should run on.
+/// * `name` - The name of the skill as entered by the user.
+///
+/// # Returns
+///
+/// Returns a `Result` containing the ID of the vocabulary entry.
+///
+/// # Errors
+///
+/// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+///
+pub(crate) async fn get_or_create_vocabulary_skill(
+    conn: &mut SqliteConnection,
+    name: &str,
+) -> Result<i64, AppError> {
+    sqlx::query_as::<_, (i64,)>(
+        "INSERT INTO skills (name, normalized_name) VALUES ($1, $2)
+          ON CONFLICT (normalized_name) DO UPDATE SET normalized_name = excluded.normalized_name
+          RETURNING id",
+    )
+    .bind(name.trim())
+    .bind(normalize_skill_name(name))
+    .fetch_one(conn)
+    .await
+    .map_err(|_| AppError::InternalError)
+    .map(|id| id.0)
+}
+
+impl SkillService {
+    pub fn new(db_pool: IdenoPool) -> Self {
+        SkillService { db_pool }
+    }
+
+    /// Asynchronously retrieves authenticated skills associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the authenticated user whose skills are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `AuthSkillModel` instances representing the skills associated with the user,
+    /// each including the experiences the skill is linked to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_authenticated_skills(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<AuthSkillModel>, AppError> {
+        let mut skills = sqlx::query_as::<_, AuthSkillModel>(
+            "SELECT
+                user_skills.id,
+                skills.name,
+                user_skills.proficiency
+              FROM user_skills
+              JOIN skills ON skills.id = user_skills.skill_id
+              WHERE user_skills.user_id = ?
+              ORDER BY user_skills.created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        let experiences = sqlx::query_as::<_, AuthSkillExperienceModel>(
+            "SELECT
+                user_skill_experiences.user_skill_id,
+                experiences.id,
+                experiences.company,
+                experiences.title
+              FROM user_skill_experiences
+              JOIN experiences ON experiences.id = user_skill_experiences.experience_id
+              WHERE experiences.user_id = ?
+              ORDER BY experiences.created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        for skill in skills.iter_mut() {
+            skill.experiences = experiences
+                .iter()
+                .filter(|experience| experience.user_skill_id == skill.id)
+                .cloned()
+                .collect();
+        }
+
+        Ok(skills)
+    }
+
+    /// Asynchronously retrieves public skills associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose public skills are to be retrieved.
+    /// * `limit` - An optional limit on the number of skills to retrieve. If not provided, retrieves all skills.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `PublicSkillModel` instances representing the public skills associated with the user,
+    /// each including the experiences the skill is linked to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_public_skills(
+        &self,
+        user_id: i32,
+        limit: Option<i32>,
+    ) -> Result<Vec<PublicSkillModel>, AppError> {
+        let limit = limit.unwrap_or(-1);
+        let mut skills = sqlx::query_as::<_, PublicSkillModel>(
+            "SELECT
+                user_skills.id,
+                skills.name,
+                user_skills.proficiency
+              FROM user_skills
+              JOIN skills ON skills.id = user_skills.skill_id
+              WHERE user_skills.user_id = $1
+              ORDER BY user_skills.created_at DESC
+              LIMIT $2",
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        let experiences = sqlx::query_as::<_, PublicSkillExperienceModel>(
+            "SELECT
+                user_skill_experiences.user_skill_id,
+                experiences.company,
+                experiences.title
+              FROM user_skill_experiences
+              JOIN experiences ON experiences.id = user_skill_experiences.experience_id
+              WHERE experiences.user_id = ?
+              ORDER BY experiences.created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        for skill in skills.iter_mut() {
+            skill.experiences = experiences
+                .iter()
+                .filter(|experience| experience.user_skill_id == skill.id)
+                .cloned()
+                .collect();
+        }
+
+        Ok(skills)
+    }
+
+    /// Asynchronously checks if a user owns a specific skill entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user to check ownership.
+    /// * `skill_id` - The ID of the skill entry to check ownership against.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option<bool>`. If the user owns the skill entry, it returns `Some(true)`,
+    /// if the user does not own the skill entry, it returns `Some(false)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` with an error message if the skill entry is not found.
+    ///
+    pub async fn user_owns_skill(
+        &self,
+        user_id: i32,
+        skill_id: i32,
+    ) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM user_skills WHERE id = $1 AND user_id = $2",
+        )
+        .bind(skill_id)
+        .bind(user_id)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map_or_else(|_| None, |count| Some(count.0 > 0));
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Skill not found".to_string(),
+            }),
+        }
+    }
+
+    /// Asynchronously checks that every given experience belongs to the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user to check ownership.
+    /// * `experience_ids` - The IDs of the experiences a skill should be linked to.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if all experiences belong to the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` if at least one of the experiences does not belong to the user.
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn validate_skill_experiences(
+        &self,
+        user_id: i32,
+        experience_ids: &Option<Vec<i32>>,
+    ) -> Result<(), AppError> {
+        let Some(experience_ids) = experience_ids else {
+            return Ok(());
+        };
+
+        let mut unique_ids = experience_ids.clone();
+        unique_ids.sort_unstable();
+        unique_ids.dedup();
+
+        let owned = sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*) FROM experiences WHERE user_id = $1 AND id IN (SELECT value FROM json_each($2))",
+        )
+        .bind(user_id)
+        .bind(serde_json::to_string(&unique_ids).unwrap())
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        if owned.0 as usize != unique_ids.len() {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid experience".to_string()),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validates the skill name and proficiency.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the skill.
+    /// * `proficiency` - An optional reference to the proficiency level.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating whether the skill is valid (`Ok(true)`) or invalid (`Err(AppError::BadRequest)`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::BadRequest` with an error message if the name is empty or the proficiency is invalid.
+    ///
+    pub fn validate_skill(&self, name: &str, proficiency: &Option<String>) -> Result<bool, AppError> {
+        if normalize_skill_name(name).is_empty() {
+            return Err(AppError::BadRequest {
+                error: Some("Invalid skill name".to_string()),
+            });
+        }
+
+        if let Some(proficiency) = proficiency {
+            if proficiency.is_empty() {
+                return Ok(true);
+            };
+
+            if SkillProficiency::from_str(proficiency).is_none() {
+                return Err(AppError::BadRequest {
+                    error: Some("Invalid skill proficiency".to_string()),
+                });
+            };
+        }
+
+        Ok(true)
+    }
+
+    /// Asynchronously checks if a user already has a skill with the given name.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user to check.
+    /// * `name` - The name of the skill, compared in its normalized form.
+    /// * `exclude_id` - An optional skill entry ID to ignore, used when updating an existing entry.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing `true` if the user already has the skill, otherwise `false`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_existing_skill(
+        &self,
+        user_id: i32,
+        name: &str,
+        exclude_id: Option<i32>,
+    ) -> Result<bool, AppError> {
+        sqlx::query_as::<_, (i64,)>(
+            "SELECT COUNT(*)
+              FROM user_skills
+              JOIN skills ON skills.id = user_skills.skill_id
+              WHERE user_skills.user_id = $1 AND skills.normalized_name = $2 AND user_skills.id != $3",
+        )
+        .bind(user_id)
+        .bind(normalize_skill_name(name))
+        .bind(exclude_id.unwrap_or(-1))
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|count| count.0 > 0)
+    }
+
+    /// Asynchronously deletes a skill entry associated with a user from the database.
+    ///
+    /// The shared skills vocabulary entry is kept, so other users linking the same skill are not affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the skill entry to be deleted.
+    /// * `skill_id` - The ID of the skill entry to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the delete operation. If the skill entry is successfully deleted,
+    /// it returns `Ok(IdenoDBResult)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn delete_skill(
+        &self,
+        user_id: i32,
+        skill_id: i32,
+    ) -> Result<IdenoDBResult, AppError> {
+        sqlx::query("DELETE FROM user_skills WHERE id = $1 AND user_id = $2")
+            .bind(skill_id)
+            .bind(user_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously updates a skill entry associated with a user in the database.
+    ///
+    /// The skill is resolved against the shared skills vocabulary and the experience links are replaced
+    /// by the ones given in the payload. Experiences that do not belong to the user are never linked.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user who owns the skill entry to be updated.
+    /// * `skill_id` - The ID of the skill entry to be updated.
+    /// * `payload` - An `UpdateSkillPayload` containing the updated data for the skill.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` indicating the outcome of the update operation. If the skill entry is successfully updated,
+    /// it returns `Ok(())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` if the skill entry does not exist or does not belong to the user.
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the update operation.
+    ///
+    pub async fn update_skill(
+        &self,
+        user_id: i32,
+        skill_id: i32,
+        payload: UpdateSkillPayload,
+    ) -> Result<(), AppError> {
+        let mut tx = self
+            .db_pool
+            .begin()
+            .await
+            .map_err(|_| AppError::InternalError)?;
+
+        let vocabulary_id = get_or_create_vocabulary_skill(&mut tx, &payload.name).await?;
+
+        let result = sqlx::query("UPDATE user_skills SET skill_id = $1, proficiency = $2 WHERE id = $3 AND user_id = $4")
+            .bind(vocabulary_id)
+            .bind(payload.proficiency)
+            .bind(skill_id)
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|_| AppError::InternalError)?;
+
+        // The experience links are only replaced once the skill entry is known to belong to the user.
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound {
+                error: "Skill not found".to_string(),
+            });
+        }
+
+        sqlx::query("DELETE FROM user_skill_experiences WHERE user_skill_id = $1")
+            .bind(skill_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|_| AppError::InternalError)?;
+
+        sqlx::query(
+            "INSERT OR IGNORE INTO user_skill_experiences (user_skill_id, experience_id)
+              SELECT $1, experiences.id
+              FROM json_each($2)
+              JOIN experiences ON experiences.id = value AND experiences.user_id = $3",
+        )
+        .bind(skill_id)
+        .bind(serde_json::to_string(&payload.experience_ids.unwrap_or_default()).unwrap())
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        tx.commit().await.map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously creates a new skill entry for a user in the database.
+    ///
+    /// The skill is added to the shared skills vocabulary if it does not exist yet, and the entry is linked
+    /// to the experiences given in the payload. Experiences that do not belong to the user are never linked.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user for whom the skill entry is to be created.
+    /// * `payload` - An `AddSkillPayload` containing the details of the skill to be created.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the ID of the newly created skill entry if the operation is successful.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the insert operation.
+    ///
+    pub async fn create_skill(&self, user_id: i32, payload: AddSkillPayload) -> Result<i64, AppError> {
+        let mut tx = self
+            .db_pool
+            .begin()
+            .await
+            .map_err(|_| AppError::InternalError)?;
+
+        let vocabulary_id = get_or_create_vocabulary_skill(&mut tx, &payload.name).await?;
+
+        let created_id = sqlx::query_as::<_, (i64,)>(
+            "INSERT INTO user_skills (user_id, skill_id, proficiency) VALUES ($1, $2, $3) RETURNING id",
+        )
+        .bind(user_id)
+        .bind(vocabulary_id)
+        .bind(payload.proficiency)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        sqlx::query(
+            "INSERT OR IGNORE INTO user_skill_experiences (user_skill_id, experience_id)
+              SELECT $1, experiences.id
+              FROM json_each($2)
+              JOIN experiences ON experiences.id = value AND experiences.user_id = $3",
+        )
+        .bind(created_id.0)
+        .bind(serde_json::to_string(&payload.experience_ids.unwrap_or_default()).unwrap())
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|_| AppError::InternalError)?;
+
+        tx.commit().await.map_err(|_| AppError::InternalError)?;
+
+        Ok(created_id.0)
+    }
+
+    /// Asynchronously retrieves the count of skill entries associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose skill count is to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing the count of skill entries associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_skill_count(&self, user_id: i32) -> Result<i64, AppError> {
+        sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM user_skills WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|count| count.0)
+    }
+
+    /// Asynchronously retrieves all skill entries associated with a user from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose skill entries are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing a vector of `SkillModel` instances representing all skill entries associated with the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while querying the database.
+    ///
+    pub async fn get_all_skills(&self, user_id: i32) -> Result<Vec<SkillModel>, AppError> {
+        sqlx::query_as::<_, SkillModel>(
+            "SELECT
+                user_skills.id,
+                user_skills.user_id,
+                user_skills.skill_id,
+                skills.name,
+                user_skills.proficiency,
+                user_skills.created_at
+              FROM user_skills
+              JOIN skills ON skills.id = user_skills.skill_id
+              WHERE user_skills.user_id = $1
+              ORDER BY user_skills.created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+    }
+
+    /// Asynchronously deletes a skill entry from the database for administrative purposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `skill_id` - The ID of the skill entry to be deleted.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<(), AppError>` indicating the outcome of the delete operation. If the skill entry is successfully deleted, it returns `Ok(())`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the delete operation.
+    ///
+    pub async fn admin_delete_skill(&self, skill_id: i32) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM user_skills WHERE id = $1")
+            .bind(skill_id)
+            .execute(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map(|_| ())
+    }
+
+    /// Asynchronously checks if a skill entry exists in the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `skill_id` - The ID of the skill entry to check for existence.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing an `Option<bool>`. If the skill entry exists, it returns `Some(true)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::NotFound` with an error message if the skill entry is not found.
+    ///
+    pub async fn skill_exists(&self, skill_id: i32) -> Result<Option<bool>, AppError> {
+        let flag = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM user_skills WHERE id = $1")
+            .bind(skill_id)
+            .fetch_one(&self.db_pool)
+            .await
+            .map_err(|_| AppError::InternalError)
+            .map_or_else(|_| None, |count| Some(count.0 > 0));
+
+        match flag {
+            Some(flag) => Ok(Some(flag)),
+            None => Err(AppError::NotFound {
+                error: "Skill not found".to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AddSkillPayload, SkillService, UpdateSkillPayload};
+    use crate::test_utils::{create_test_user, test_pool};
+    use crate::IdenoPool;
+
+    async fn create_experience(pool: &IdenoPool, user_id: i32) -> i32 {
+        sqlx::query_as::<_, (i32,)>(
+            "INSERT INTO experiences (user_id, company, title) VALUES ($1, 'Ideno', 'Engineer') RETURNING id",
+        )
+        .bind(user_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+        .0
+    }
+
+    #[tokio::test]
+    async fn test_skill_experiences_of_other_user() {
+        let pool = test_pool().await;
+        let owner_id = create_test_user(&pool, "owner").await;
+        let other_id = create_test_user(&pool, "other").await;
+        let owner_experience = create_experience(&pool, owner_id).await;
+        let other_experience = create_experience(&pool, other_id).await;
+        let service = SkillService::new(pool);
+
+        let skill_id = service
+            .create_skill(
+                owner_id,
+                AddSkillPayload {
+                    name: "Rust".to_string(),
+                    proficiency: None,
+                    experience_ids: Some(vec![owner_experience]),
+                },
+            )
+            .await
+            .ok()
+            .unwrap();
+
+        // Updating a skill entry of another user must fail without touching its experience links
+        let result = service
+            .update_skill(
+                other_id,
+                skill_id as i32,
+                UpdateSkillPayload {
+                    name: "Rust".to_string(),
+                    proficiency: None,
+                    experience_ids: Some(vec![other_experience]),
+                },
+            )
+            .await;
+        assert!(result.is_err());
+
+        let skills = service.get_authenticated_skills(owner_id).await.ok().unwrap();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].experiences.len(), 1);
+        assert_eq!(skills[0].experiences[0].id, owner_experience);
+
+        // Experiences of another user are never linked to a skill entry
+        service
+            .create_skill(
+                other_id,
+                AddSkillPayload {
+                    name: "Go".to_string(),
+                    proficiency: None,
+                    experience_ids: Some(vec![owner_experience, other_experience]),
+                },
+            )
+            .await
+            .ok()
+            .unwrap();
+
+        let skills = service.get_authenticated_skills(other_id).await.ok().unwrap();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].experiences.len(), 1);
+        assert_eq!(skills[0].experiences[0].id, other_experience);
+    }
+}
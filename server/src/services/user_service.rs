@@ -39,7 +39,7 @@ impl UserService {
     /// Returns an `AppError::Forbidden` error if the user is not an admin.
     ///
     pub async fn check_admin(&self, session: &Session) -> Result<UserModel, AppError> {
-        let user = self.check_user(&session).await?;
+        let user = self.check_user(session).await?;
 
         match user.role.as_str() {
             "admin" => Ok(user),
@@ -72,6 +72,34 @@ impl UserService {
         }
     }
 
+    /// Asynchronously retrieves the account email of a user, if the user has confirmed it.
+    ///
+    /// Contact information entries are never confirmed, so they cannot be used to prove that an address belongs
+    /// to the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The ID of the user whose email is being retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing `Some(String)` with the account email if it is confirmed, or `None` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AppError::InternalError` if there is an internal error while executing the query.
+    ///
+    pub async fn get_verified_email(&self, user_id: i32) -> Result<Option<String>, AppError> {
+        sqlx::query_as::<_, (String,)>(
+            "SELECT email FROM users WHERE id = $1 AND email_verified_at IS NOT NULL",
+        )
+        .bind(user_id)
+        .fetch_optional(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
+        .map(|email| email.map(|email| email.0))
+    }
+
     /// Asynchronously retrieves a user by email or username from the database.
     ///
     /// # Arguments
@@ -277,14 +305,21 @@ impl UserService {
         user_id: i32,
         payload: UpdateUserRequest,
     ) -> Result<IdenoDBResult, AppError> {
-        sqlx::query("UPDATE users SET username = $1, email = $2, role = $3 WHERE id = $4")
-            .bind(payload.username)
-            .bind(payload.email)
-            .bind(payload.role)
-            .bind(user_id)
-            .execute(&self.db_pool)
-            .await
-            .map_err(|_| AppError::InternalError)
+        sqlx::query(
+            "UPDATE users
+              SET username = $1,
+                email = $2,
+                role = $3,
+                email_verified_at = CASE WHEN email = $2 THEN email_verified_at END
+              WHERE id = $4",
+        )
+        .bind(payload.username)
+        .bind(payload.email)
+        .bind(payload.role)
+        .bind(user_id)
+        .execute(&self.db_pool)
+        .await
+        .map_err(|_| AppError::InternalError)
     }
 
     /// Asynchronously retrieves user data by user ID from the database for administrative purposes.
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+use crate::models::contact_information::PublicContactInformationModel;
+use crate::models::contact_type::{ContactTypeModel, ContactValueFormat};
+use crate::models::profile::PublicProfileModel;
+
+/// The longest line of a vCard in octets, longer lines are folded.
+const MAX_LINE_LENGTH: usize = 75;
+
+fn non_empty(value: Option<&String>) -> Option<&str> {
+    value
+        .map(|value| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+/// Escapes a text value, or a component of a structured value, of a vCard property.
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ',' => escaped.push_str("\\,"),
+            ';' => escaped.push_str("\\;"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Formats a parameter value of a vCard property, quoting values with characters that end a parameter.
+fn param_value(value: &str) -> String {
+    let value: String = value
+        .chars()
+        .filter(|c| *c != '"' && !c.is_control())
+        .collect();
+
+    match value.contains([':', ';', ',']) {
+        true => format!("\"{}\"", value),
+        false => value,
+    }
+}
+
+/// Folds a content line of a vCard into lines of at most 75 octets, as required by RFC 6350.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 8);
+    let mut length = 0;
+
+    for c in line.chars() {
+        if length + c.len_utf8() > MAX_LINE_LENGTH {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+
+    folded.push_str("\r\n");
+    folded
+}
+
+/// Converts a contact entry into a vCard property, based on the value format of its contact type.
+///
+/// Profiles on other platforms become `SOCIALPROFILE` properties as defined in RFC 9554. Entries without a
+/// link to open are left out, which also drops stored values that do not pass the validation of their type.
+///
+fn contact_property(
+    entry: &PublicContactInformationModel,
+    contact_type: Option<&ContactTypeModel>,
+) -> Option<String> {
+    let value_format = contact_type
+        .and_then(|contact_type| ContactValueFormat::from_str(&contact_type.value_format));
+
+    match value_format {
+        Some(ContactValueFormat::Email) => Some(format!("EMAIL:{}", escape_text(&entry.value))),
+        Some(ContactValueFormat::Phone) => Some(format!("TEL;VALUE=uri:{}", entry.url.as_ref()?)),
+        Some(ContactValueFormat::Url) if entry.type_field == "website" => {
+            Some(format!("URL:{}", entry.url.as_ref()?))
+        }
+        Some(ContactValueFormat::Matrix) => {
+            entry.url.as_ref()?;
+            let value = contact_type?.normalize(&entry.value)?;
+
+            Some(format!("IMPP:matrix:u/{}", value.trim_start_matches('@')))
+        }
+        _ => {
+            let url = entry.url.as_ref()?;
+            let service = non_empty(entry.label.as_ref())
+                .map(str::to_string)
+                .or_else(|| contact_type.map(|contact_type| contact_type.label.clone()))
+                .unwrap_or_else(|| entry.type_field.clone());
+
+            Some(format!(
+                "SOCIALPROFILE;SERVICE-TYPE={}:{}",
+                param_value(&service),
+                url
+            ))
+        }
+    }
+}
+
+/// Builds a vCard 4.0 of a profile, as defined in RFC 6350.
+///
+/// The card holds the name, pronouns, headline and location of the profile, its public contact entries and a link to
+/// the profile page. Masked contact entries are left out.
+///
+/// # Arguments
+///
+/// * `username` - The username of the profile owner, used as the formatted name when the profile has no name.
+/// * `profile` - The public profile.
+/// * `contact_information` - The contact entries of the profile as seen by other users.
+/// * `contact_types` - The contact types by name.
+/// * `profile_url` - The link to the profile page.
+///
+/// # Returns
+///
+/// Returns the vCard with CRLF line endings.
+///
+pub fn export_vcard(
+    username: &str,
+    profile: &PublicProfileModel,
+    contact_information: &[PublicContactInformationModel],
+    contact_types: &HashMap<String, ContactTypeModel>,
+    profile_url: &str,
+) -> String {
+    let first_name = non_empty(profile.first_name.as_ref());
+    let last_name = non_empty(profile.last_name.as_ref());
+    let name: Vec<&str> = [first_name, last_name].into_iter().flatten().collect();
+
+    let mut lines = vec!["BEGIN:VCARD".to_string(), "VERSION:4.0".to_string()];
+
+    lines.push(match name.is_empty() {
+        true => format!("FN:{}", escape_text(username)),
+        false => format!("FN:{}", escape_text(&name.join(" "))),
+    });
+    if !name.is_empty() {
+        lines.push(format!(
+            "N:{};{};;;",
+            escape_text(last_name.unwrap_or_default()),
+            escape_text(first_name.unwrap_or_default())
+        ));
+    }
+    if let Some(pronouns) = non_empty(profile.pronouns.as_ref()) {
+        lines.push(format!("PRONOUNS:{}", escape_text(pronouns)));
+    }
+    if let Some(headline) = non_empty(profile.headline.as_ref()) {
+        lines.push(format!("TITLE:{}", escape_text(headline)));
+    }
+
+    let city = non_empty(profile.city.as_ref());
+    let country = non_empty(profile.country.as_ref());
+    if city.is_some() || country.is_some() {
+        lines.push(format!(
+            "ADR:;;;{};;;{}",
+            escape_text(city.unwrap_or_default()),
+            escape_text(country.unwrap_or_default())
+        ));
+    }
+
+    lines.extend(
+        contact_information
+            .iter()
+            .filter(|entry| !entry.masked)
+            .filter_map(|entry| contact_property(entry, contact_types.get(&entry.type_field))),
+    );
+
+    lines.push(format!("URL:{}", profile_url));
+    lines.push("END:VCARD".to_string());
+
+    lines.iter().map(|line| fold_line(line)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contact_type(
+        name: &str,
+        value_format: &str,
+        url_template: Option<&str>,
+    ) -> ContactTypeModel {
+        ContactTypeModel {
+            name: name.to_string(),
+            label: name.to_uppercase(),
+            value_format: value_format.to_string(),
+            pattern: None,
+            hosts: None,
+            path_prefix: None,
+            url_template: url_template.map(str::to_string),
+            case_sensitive: false,
+            has_label: false,
+            is_enabled: true,
+        }
+    }
+
+    fn contact(
+        type_field: &str,
+        value: &str,
+        url: Option<&str>,
+        masked: bool,
+    ) -> PublicContactInformationModel {
+        PublicContactInformationModel {
+            id: 0,
+            type_field: type_field.to_string(),
+            label: None,
+            value: value.to_string(),
+            url: url.map(str::to_string),
+            visibility: if masked { "masked" } else { "public" }.to_string(),
+            masked,
+        }
+    }
+
+    #[test]
+    fn test_export_vcard() {
+        let profile = PublicProfileModel {
+            first_name: Some("Ada".to_string()),
+            last_name: Some("Lovelace".to_string()),
+            pronouns: None,
+            headline: Some("Analyst, programmer; poet".to_string()),
+            country: Some("GB".to_string()),
+            city: Some("London".to_string()),
+            bio: None,
+        };
+        let contact_types = HashMap::from([
+            ("email".to_string(), contact_type("email", "email", None)),
+            ("phone".to_string(), contact_type("phone", "phone", None)),
+            ("website".to_string(), contact_type("website", "url", None)),
+            (
+                "github".to_string(),
+                contact_type("github", "handle", Some("https://github.com/{value}")),
+            ),
+        ]);
+        let contact_information = vec![
+            contact(
+                "email",
+                "ada@example.org",
+                Some("mailto:ada@example.org"),
+                false,
+            ),
+            contact("phone", "+44******90", None, true),
+            contact("github", "ada", Some("https://github.com/ada"), false),
+            contact(
+                "website",
+                "https://ada.example.org",
+                Some("https://ada.example.org"),
+                false,
+            ),
+            // Legacy values that fail validation have no link
+            contact("phone", "+44 20\r\nNOTE:injected", None, false),
+            contact("website", "javascript:alert(1)", None, false),
+        ];
+
+        let vcard = export_vcard(
+            "ada",
+            &profile,
+            &contact_information,
+            &contact_types,
+            &format!("https://ideno.example/profile/{}", "a".repeat(80)),
+        );
+        let lines: Vec<&str> = vcard.split("\r\n").collect();
+
+        assert_eq!(lines[0], "BEGIN:VCARD");
+        assert_eq!(lines[1], "VERSION:4.0");
+        assert!(lines.contains(&"FN:Ada Lovelace"));
+        assert!(lines.contains(&"N:Lovelace;Ada;;;"));
+        assert!(lines.contains(&"TITLE:Analyst\\, programmer\\; poet"));
+        assert!(lines.contains(&"ADR:;;;London;;;GB"));
+        assert!(lines.contains(&"EMAIL:ada@example.org"));
+        assert!(lines.contains(&"SOCIALPROFILE;SERVICE-TYPE=GITHUB:https://github.com/ada"));
+        assert!(lines.contains(&"URL:https://ada.example.org"));
+        // Masked entries and entries without a link are left out
+        assert!(!vcard.contains("TEL"));
+        assert!(!vcard.contains("NOTE"));
+        assert!(!vcard.contains("javascript"));
+        // Long lines are folded
+        assert!(lines.iter().all(|line| line.len() <= MAX_LINE_LENGTH));
+        assert!(lines.iter().any(|line| line.starts_with(' ')));
+        assert!(vcard.ends_with("END:VCARD\r\n"));
+    }
+}
@@ -0,0 +1,47 @@
+use sqlx::sqlite::SqlitePoolOptions;
+
+use crate::IdenoPool;
+
+/// Creates an in-memory database with every migration applied.
+///
+/// The pool is limited to a single connection because every new connection to `sqlite::memory:`
+/// opens its own, empty database.
+///
+/// # Returns
+///
+/// Returns an `IdenoPool` connected to the fresh database.
+///
+pub async fn test_pool() -> IdenoPool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+
+    sqlx::migrate!().run(&pool).await.unwrap();
+
+    pool
+}
+
+/// Inserts a user with the given username into the test database.
+///
+/// # Arguments
+///
+/// * `pool` - The test database.
+/// * `username` - The username of the new user, also used to build a unique email.
+///
+/// # Returns
+///
+/// Returns the ID of the new user.
+///
+pub async fn create_test_user(pool: &IdenoPool, username: &str) -> i32 {
+    sqlx::query_as::<_, (i32,)>(
+        "INSERT INTO users (username, email, password) VALUES ($1, $2, '') RETURNING id",
+    )
+    .bind(username)
+    .bind(format!("{username}@example.com"))
+    .fetch_one(pool)
+    .await
+    .unwrap()
+    .0
+}